@@ -1,10 +1,35 @@
 from typing import Any
 
-from .dictwalk import DictWalk, dictwalk
+from .dictwalk import (
+    DictWalk,
+    Validator,
+    add_soft_error,
+    capabilities,
+    dictwalk,
+    get_limits,
+    get_options,
+    remove_soft_error,
+    set_limits,
+    set_options,
+    soft_errors,
+)
 
 
 def run_filter_function(path_filter: str, value: Any) -> Any:
     return dictwalk.run_filter_function(path_filter, value)
 
 
-__all__ = ["dictwalk", "DictWalk", "run_filter_function"]
+__all__ = [
+    "add_soft_error",
+    "capabilities",
+    "dictwalk",
+    "DictWalk",
+    "get_limits",
+    "get_options",
+    "remove_soft_error",
+    "run_filter_function",
+    "set_limits",
+    "set_options",
+    "soft_errors",
+    "Validator",
+]
@@ -1,22 +1,162 @@
-from typing import TYPE_CHECKING, Any, Callable, Protocol, TypeVar, cast, overload
+from typing import (
+    TYPE_CHECKING,
+    Any,
+    Callable,
+    Sequence,
+    Literal,
+    Protocol,
+    TypeVar,
+    cast,
+    overload,
+)
 
 TData = TypeVar("TData")
 TDefault = TypeVar("TDefault")
+PathDialect = Literal["dictwalk", "jsonpointer"]
+GetReturns = Literal["values", "indices", "items"]
 
 
 class DictWalkProtocol(Protocol):
     @overload
     def get(
-        self, data: Any, path: str, default: None = None, *, strict: bool = False
+        self,
+        data: Any,
+        path: str,
+        default: None = None,
+        *,
+        strict: bool = False,
+        lenient_compare: bool = False,
+        copy: bool = False,
+        map_transform: bool = False,
+        now: Any = None,
+        dialect: PathDialect = "dictwalk",
+        params: dict[str, Any] | None = None,
+        returns: GetReturns = "values",
+        normalize_tuples: bool = False,
+        flatten: bool | int = False,
+        fallback: Any = None,
     ) -> Any | None: ...
 
     @overload
     def get(
-        self, data: Any, path: str, default: TDefault, *, strict: bool = False
+        self,
+        data: Any,
+        path: str,
+        default: TDefault,
+        *,
+        strict: bool = False,
+        lenient_compare: bool = False,
+        copy: bool = False,
+        map_transform: bool = False,
+        now: Any = None,
+        dialect: PathDialect = "dictwalk",
+        params: dict[str, Any] | None = None,
+        returns: GetReturns = "values",
+        normalize_tuples: bool = False,
+        flatten: bool | int = False,
+        fallback: Any = None,
     ) -> Any | TDefault: ...
 
-    def exists(self, data: Any, path: str, *, strict: bool = False) -> bool: ...
+    def get_or_default(
+        self,
+        data: Any,
+        path: str,
+        default: Any = None,
+        *,
+        lenient_compare: bool = False,
+    ) -> tuple[Any, bool]: ...
+
+    def get_typed(
+        self,
+        data: Any,
+        path: str,
+        schema: str | dict[Any, Any] | list[Any],
+        default: Any = None,
+        *,
+        on_error: Literal["null", "raise"] = "null",
+        strict: bool = False,
+        lenient_compare: bool = False,
+    ) -> Any: ...
+
+    def get_unique(
+        self,
+        data: Any,
+        path: str,
+        *,
+        strict: bool = False,
+        lenient_compare: bool = False,
+    ) -> list[Any]: ...
+
+    def exists(
+        self,
+        data: Any,
+        path: str,
+        *,
+        strict: bool = False,
+        lenient_compare: bool = False,
+        dialect: PathDialect = "dictwalk",
+        params: dict[str, Any] | None = None,
+        nonempty_wildcards: bool = True,
+    ) -> bool: ...
+
+    def exists_typed(
+        self,
+        data: Any,
+        path: str,
+        type_name: str,
+        *,
+        strict: bool = False,
+        lenient_compare: bool = False,
+    ) -> bool: ...
+
+    def first_match_with_path(
+        self,
+        data: Any,
+        path: str,
+        *,
+        strict: bool = False,
+        lenient_compare: bool = False,
+    ) -> tuple[str | None, Any] | None: ...
+
+    def match_count(
+        self,
+        data: Any,
+        path: str,
+        *,
+        strict: bool = False,
+        lenient_compare: bool = False,
+    ) -> int: ...
+
+    def for_each(
+        self,
+        data: Any,
+        path: str,
+        callback: Callable[[Any], Any],
+        *,
+        strict: bool = False,
+        lenient_compare: bool = False,
+    ) -> int: ...
+
+    @overload
+    def set(
+        self,
+        data: TData,
+        path: str,
+        value: Any,
+        *,
+        strict: bool = False,
+        create_missing: bool = True,
+        create_filter_match: bool = True,
+        overwrite_incompatible: bool = True,
+        overwrite_scalar: bool | None = None,
+        overwrite_container: bool | None = None,
+        dialect: PathDialect = "dictwalk",
+        params: dict[str, Any] | None = None,
+        report: Literal[False] = False,
+        skip_unchanged: bool = False,
+    ) -> TData: ...
 
+    @overload
     def set(
         self,
         data: TData,
@@ -27,9 +167,56 @@ class DictWalkProtocol(Protocol):
         create_missing: bool = True,
         create_filter_match: bool = True,
         overwrite_incompatible: bool = True,
+        overwrite_scalar: bool | None = None,
+        overwrite_container: bool | None = None,
+        dialect: PathDialect = "dictwalk",
+        params: dict[str, Any] | None = None,
+        report: Literal[True],
+        skip_unchanged: bool = False,
+    ) -> tuple[TData, list[str | None]]: ...
+
+    def set_if(
+        self,
+        data: TData,
+        path: str,
+        value: Any,
+        condition: str,
+        *,
+        strict: bool = False,
+        create_missing: bool = True,
+        create_filter_match: bool = True,
+        overwrite_incompatible: bool = True,
+        overwrite_scalar: bool | None = None,
+        overwrite_container: bool | None = None,
+        dialect: PathDialect = "dictwalk",
+        params: dict[str, Any] | None = None,
+        report: bool = False,
+        skip_unchanged: bool = False,
+    ) -> Any: ...
+
+    def set_all(
+        self,
+        data: TData,
+        paths: list[str],
+        value: Any,
+        *,
+        strict: bool = False,
+        create_missing: bool = True,
+        create_filter_match: bool = True,
+        overwrite_incompatible: bool = True,
+        overwrite_scalar: bool | None = None,
+        overwrite_container: bool | None = None,
     ) -> TData: ...
 
-    def unset(self, data: TData, path: str, *, strict: bool = False) -> TData: ...
+    def unset(
+        self,
+        data: TData,
+        path: str,
+        *,
+        strict: bool = False,
+        dialect: PathDialect = "dictwalk",
+        params: dict[str, Any] | None = None,
+    ) -> TData: ...
 
     def run_filter_function(self, path_filter: str, value: Any) -> Any: ...
 
@@ -40,7 +227,7 @@ class DictWalkProtocol(Protocol):
     def get_path_filter(self, name: str) -> Callable[[Any], Any]: ...
 
 
-def _load_rust_backend() -> Any:
+def _load_rust_module() -> Any:
     try:
         from . import _dictwalk_rs  # type: ignore[attr-defined]
     except Exception as ex:
@@ -48,8 +235,11 @@ def _load_rust_backend() -> Any:
             "Rust backend is required but unavailable. "
             "Build/install the extension (dictwalk._dictwalk_rs)."
         ) from ex
+    return _dictwalk_rs
 
-    backend = getattr(_dictwalk_rs, "dictwalk", _dictwalk_rs)
+
+def _load_rust_backend(rs_module: Any) -> Any:
+    backend = getattr(rs_module, "dictwalk", rs_module)
     required_methods = ("get", "exists", "set", "unset", "run_filter_function")
     if not all(hasattr(backend, method_name) for method_name in required_methods):
         raise RuntimeError(
@@ -58,7 +248,8 @@ def _load_rust_backend() -> Any:
     return backend
 
 
-_backend = _load_rust_backend()
+_rs_module = _load_rust_module()
+_backend = _load_rust_backend(_rs_module)
 dictwalk: DictWalkProtocol = cast(DictWalkProtocol, _backend)
 
 if TYPE_CHECKING:
@@ -66,6 +257,27 @@ if TYPE_CHECKING:
 else:
     DictWalk = type(_backend)
 
+if TYPE_CHECKING:
+
+    class Validator:
+        def __init__(
+            self,
+            paths: Sequence[str],
+            *,
+            lenient_compare: bool = False,
+            nonempty_wildcards: bool = True,
+        ) -> None: ...
+
+        @property
+        def paths(self) -> list[str]: ...
+
+        def check(self, data: Any) -> dict[str, bool]: ...
+
+        def missing(self, data: Any) -> list[str]: ...
+
+else:
+    Validator = _rs_module.Validator
+
 
 def register_path_filter(name: str, path_filter: Callable[[Any], Any]) -> None:
     return dictwalk.register_path_filter(name, path_filter)
@@ -73,3 +285,44 @@ def register_path_filter(name: str, path_filter: Callable[[Any], Any]) -> None:
 
 def run_filter_function(path_filter: str, value: Any) -> Any:
     return dictwalk.run_filter_function(path_filter, value)
+
+
+def set_limits(
+    *,
+    max_path_tokens: int | None = None,
+    max_result_size: int | None = None,
+    max_recursion_depth: int | None = None,
+) -> None:
+    _rs_module.set_limits(
+        max_path_tokens=max_path_tokens,
+        max_result_size=max_result_size,
+        max_recursion_depth=max_recursion_depth,
+    )
+
+
+def get_limits() -> dict[str, int]:
+    return _rs_module.get_limits()
+
+
+def set_options(*, none_safe_strings: bool | None = None) -> None:
+    _rs_module.set_options(none_safe_strings=none_safe_strings)
+
+
+def get_options() -> dict[str, bool]:
+    return _rs_module.get_options()
+
+
+def add_soft_error(exc_type: type[Exception]) -> None:
+    _rs_module.add_soft_error(exc_type)
+
+
+def remove_soft_error(exc_type: type[Exception]) -> bool:
+    return _rs_module.remove_soft_error(exc_type)
+
+
+def soft_errors() -> tuple[type[Exception], ...]:
+    return _rs_module.soft_errors()
+
+
+def capabilities() -> dict[str, Any]:
+    return _rs_module.capabilities()
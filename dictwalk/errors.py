@@ -20,3 +20,7 @@ class DictWalkResolutionError(DictWalkError):
         self.path = path
         self.token = token
         self.message = message
+
+
+class DictWalkLimitError(DictWalkError):
+    pass
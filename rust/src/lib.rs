@@ -1,10 +1,19 @@
 use pyo3::basic::CompareOp;
-use pyo3::exceptions::{PyKeyError, PyRuntimeError, PyTypeError, PyValueError};
+use pyo3::exceptions::{
+    PyArithmeticError, PyIndexError, PyKeyError, PyOSError, PyOverflowError, PyRuntimeError,
+    PyTypeError, PyValueError,
+};
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict, PyFloat, PyInt, PyList, PyModule, PyString, PyTuple};
+use pyo3::types::{
+    PyAny, PyBool, PyBytes, PyDict, PyFloat, PyFrozenSet, PyInt, PyList, PyMapping, PyModule,
+    PySet, PySlice, PyString, PyTuple, PyType,
+};
 use regex::Regex;
-use std::cmp::Ordering;
-use std::sync::LazyLock;
+use std::cell::{Cell, RefCell};
+use std::cmp::{Ordering, Reverse};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::{LazyLock, Mutex};
 
 #[derive(Clone, Debug)]
 enum TokenKind {
@@ -18,9 +27,7 @@ enum TokenKind {
         end: Option<isize>,
     },
     RootFilter {
-        field: String,
-        operator: String,
-        value: String,
+        filter: FilterExpr,
     },
     Wildcard,
     DeepWildcard,
@@ -28,7 +35,7 @@ enum TokenKind {
     Get(String),
     Index {
         key: String,
-        index: isize,
+        index: IndexValue,
     },
     Slice {
         key: String,
@@ -37,10 +44,25 @@ enum TokenKind {
     },
     Filter {
         list_key: String,
+        filter: FilterExpr,
+    },
+}
+
+#[derive(Clone, Debug)]
+enum IndexValue {
+    Literal(isize),
+    RootReference(String),
+}
+
+#[derive(Clone, Debug)]
+enum FilterExpr {
+    Clause {
         field: String,
         operator: String,
         value: String,
     },
+    And(Box<FilterExpr>, Box<FilterExpr>),
+    Or(Box<FilterExpr>, Box<FilterExpr>),
 }
 
 #[derive(Clone, Debug)]
@@ -51,6 +73,8 @@ struct ParsedToken {
 
 static INDEX_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^(.+)\[(-?\d+)\]$").expect("valid regex"));
+static ROOT_REFERENCE_INDEX_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(.+)\[(\$\$root(?:\.[^\[\]]+)?)\]$").expect("valid regex"));
 static SLICE_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^(.+)\[(-?\d*):(-?\d*)\]$").expect("valid regex"));
 static ROOT_INDEX_RE: LazyLock<Regex> =
@@ -59,6 +83,10 @@ static ROOT_SLICE_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^\[(-?\d*):(-?\d*)\]$").expect("valid regex"));
 static PATH_FILTER_SEGMENT_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^\$([a-zA-Z_]\w*)(?:\((.*)\))?(\[\])?$").expect("valid regex"));
+static HTML_MARKUP_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?is)<script\b.*?</script\s*>|<style\b.*?</style\s*>|<!--.*?-->|<[a-z/!][^>]*>")
+        .expect("valid regex")
+});
 
 enum BuiltinFilter {
     Inc,
@@ -68,10 +96,12 @@ enum BuiltinFilter {
     String,
     Int,
     Float,
+    ParseNumber,
     Decimal,
     Quote,
     Even,
     Odd,
+    SignCheck(&'static str),
     Gt(PyObject),
     Lt(PyObject),
     Gte(PyObject),
@@ -88,20 +118,34 @@ enum BuiltinFilter {
     Sqrt,
     Root(PyObject),
     Round(Option<PyObject>),
+    RoundSig(PyObject),
     Floor,
     Ceil,
-    Max,
-    Min,
+    Max(Option<PyObject>),
+    Min(Option<PyObject>),
     Len,
     Pick(Vec<PyObject>),
+    ToOrdered(Option<PyObject>),
     Unpick(Vec<PyObject>),
+    Wrap(PyObject),
+    FlattenKeys(Option<PyObject>),
+    NestKeys(Option<PyObject>),
+    CaseKeys(&'static str),
+    DeepGet(PyObject),
+    JsonPointer(PyObject),
+    Unwrap(PyObject),
     Abs,
     Clamp(PyObject, PyObject),
+    ClampEach(PyObject, PyObject),
     Sign,
     Log(Option<PyObject>),
     Exp,
     Pct(PyObject),
+    Ratio(PyObject),
+    AbsError(PyObject),
+    RelError(PyObject),
     Pctile(PyObject),
+    Winsorize(PyObject),
     Median,
     Q1,
     Q3,
@@ -109,52 +153,125 @@ enum BuiltinFilter {
     Mode,
     Stdev,
     Between(PyObject, PyObject),
-    Sum,
-    Avg,
+    Within(PyObject, PyObject),
+    Sum(Option<PyObject>),
+    Cumsum,
+    CumulativePct,
+    MinMaxScale(PyObject, PyObject),
+    RunningAvg(PyObject, bool),
+    FillNone(bool),
+    Avg(Option<PyObject>),
     Unique,
+    Tally,
+    CountTrue,
+    ProportionTrue,
+    DedupKeys,
+    ToSet,
+    SetOp(&'static str, PyObject),
+    SymDiff(PyObject),
+    Partition(PredicateExpr),
+    ApplyIf(PredicateExpr, BuiltinFilterPipeline),
+    MapRecursive(BuiltinFilterPipeline),
+    PruneNulls(bool),
+    SortKeysRecursive,
+    Switch(Vec<PyObject>),
+    Lookup(PyObject, Option<PyObject>),
+    WithDefaultMap(PyObject),
+    Require(Vec<PyObject>),
     Reverse,
     Chunk(PyObject),
+    SplitAt(PyObject),
+    Rotate(PyObject),
+    Transpose(bool, Option<PyObject>),
+    Zip(PyObject),
+    Interleave(PyObject),
+    Repeat(PyObject),
+    PadList(PyObject, Option<PyObject>, bool),
+    ClampLen(PyObject),
     Flatten,
     FlattenDeep,
     Sorted(Option<PyObject>),
+    SortedByAbs(Option<PyObject>),
     First,
     Last,
     Contains(PyObject),
+    IndexOf(PyObject),
+    CountOccurrences(PyObject),
     In(PyObject),
     Lower,
     Upper,
     Title,
     Strip(Option<PyObject>),
     Replace(PyObject, PyObject),
+    ReplaceMap(PyObject),
     RegexReplace(PyObject, PyObject),
     Split(Option<PyObject>),
+    SplitLines(bool),
+    StripHtml(bool),
+    Html(&'static str),
     Join(PyObject),
+    ToCsvRow(Option<PyObject>),
     Startswith(PyObject),
     Endswith(PyObject),
     Matches(PyObject),
+    Similarity(PyObject),
+    Indent(PyObject),
+    Dedent,
+    WrapText(PyObject, Option<PyObject>),
     Keys,
+    KeysMatching(Option<PyObject>, PyObject, Option<PyObject>),
+    SelectKeys(Option<PyObject>, PyObject, Option<PyObject>),
     Values,
     Items,
     SortBy(PyObject, Option<PyObject>),
+    Rank(Option<PyObject>),
     UniqueBy(PyObject),
     IndexBy(PyObject),
     GroupBy(PyObject),
+    AggregateBy(&'static str, PyObject, PyObject),
+    Pivot(PyObject, PyObject, PyObject, Option<PyObject>),
     Const(PyObject),
     Default(PyObject),
+    CoalesceEmptyTo(PyObject),
+    TypedDefault(PyObject),
     Coalesce(Vec<PyObject>),
-    Bool,
+    CoalescePaths(Vec<PyObject>),
+    CoalesceFlat,
+    Bool(bool),
+    Coerce(PyObject),
+    CoerceKeys(PyObject),
+    CoerceListOf(PyObject, bool),
+    ToBoolStrict,
     TypeIs(PyObject),
+    AssertType(PyObject),
     IsEmpty,
     NonEmpty,
     Compact,
+    NanTo(PyObject, bool),
     FromJson,
     ToJson,
+    CanonicalJson,
+    JsonPath(PyObject),
+    Hash(PyObject),
+    Checksum(&'static str, Option<PyObject>),
     ToDatetime(Option<PyObject>),
     Strftime(PyObject),
     Timestamp,
+    TimestampMs,
+    FromTimestampMs,
     AgeSeconds,
+    AgeDays,
+    Duration(PyObject),
+    BusinessDaysBetween(PyObject, Option<PyObject>),
+    HumanizeDuration(bool),
+    DateTrunc(PyObject),
+    IsoWeek,
+    IsoYear,
+    IsWeekend,
+    IsWeekday,
     Before(PyObject),
     After(PyObject),
+    BetweenDates(PyObject, PyObject),
 }
 
 struct BuiltinFilterStep {
@@ -164,6 +281,313 @@ struct BuiltinFilterStep {
 
 type BuiltinFilterPipeline = Vec<BuiltinFilterStep>;
 
+const DEFAULT_MAX_PATH_TOKENS: usize = 1_000;
+const DEFAULT_MAX_RESULT_SIZE: usize = 10_000_000;
+const DEFAULT_MAX_RECURSION_DEPTH: usize = 1_000;
+
+static MAX_PATH_TOKENS: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_PATH_TOKENS);
+static MAX_RESULT_SIZE: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_RESULT_SIZE);
+static MAX_RECURSION_DEPTH: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_RECURSION_DEPTH);
+static NONE_SAFE_STRINGS: AtomicBool = AtomicBool::new(false);
+/// Exception types registered with `add_soft_error`, treated like `KeyError`.
+static SOFT_ERROR_TYPES: Mutex<Vec<Py<PyType>>> = Mutex::new(Vec::new());
+
+const PATH_DIALECTS: &[&str] = &["dictwalk", "jsonpointer"];
+const COMPARISON_OPERATORS: &[&str] = &["==", "!=", ">", "<", ">=", "<="];
+const LOGICAL_OPERATORS: &[&str] = &["&&", "||", "!", "??", "and", "or", "not"];
+
+/// Names accepted by `compile_builtin_filter`, reported by `capabilities()`.
+/// Keep in sync when adding a builtin.
+const BUILTIN_FILTER_NAMES: &[&str] = &[
+    "abs",
+    "abs_error",
+    "add",
+    "adler32",
+    "after",
+    "age_days",
+    "age_seconds",
+    "apply_if",
+    "assert_type",
+    "avg",
+    "avg_by",
+    "before",
+    "between",
+    "between_dates",
+    "bool",
+    "business_days_between",
+    "canonical_json",
+    "ceil",
+    "chunk",
+    "clamp",
+    "clamp_each",
+    "clamp_len",
+    "coalesce",
+    "coalesce_empty_to",
+    "coalesce_flat",
+    "coalesce_paths",
+    "coerce",
+    "coerce_keys",
+    "coerce_list_of",
+    "compact",
+    "const",
+    "contains",
+    "count_occurrences",
+    "count_true",
+    "crc32",
+    "cumsum",
+    "cumulative_pct",
+    "date_trunc",
+    "dec",
+    "decimal",
+    "dedent",
+    "dedup_keys",
+    "deep_get",
+    "default",
+    "difference",
+    "div",
+    "double",
+    "duration",
+    "endswith",
+    "escape_html",
+    "even",
+    "exp",
+    "fill_backward",
+    "fill_forward",
+    "first",
+    "flatten",
+    "flatten_deep",
+    "flatten_keys",
+    "float",
+    "floor",
+    "from_json",
+    "from_timestamp_ms",
+    "group_by",
+    "gt",
+    "gte",
+    "hash",
+    "humanize_duration",
+    "idiv",
+    "in",
+    "inc",
+    "indent",
+    "index_by",
+    "index_of",
+    "int",
+    "interleave",
+    "intersection",
+    "iqr",
+    "is_empty",
+    "is_weekday",
+    "is_weekend",
+    "iso_week",
+    "iso_year",
+    "items",
+    "join",
+    "json_path",
+    "json_pointer",
+    "keys",
+    "keys_matching",
+    "last",
+    "len",
+    "log",
+    "lookup",
+    "lower",
+    "lower_keys",
+    "lt",
+    "lte",
+    "map_recursive",
+    "matches",
+    "max",
+    "md5",
+    "median",
+    "min",
+    "min_max_scale",
+    "mod",
+    "mode",
+    "mul",
+    "nan_to",
+    "neg",
+    "negative",
+    "nest_keys",
+    "non_empty",
+    "nonzero",
+    "odd",
+    "pad_list",
+    "parse_number",
+    "partition",
+    "pct",
+    "pctile",
+    "pick",
+    "pivot",
+    "positive",
+    "pow",
+    "proportion_true",
+    "prune_nulls",
+    "q1",
+    "q3",
+    "quote",
+    "rank",
+    "ratio",
+    "regex_replace",
+    "rel_error",
+    "repeat",
+    "replace",
+    "replace_map",
+    "require",
+    "reverse",
+    "root",
+    "rotate",
+    "round",
+    "round_sig",
+    "rpow",
+    "running_avg",
+    "select_keys",
+    "sha1",
+    "sha256",
+    "sign",
+    "similarity",
+    "sort_by",
+    "sort_keys_recursive",
+    "sorted",
+    "sorted_by_abs",
+    "split",
+    "split_at",
+    "split_lines",
+    "sqrt",
+    "square",
+    "startswith",
+    "stdev",
+    "strftime",
+    "string",
+    "strip",
+    "strip_html",
+    "sub",
+    "sum",
+    "sum_by",
+    "switch",
+    "symdiff",
+    "tally",
+    "timestamp",
+    "timestamp_ms",
+    "title",
+    "title_keys",
+    "to_bool_strict",
+    "to_csv_row",
+    "to_datetime",
+    "to_json",
+    "to_ordered",
+    "to_set",
+    "transpose",
+    "type_is",
+    "typed_default",
+    "unescape_html",
+    "union",
+    "unique",
+    "unique_by",
+    "unpick",
+    "unwrap",
+    "upper",
+    "upper_keys",
+    "values",
+    "winsorize",
+    "with_default_map",
+    "within",
+    "wrap",
+    "wrap_text",
+    "xor_lists",
+    "zip",
+];
+
+thread_local! {
+    static LENIENT_COMPARE: Cell<bool> = const { Cell::new(false) };
+    static NOW_OVERRIDE: RefCell<Option<PyObject>> = const { RefCell::new(None) };
+}
+
+/// Enables lenient filter comparisons for the current call, restoring the
+/// previous setting on drop so nested backend calls keep their own mode.
+struct LenientCompareGuard {
+    previous: bool,
+}
+
+impl LenientCompareGuard {
+    fn new(enabled: bool) -> Self {
+        Self {
+            previous: LENIENT_COMPARE.with(|flag| flag.replace(enabled)),
+        }
+    }
+}
+
+impl Drop for LenientCompareGuard {
+    fn drop(&mut self) {
+        LENIENT_COMPARE.with(|flag| flag.set(self.previous));
+    }
+}
+
+/// Fixes the "now" used by relative datetime builtins for the current call. A
+/// `None` override keeps any outer value so nested backend calls stay consistent.
+struct NowOverrideGuard {
+    previous: Option<Option<PyObject>>,
+}
+
+impl NowOverrideGuard {
+    fn new(py: Python<'_>, now: Option<PyObject>) -> PyResult<Self> {
+        let Some(now) = now else {
+            return Ok(Self { previous: None });
+        };
+        let now = as_utc_datetime(py, &now)?.ok_or_else(|| {
+            PyTypeError::new_err("'now' must be a datetime, ISO 8601 string, or timestamp.")
+        })?;
+        let previous = NOW_OVERRIDE.with(|slot| slot.replace(Some(now)));
+        Ok(Self {
+            previous: Some(previous),
+        })
+    }
+}
+
+impl Drop for NowOverrideGuard {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            NOW_OVERRIDE.with(|slot| *slot.borrow_mut() = previous);
+        }
+    }
+}
+
+fn check_path_token_limit(py: Python<'_>, count: usize) -> PyResult<()> {
+    let limit = MAX_PATH_TOKENS.load(AtomicOrdering::Relaxed);
+    if count > limit {
+        return Err(make_error(
+            py,
+            "DictWalkLimitError",
+            &format!("Path has {count} tokens, exceeding max_path_tokens={limit}."),
+        ));
+    }
+    Ok(())
+}
+
+fn check_result_size_limit(py: Python<'_>, size: usize) -> PyResult<()> {
+    let limit = MAX_RESULT_SIZE.load(AtomicOrdering::Relaxed);
+    if size > limit {
+        return Err(make_error(
+            py,
+            "DictWalkLimitError",
+            &format!("Resolution produced more than max_result_size={limit} values."),
+        ));
+    }
+    Ok(())
+}
+
+fn check_recursion_depth_limit(py: Python<'_>, depth: usize) -> PyResult<()> {
+    let limit = MAX_RECURSION_DEPTH.load(AtomicOrdering::Relaxed);
+    if depth > limit {
+        return Err(make_error(
+            py,
+            "DictWalkLimitError",
+            &format!("Resolution exceeded max_recursion_depth={limit}."),
+        ));
+    }
+    Ok(())
+}
+
 fn make_error(py: Python<'_>, class_name: &str, message: &str) -> PyErr {
     match py.import_bound("dictwalk.errors") {
         Ok(errors_module) => match errors_module.getattr(class_name) {
@@ -219,12 +643,140 @@ fn load_registry(py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
     Ok(py.None().into_bound(py))
 }
 
+/// Tracks quoted literals and backslash escapes for every splitter (path tokens,
+/// filter clauses, pipeline segments), so brackets, dots, and pipes within values
+/// such as `[?.note=='a]b']` are not treated as syntax. A backslash escapes the
+/// next character both inside and outside quotes.
+#[derive(Default)]
+struct QuoteState {
+    in_single: bool,
+    in_double: bool,
+    escaped: bool,
+}
+
+impl QuoteState {
+    /// Consumes `ch` and returns true when it is escaped or part of a quoted literal.
+    fn consume(&mut self, ch: char) -> bool {
+        if self.escaped {
+            self.escaped = false;
+            return true;
+        }
+        if ch == '\\' {
+            self.escaped = true;
+            return true;
+        }
+        if self.in_single {
+            self.in_single = ch != '\'';
+            return true;
+        }
+        if self.in_double {
+            self.in_double = ch != '"';
+            return true;
+        }
+        match ch {
+            '\'' => self.in_single = true,
+            '"' => self.in_double = true,
+            _ => return false,
+        }
+        true
+    }
+
+    fn in_quotes(&self) -> bool {
+        self.in_single || self.in_double
+    }
+}
+
+/// Splits an RFC 6901 JSON Pointer into unescaped reference tokens.
+/// The empty pointer refers to the whole document.
+fn split_json_pointer(pointer: &str) -> Result<Vec<String>, String> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(rest) = pointer.strip_prefix('/') else {
+        return Err(format!(
+            "JSON Pointer '{pointer}' must be empty or start with '/'."
+        ));
+    };
+
+    let mut tokens = Vec::new();
+    for raw in rest.split('/') {
+        let mut token = String::with_capacity(raw.len());
+        let mut chars = raw.chars();
+        while let Some(ch) = chars.next() {
+            if ch != '~' {
+                token.push(ch);
+                continue;
+            }
+            match chars.next() {
+                Some('0') => token.push('~'),
+                Some('1') => token.push('/'),
+                _ => {
+                    return Err(format!(
+                        "JSON Pointer '{pointer}' has an invalid escape in '{raw}'; '~' must be followed by '0' or '1'."
+                    ))
+                }
+            }
+        }
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// RFC 6901 array index: `0` or digits without a leading zero.
+fn json_pointer_index(token: &str) -> Option<usize> {
+    if token.is_empty() || !token.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if token.len() > 1 && token.starts_with('0') {
+        return None;
+    }
+    token.parse().ok()
+}
+
+/// One RFC 6901 step: a segment names a member of a mapping (even when it is
+/// all digits) and a position of a list or tuple.
+fn json_pointer_step<'py>(
+    current: &Bound<'py, PyAny>,
+    token: &str,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    if let Some(mapping) = as_read_mapping(current) {
+        return read_mapping_item(mapping, token);
+    }
+    if is_list_or_tuple(current) {
+        return match json_pointer_index(token) {
+            Some(index) if index < current.len()? => current.get_item(index).map(Some),
+            _ => Ok(None),
+        };
+    }
+    Ok(None)
+}
+
+fn resolve_json_pointer(
+    py: Python<'_>,
+    value: &PyObject,
+    tokens: &[String],
+) -> PyResult<Option<PyObject>> {
+    let mut current = value.bind(py).clone();
+    for token in tokens {
+        let Some(next) = json_pointer_step(&current, token)? else {
+            return Ok(None);
+        };
+        current = next;
+    }
+    Ok(Some(current.unbind()))
+}
+
 fn split_raw_path_tokens(path: &str) -> Vec<String> {
     let mut tokens: Vec<String> = Vec::new();
     let mut current = String::new();
     let mut bracket_depth = 0i32;
+    let mut quotes = QuoteState::default();
 
     for ch in path.chars() {
+        if bracket_depth > 0 && quotes.consume(ch) {
+            current.push(ch);
+            continue;
+        }
         if ch == '[' {
             bracket_depth += 1;
             current.push(ch);
@@ -248,11 +800,16 @@ fn split_raw_path_tokens(path: &str) -> Vec<String> {
 
 fn split_path_and_transform(path: &str) -> (String, Option<String>) {
     let mut bracket_depth = 0i32;
+    let mut quotes = QuoteState::default();
     let chars: Vec<char> = path.chars().collect();
 
     let mut i = 0usize;
     while i < chars.len() {
         let ch = chars[i];
+        if bracket_depth > 0 && quotes.consume(ch) {
+            i += 1;
+            continue;
+        }
         if ch == '[' {
             bracket_depth += 1;
             i += 1;
@@ -426,7 +983,208 @@ fn parse_filter_expression_parts(expression: &str) -> Option<(String, String, St
     Some((field, "==".to_string(), "$bool".to_string()))
 }
 
-fn parse_filter_token_parts(raw_token: &str) -> Option<(String, String, String, String)> {
+/// Word synonym (`and`, `or`, `not`) of a boolean operator starting at `i`,
+/// returned as its symbol plus the word length. A word only counts when it stands
+/// alone: `and`/`or` need whitespace on both sides and `not` needs whitespace or
+/// `(` after it, so fields such as `.order` or `notes` are left alone.
+fn boolean_word_operator_at<T: Copy + Into<char>>(
+    items: &[T],
+    i: usize,
+) -> Option<(&'static str, usize)> {
+    let char_at = |idx: usize| items.get(idx).map(|item| (*item).into());
+    let preceded_by_space = i > 0 && char_at(i - 1).is_some_and(char::is_whitespace);
+    let starts_operand = i == 0 || char_at(i - 1).is_some_and(|ch| ch.is_whitespace() || ch == '(');
+    for (word, symbol) in [("and", "&&"), ("or", "||"), ("not", "!")] {
+        let matches_word = word
+            .chars()
+            .enumerate()
+            .all(|(offset, expected)| char_at(i + offset) == Some(expected));
+        if !matches_word {
+            continue;
+        }
+        let next = char_at(i + word.len());
+        let standalone = if symbol == "!" {
+            starts_operand && next.is_some_and(|ch| ch.is_whitespace() || ch == '(')
+        } else {
+            preceded_by_space && next.is_some_and(char::is_whitespace)
+        };
+        if standalone {
+            return Some((symbol, word.len()));
+        }
+    }
+    None
+}
+
+fn next_filter_clause_starts_field(chars: &[char], start: usize) -> bool {
+    chars[start..]
+        .iter()
+        .find(|ch| !ch.is_whitespace() && **ch != '(')
+        .is_some_and(|ch| *ch == '.')
+}
+
+fn push_filter_clause_token(tokens: &mut Vec<String>, current: &mut String) {
+    let clause = current.trim();
+    if !clause.is_empty() {
+        tokens.push(clause.to_string());
+    }
+    current.clear();
+}
+
+fn tokenize_filter_clauses(expression: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let mut group_depth = 0i32;
+    let mut quotes = QuoteState::default();
+    let chars: Vec<char> = expression.chars().collect();
+
+    let mut i = 0usize;
+    while i < chars.len() {
+        let ch = chars[i];
+        if !quotes.consume(ch) {
+            // A '(' before a clause's field opens a group of clauses, and a ')' with no
+            // open parenthesis inside the current clause closes one.
+            if ch == '('
+                && depth == 0
+                && current.trim().is_empty()
+                && next_filter_clause_starts_field(&chars, i + 1)
+            {
+                tokens.push("(".to_string());
+                group_depth += 1;
+                i += 1;
+                continue;
+            }
+            if ch == ')' && depth == 0 && group_depth > 0 {
+                push_filter_clause_token(&mut tokens, &mut current);
+                tokens.push(")".to_string());
+                group_depth -= 1;
+                i += 1;
+                continue;
+            }
+
+            match ch {
+                '[' | '(' | '{' => depth += 1,
+                ']' | ')' | '}' => depth -= 1,
+                _ => {}
+            }
+
+            // A top-level '&&'/'||' only separates clauses when the next operand is a
+            // field expression; otherwise it belongs to a right-hand predicate filter.
+            if depth == 0 && i + 1 < chars.len() {
+                let operator = match (ch, chars[i + 1]) {
+                    ('&', '&') => Some("&&"),
+                    ('|', '|') => Some("||"),
+                    _ => None,
+                };
+                if let Some(operator) = operator {
+                    if next_filter_clause_starts_field(&chars, i + 2) {
+                        push_filter_clause_token(&mut tokens, &mut current);
+                        tokens.push(operator.to_string());
+                        i += 2;
+                        continue;
+                    }
+                }
+            }
+            if depth == 0 {
+                if let Some((operator @ ("&&" | "||"), len)) = boolean_word_operator_at(&chars, i) {
+                    if next_filter_clause_starts_field(&chars, i + len) {
+                        push_filter_clause_token(&mut tokens, &mut current);
+                        tokens.push(operator.to_string());
+                        i += len;
+                        continue;
+                    }
+                }
+            }
+        }
+
+        current.push(ch);
+        i += 1;
+    }
+    push_filter_clause_token(&mut tokens, &mut current);
+    tokens
+}
+
+struct FilterClauseParser {
+    tokens: Vec<String>,
+    idx: usize,
+}
+
+impl FilterClauseParser {
+    fn parse(mut self) -> Option<FilterExpr> {
+        let result = self.parse_or()?;
+        if self.idx != self.tokens.len() {
+            return None;
+        }
+        Some(result)
+    }
+
+    fn parse_or(&mut self) -> Option<FilterExpr> {
+        let mut left = self.parse_and()?;
+        while self.peek() == Some("||") {
+            self.idx += 1;
+            let right = self.parse_and()?;
+            left = FilterExpr::Or(Box::new(left), Box::new(right));
+        }
+        Some(left)
+    }
+
+    fn parse_and(&mut self) -> Option<FilterExpr> {
+        let mut left = self.parse_primary()?;
+        while self.peek() == Some("&&") {
+            self.idx += 1;
+            let right = self.parse_primary()?;
+            left = FilterExpr::And(Box::new(left), Box::new(right));
+        }
+        Some(left)
+    }
+
+    fn parse_primary(&mut self) -> Option<FilterExpr> {
+        if self.peek() == Some("(") {
+            self.idx += 1;
+            let expr = self.parse_or()?;
+            if self.peek() != Some(")") {
+                return None;
+            }
+            self.idx += 1;
+            return Some(expr);
+        }
+        self.parse_clause()
+    }
+
+    fn parse_clause(&mut self) -> Option<FilterExpr> {
+        let token = self.peek()?;
+        if matches!(token, "&&" | "||" | "(" | ")") {
+            return None;
+        }
+        let (field, operator, value) = parse_filter_expression_parts(token)?;
+        self.idx += 1;
+        Some(FilterExpr::Clause {
+            field,
+            operator,
+            value,
+        })
+    }
+
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.idx).map(|token| token.as_str())
+    }
+}
+
+fn parse_filter_expression(expression: &str) -> Option<FilterExpr> {
+    let tokens = tokenize_filter_clauses(expression);
+    if tokens.len() == 1 {
+        let (field, operator, value) = parse_filter_expression_parts(expression)?;
+        return Some(FilterExpr::Clause {
+            field,
+            operator,
+            value,
+        });
+    }
+
+    FilterClauseParser { tokens, idx: 0 }.parse()
+}
+
+fn parse_filter_token_parts(raw_token: &str) -> Option<(String, FilterExpr)> {
     let start = raw_token.find("[?")?;
     if !raw_token.ends_with(']') {
         return None;
@@ -438,8 +1196,8 @@ fn parse_filter_token_parts(raw_token: &str) -> Option<(String, String, String,
     }
 
     let expression = &raw_token[start + 2..raw_token.len() - 1];
-    let (field, operator, value) = parse_filter_expression_parts(expression)?;
-    Some((list_key, field, operator, value))
+    let filter = parse_filter_expression(expression)?;
+    Some((list_key, filter))
 }
 
 fn parse_root_selector_suffix(suffix: &str) -> Result<TokenKind, String> {
@@ -474,14 +1232,10 @@ fn parse_root_selector_suffix(suffix: &str) -> Result<TokenKind, String> {
             return Err("Failed to parse filter token.".to_string());
         }
         let expression = &suffix[2..suffix.len() - 1];
-        let Some((field, operator, value)) = parse_filter_expression_parts(expression) else {
+        let Some(filter) = parse_filter_expression(expression) else {
             return Err("Failed to parse filter token.".to_string());
         };
-        return Ok(TokenKind::RootFilter {
-            field,
-            operator,
-            value,
-        });
+        return Ok(TokenKind::RootFilter { filter });
     }
 
     Err("Failed to parse root selector token.".to_string())
@@ -518,7 +1272,25 @@ fn parse_token(raw_token: &str) -> Result<TokenKind, String> {
             .get(2)
             .and_then(|m| m.as_str().parse::<isize>().ok())
             .ok_or("Failed to parse list index.")?;
-        return Ok(TokenKind::Index { key, index });
+        return Ok(TokenKind::Index {
+            key,
+            index: IndexValue::Literal(index),
+        });
+    }
+
+    if let Some(captures) = ROOT_REFERENCE_INDEX_RE.captures(raw_token) {
+        let key = captures
+            .get(1)
+            .map(|m| m.as_str().to_string())
+            .ok_or("Failed to parse index key.")?;
+        let reference = captures
+            .get(2)
+            .map(|m| m.as_str().to_string())
+            .ok_or("Failed to parse index reference.")?;
+        return Ok(TokenKind::Index {
+            key,
+            index: IndexValue::RootReference(reference),
+        });
     }
 
     if let Some(captures) = SLICE_RE.captures(raw_token) {
@@ -540,15 +1312,10 @@ fn parse_token(raw_token: &str) -> Result<TokenKind, String> {
     }
 
     if raw_token.contains("[?") {
-        let Some((list_key, field, operator, value)) = parse_filter_token_parts(raw_token) else {
+        let Some((list_key, filter)) = parse_filter_token_parts(raw_token) else {
             return Err("Failed to parse filter token.".to_string());
         };
-        return Ok(TokenKind::Filter {
-            list_key,
-            field,
-            operator,
-            value,
-        });
+        return Ok(TokenKind::Filter { list_key, filter });
     }
 
     Ok(TokenKind::Get(raw_token.to_string()))
@@ -575,7 +1342,15 @@ fn validate_filter_token(
     if field == "." {
         // Valid root-field expression.
     } else if let Some(field_transform) = field.strip_prefix(".|") {
-        if compile_builtin_pipeline(py, field_transform, None).is_none() {
+        // `$$root` arguments only resolve against the document, so they are
+        // compiled when the filter runs rather than validated here.
+        if !field_transform.contains("$$root")
+            && compile_builtin_pipeline(py, field_transform, None).is_none()
+            && !matches!(
+                compile_builtin_or_boolean_predicate(py, field_transform, None),
+                Ok(Some(_))
+            )
+        {
             return Err(make_parse_error(
                 py,
                 &format!("{list_key}[?{field}{operator}{value}]"),
@@ -593,7 +1368,10 @@ fn validate_filter_token(
     }
 
     // Validate right-side predicate expression/filter syntax.
-    if let Err(message) = compile_builtin_or_boolean_predicate(py, value) {
+    if value.contains("$$root") {
+        return Ok(());
+    }
+    if let Err(message) = compile_builtin_or_boolean_predicate(py, value, None) {
         return Err(make_parse_error(
             py,
             &format!("{list_key}[?{field}{operator}{value}]"),
@@ -605,6 +1383,26 @@ fn validate_filter_token(
     Ok(())
 }
 
+fn validate_filter_expr(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    list_key: &str,
+    filter: &FilterExpr,
+) -> PyResult<()> {
+    match filter {
+        FilterExpr::Clause {
+            field,
+            operator,
+            value,
+        } => validate_filter_token(py, module, registry, list_key, field, operator, value),
+        FilterExpr::And(left, right) | FilterExpr::Or(left, right) => {
+            validate_filter_expr(py, module, registry, list_key, left)?;
+            validate_filter_expr(py, module, registry, list_key, right)
+        }
+    }
+}
+
 fn parse_path(
     py: Python<'_>,
     module: &Bound<'_, PyModule>,
@@ -616,6 +1414,7 @@ fn parse_path(
     }
 
     let mut raw_tokens = split_raw_path_tokens(path);
+    check_path_token_limit(py, raw_tokens.len())?;
     if raw_tokens.len() >= 2
         && raw_tokens[0].is_empty()
         && raw_tokens[1].starts_with('[')
@@ -633,25 +1432,16 @@ fn parse_path(
         };
 
         match &kind {
-            TokenKind::Filter {
-                list_key,
-                field,
-                operator,
-                value,
-            } => {
-                validate_filter_token(py, module, registry, list_key, field, operator, value)?;
+            TokenKind::Filter { list_key, filter } => {
+                validate_filter_expr(py, module, registry, list_key, filter)?;
             }
-            TokenKind::RootFilter {
-                field,
-                operator,
-                value,
-            } => {
+            TokenKind::RootFilter { filter } => {
                 let root_key = if raw_token.starts_with("$$root") {
                     "$$root"
                 } else {
                     "."
                 };
-                validate_filter_token(py, module, registry, root_key, field, operator, value)?;
+                validate_filter_expr(py, module, registry, root_key, filter)?;
             }
             _ => {}
         }
@@ -664,33 +1454,37 @@ fn parse_path(
     Ok(tokens)
 }
 
-fn resolve_get_token(py: Python<'_>, current: &PyObject, key: &str) -> PyResult<PyObject> {
-    let bound = current.bind(py);
-    if let Ok(dict) = bound.downcast::<PyDict>() {
-        let value = match dict.get_item(key)? {
-            Some(inner) => inner,
-            None => return Err(PyKeyError::new_err(key.to_string())),
-        };
-        return Ok(value.into());
+/// Mapping view of a node for reads: `dict` plus any other
+/// `collections.abc.Mapping` such as `types.MappingProxyType`. Writes still
+/// require a mutable `dict`.
+fn as_read_mapping<'a, 'py>(bound: &'a Bound<'py, PyAny>) -> Option<&'a Bound<'py, PyMapping>> {
+    if bound.is_none()
+        || bound.is_instance_of::<PyList>()
+        || bound.is_instance_of::<PyString>()
+        || bound.is_instance_of::<PyInt>()
+        || bound.is_instance_of::<PyFloat>()
+    {
+        return None;
     }
+    bound.downcast::<PyMapping>().ok()
+}
 
-    if let Ok(list) = bound.downcast::<PyList>() {
-        let out = PyList::empty_bound(py);
-        for item in list.iter() {
-            if let Ok(item_dict) = item.downcast::<PyDict>() {
-                if item_dict.contains(key)? {
-                    if let Some(value) = item_dict.get_item(key)? {
-                        out.append(value)?;
-                    }
-                }
-            }
-        }
-        return Ok(out.into());
-    }
+fn is_read_mapping(bound: &Bound<'_, PyAny>) -> bool {
+    as_read_mapping(bound).is_some()
+}
 
-    Err(PyTypeError::new_err(format!(
-        "Key '{key}' not found in current context."
-    )))
+/// `dict.get_item` for any read mapping: `Ok(None)` when `key` is missing.
+fn read_mapping_item<'py>(
+    mapping: &Bound<'py, PyMapping>,
+    key: &str,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    if let Ok(dict) = mapping.downcast::<PyDict>() {
+        return dict.get_item(key);
+    }
+    if !mapping.contains(key)? {
+        return Ok(None);
+    }
+    mapping.get_item(key).map(Some)
 }
 
 fn get_type_name(bound: &Bound<'_, PyAny>) -> String {
@@ -701,35 +1495,20 @@ fn get_type_name(bound: &Bound<'_, PyAny>) -> String {
         .unwrap_or_else(|_| "unknown".to_string())
 }
 
-fn resolve_map_token(py: Python<'_>, current: &PyObject, key: &str) -> PyResult<PyObject> {
-    let bound = current.bind(py);
-    let type_name = get_type_name(&bound);
-    let list = bound.downcast::<PyList>().map_err(|_| {
-        PyTypeError::new_err(format!("Expected a list for key '{key}', got {type_name}."))
-    })?;
-
-    let out = PyList::empty_bound(py);
-    for item in list.iter() {
-        if let Ok(item_dict) = item.downcast::<PyDict>() {
-            if item_dict.contains(key)? {
-                if let Some(value) = item_dict.get_item(key)? {
-                    out.append(value)?;
-                }
-            }
+/// Case-insensitive type check by Python type name, plus the JSON-style
+/// categories `number`, `string`, `array`, `object`, and `null`.
+fn matches_type_name(bound: &Bound<'_, PyAny>, type_name: &str) -> bool {
+    match type_name.to_lowercase().as_str() {
+        "number" => {
+            !bound.is_instance_of::<PyBool>()
+                && (bound.is_instance_of::<PyInt>() || bound.is_instance_of::<PyFloat>())
         }
+        "string" => bound.is_instance_of::<PyString>(),
+        "array" => is_list_or_tuple(bound),
+        "object" => bound.is_instance_of::<PyDict>(),
+        "null" => bound.is_none(),
+        expected => get_type_name(bound).to_lowercase() == expected,
     }
-    Ok(out.into())
-}
-
-fn resolve_root_map_token(py: Python<'_>, current: &PyObject) -> PyResult<PyObject> {
-    let bound = current.bind(py);
-    if !bound.is_instance_of::<PyList>() {
-        return Err(PyTypeError::new_err(format!(
-            "Expected a list for root map '[]', got {}.",
-            get_type_name(&bound)
-        )));
-    }
-    Ok(current.clone_ref(py))
 }
 
 fn iter_child_nodes(py: Python<'_>, node: &Bound<'_, PyAny>) -> PyResult<Vec<PyObject>> {
@@ -747,56 +1526,135 @@ fn iter_child_nodes(py: Python<'_>, node: &Bound<'_, PyAny>) -> PyResult<Vec<PyO
         }
         return Ok(out);
     }
+    if let Some(mapping) = as_read_mapping(node) {
+        let mut out: Vec<PyObject> = Vec::new();
+        for value in mapping.values()?.iter()? {
+            out.push(value?.into());
+        }
+        return Ok(out);
+    }
     let _ = py;
     Ok(Vec::new())
 }
 
-fn resolve_wildcard_token(py: Python<'_>, current: &PyObject) -> PyResult<PyObject> {
-    let bound = current.bind(py);
-    let type_name = get_type_name(&bound);
-    let children = iter_child_nodes(py, &bound)?;
-    if children.is_empty() && !bound.is_instance_of::<PyDict>() && !bound.is_instance_of::<PyList>()
-    {
-        return Err(PyTypeError::new_err(format!(
-            "Expected dict or list for wildcard '*', got {type_name}."
-        )));
+/// Rebuilds dicts, mappings (as dicts) and lists, applying `pipeline` to every
+/// other node. Tuples, sets and `None` count as leaves.
+fn map_leaves(
+    py: Python<'_>,
+    node: &PyObject,
+    pipeline: &BuiltinFilterPipeline,
+    depth: usize,
+) -> PyResult<PyObject> {
+    check_recursion_depth_limit(py, depth)?;
+    let bound = node.bind(py);
+    if let Some(mapping) = as_read_mapping(&bound) {
+        let out = PyDict::new_bound(py);
+        let keys = mapping.keys()?;
+        for (key, child) in keys.iter()?.zip(iter_child_nodes(py, &bound)?) {
+            out.set_item(key?, map_leaves(py, &child, pipeline, depth + 1)?)?;
+        }
+        return Ok(out.into());
     }
-
-    let out = PyList::empty_bound(py);
-    for child in children {
-        out.append(child)?;
+    if bound.is_instance_of::<PyList>() {
+        let out = PyList::empty_bound(py);
+        for child in iter_child_nodes(py, &bound)? {
+            out.append(map_leaves(py, &child, pipeline, depth + 1)?)?;
+        }
+        return Ok(out.into());
     }
-    Ok(out.into())
+    apply_builtin_pipeline(py, node.clone_ref(py), pipeline)
 }
 
-fn collect_descendants(py: Python<'_>, node: PyObject, out: &Bound<'_, PyList>) -> PyResult<()> {
+/// Rebuilds dicts (and mappings, as dicts) with sorted keys at every level.
+/// Lists keep their order; everything else is returned as-is.
+fn sort_keys_recursive(py: Python<'_>, node: &PyObject, depth: usize) -> PyResult<PyObject> {
+    check_recursion_depth_limit(py, depth)?;
     let bound = node.bind(py);
-    for child in iter_child_nodes(py, &bound)? {
-        out.append(child.clone_ref(py))?;
-        collect_descendants(py, child, out)?;
+    if let Some(mapping) = as_read_mapping(&bound) {
+        let keys = call_builtin1(py, "sorted", &mapping.keys()?.into_any().unbind())?;
+        let out = PyDict::new_bound(py);
+        for key in keys.bind(py).iter()? {
+            let key = key?;
+            let child: PyObject = mapping.get_item(&key)?.into();
+            out.set_item(key, sort_keys_recursive(py, &child, depth + 1)?)?;
+        }
+        return Ok(out.into());
     }
-    Ok(())
+    if bound.is_instance_of::<PyList>() {
+        let out = PyList::empty_bound(py);
+        for child in iter_child_nodes(py, &bound)? {
+            out.append(sort_keys_recursive(py, &child, depth + 1)?)?;
+        }
+        return Ok(out.into());
+    }
+    Ok(node.clone_ref(py))
 }
 
-fn resolve_deep_wildcard_token(py: Python<'_>, current: &PyObject) -> PyResult<PyObject> {
-    let bound = current.bind(py);
-    let type_name = get_type_name(&bound);
-    let direct_children = iter_child_nodes(py, &bound)?;
-    if direct_children.is_empty()
-        && !bound.is_instance_of::<PyDict>()
-        && !bound.is_instance_of::<PyList>()
-    {
-        return Err(PyTypeError::new_err(format!(
-            "Expected dict or list for wildcard '**', got {type_name}."
-        )));
+/// Returns `None` when `node` should be dropped from its parent. Containers
+/// are checked for emptiness after their own children were pruned.
+fn prune_nulls(
+    py: Python<'_>,
+    node: &PyObject,
+    drop_empty: bool,
+    depth: usize,
+) -> PyResult<Option<PyObject>> {
+    check_recursion_depth_limit(py, depth)?;
+    let bound = node.bind(py);
+    if bound.is_none() {
+        return Ok(None);
+    }
+    if let Some(mapping) = as_read_mapping(&bound) {
+        let out = PyDict::new_bound(py);
+        let keys = mapping.keys()?;
+        for (key, child) in keys.iter()?.zip(iter_child_nodes(py, &bound)?) {
+            if let Some(kept) = prune_nulls(py, &child, drop_empty, depth + 1)? {
+                out.set_item(key?, kept)?;
+            }
+        }
+        if drop_empty && out.is_empty() {
+            return Ok(None);
+        }
+        return Ok(Some(out.into()));
+    }
+    if bound.is_instance_of::<PyList>() {
+        let out = PyList::empty_bound(py);
+        for child in iter_child_nodes(py, &bound)? {
+            if let Some(kept) = prune_nulls(py, &child, drop_empty, depth + 1)? {
+                out.append(kept)?;
+            }
+        }
+        if drop_empty && out.is_empty() {
+            return Ok(None);
+        }
+        return Ok(Some(out.into()));
     }
+    if drop_empty && bound.is_instance_of::<PyString>() && bound.len()? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(node.clone_ref(py)))
+}
 
-    let out = PyList::empty_bound(py);
-    for child in direct_children {
-        out.append(child.clone_ref(py))?;
-        collect_descendants(py, child, &out)?;
+/// Rebuild `node` with every nested tuple turned into a list.
+fn normalize_tuples(py: Python<'_>, node: &PyObject, depth: usize) -> PyResult<PyObject> {
+    check_recursion_depth_limit(py, depth)?;
+    let bound = node.bind(py);
+    if let Some(mapping) = as_read_mapping(&bound) {
+        let out = PyDict::new_bound(py);
+        let keys = mapping.keys()?;
+        for (key, child) in keys.iter()?.zip(iter_child_nodes(py, &bound)?) {
+            out.set_item(key?, normalize_tuples(py, &child, depth + 1)?)?;
+        }
+        return Ok(out.into());
     }
-    Ok(out.into())
+    if is_list_or_tuple(&bound) {
+        let out = PyList::empty_bound(py);
+        for idx in 0..bound.len()? {
+            let child: PyObject = bound.get_item(idx)?.into();
+            out.append(normalize_tuples(py, &child, depth + 1)?)?;
+        }
+        return Ok(out.into());
+    }
+    Ok(node.clone_ref(py))
 }
 
 fn apply_output_transform(
@@ -813,131 +1671,24 @@ fn apply_output_transform(
     Ok(current.clone_ref(py))
 }
 
-fn resolve_index_token(
+/// Applies an output transform to each element of a list result, or to the value
+/// itself when it is not a list.
+fn apply_output_transform_to_elements(
     py: Python<'_>,
     current: &PyObject,
-    key: &str,
-    index: isize,
+    transform: &str,
+    root_data: &PyObject,
 ) -> PyResult<PyObject> {
-    let bound = current.bind(py);
-    let dict = bound.downcast::<PyDict>().map_err(|_| {
-        PyTypeError::new_err(format!(
-            "Expected a dict for key '{key}', got {}.",
-            get_type_name(&bound)
-        ))
-    })?;
-
-    let list_value = match dict.get_item(key)? {
-        Some(value) => value,
-        None => return Err(PyKeyError::new_err(key.to_string())),
+    let Some(pipeline) = compile_builtin_pipeline(py, transform, Some(root_data)) else {
+        return Ok(current.clone_ref(py));
     };
-    let list = list_value.downcast::<PyList>().map_err(|_| {
-        PyTypeError::new_err(format!(
-            "Expected a list for key '{key}', got {}.",
-            get_type_name(&list_value)
-        ))
-    })?;
-
-    let index_obj = index.to_object(py);
-    list.as_any().get_item(index_obj).map(|value| value.into())
-}
-
-fn resolve_root_index_token(
-    py: Python<'_>,
-    current: &PyObject,
-    index: isize,
-) -> PyResult<PyObject> {
-    let bound = current.bind(py);
-    let list = bound.downcast::<PyList>().map_err(|_| {
-        PyTypeError::new_err(format!(
-            "Expected a list for root index '[{index}]', got {}.",
-            get_type_name(&bound)
-        ))
-    })?;
-
-    let index_obj = index.to_object(py);
-    list.as_any().get_item(index_obj).map(|value| value.into())
-}
-
-fn resolve_slice_token(
-    py: Python<'_>,
-    current: &PyObject,
-    key: &str,
-    start: Option<isize>,
-    end: Option<isize>,
-) -> PyResult<PyObject> {
-    let bound = current.bind(py);
-    let dict = bound.downcast::<PyDict>().map_err(|_| {
-        PyTypeError::new_err(format!(
-            "Expected a dict for key '{key}', got {}.",
-            get_type_name(&bound)
-        ))
-    })?;
-
-    let list_value = match dict.get_item(key)? {
-        Some(value) => value,
-        None => return Err(PyKeyError::new_err(key.to_string())),
+    let Ok(items) = current.bind(py).downcast::<PyList>() else {
+        return apply_builtin_pipeline(py, current.clone_ref(py), &pipeline);
     };
-    let list = list_value.downcast::<PyList>().map_err(|_| {
-        PyTypeError::new_err(format!(
-            "Expected a list for key '{key}', got {}.",
-            get_type_name(&list_value)
-        ))
-    })?;
-
-    let len = list.len() as isize;
-
-    let mut slice_start = start.unwrap_or(0);
-    if slice_start < 0 {
-        slice_start += len;
-    }
-    if slice_start < 0 {
-        slice_start = 0;
-    }
-    if slice_start > len {
-        slice_start = len;
-    }
-
-    let mut slice_end = end.unwrap_or(len);
-    if slice_end < 0 {
-        slice_end += len;
-    }
-    if slice_end < 0 {
-        slice_end = 0;
-    }
-    if slice_end > len {
-        slice_end = len;
-    }
-
-    let out = PyList::empty_bound(py);
-    if slice_start >= slice_end {
-        return Ok(out.into());
-    }
-
-    for idx in slice_start..slice_end {
-        out.append(list.get_item(idx as usize)?)?;
-    }
-    Ok(out.into())
-}
-
-fn resolve_root_slice_token(
-    py: Python<'_>,
-    current: &PyObject,
-    start: Option<isize>,
-    end: Option<isize>,
-) -> PyResult<PyObject> {
-    let bound = current.bind(py);
-    let list = bound.downcast::<PyList>().map_err(|_| {
-        PyTypeError::new_err(format!(
-            "Expected a list for root slice, got {}.",
-            get_type_name(&bound)
-        ))
-    })?;
 
-    let indexes = compute_slice_indexes(list.len(), start, end);
     let out = PyList::empty_bound(py);
-    for idx in indexes {
-        out.append(list.get_item(idx)?)?;
+    for item in items.iter() {
+        out.append(apply_builtin_pipeline(py, item.into(), &pipeline)?)?;
     }
     Ok(out.into())
 }
@@ -955,6 +1706,16 @@ fn parse_literal(py: Python<'_>, value: &str) -> PyObject {
     }
 }
 
+/// Filter comparison values also accept the JSON spellings `null`, `true`, and `false`.
+fn parse_filter_literal(py: Python<'_>, value: &str) -> PyObject {
+    match value.trim() {
+        "null" => py.None(),
+        "true" => true.to_object(py),
+        "false" => false.to_object(py),
+        _ => parse_literal(py, value),
+    }
+}
+
 fn split_filter_args(args_string: &str) -> Option<Vec<String>> {
     let mut out: Vec<String> = Vec::new();
     let mut current = String::new();
@@ -1074,7 +1835,36 @@ fn parse_filter_args(
     Some(out)
 }
 
-fn compile_builtin_filter(py: Python<'_>, name: &str, args: &[PyObject]) -> Option<BuiltinFilter> {
+/// Resolves `$$root.<path>` arguments, treating unresolvable paths as `None`.
+fn parse_root_path_args(
+    py: Python<'_>,
+    args_string: &str,
+    root_data: &PyObject,
+) -> Option<Vec<PyObject>> {
+    let arg_tokens = split_filter_args(args_string)?;
+    let mut out: Vec<PyObject> = Vec::new();
+    for token in arg_tokens {
+        if !token.starts_with("$$root") {
+            return None;
+        }
+        let resolved = match resolve_root_reference_value(py, root_data, &token) {
+            Ok(value) => value,
+            Err(err) if is_resolution_error(py, &err) => py.None(),
+            Err(_) => return None,
+        };
+        out.push(resolved);
+    }
+    Some(out)
+}
+
+/// `root_data` is threaded into nested predicate/pipeline arguments
+/// (`$apply_if`, `$partition`) so their own `$$root` references resolve.
+fn compile_builtin_filter(
+    py: Python<'_>,
+    name: &str,
+    args: &[PyObject],
+    root_data: Option<&PyObject>,
+) -> Option<BuiltinFilter> {
     match (name, args.len()) {
         ("inc", 0) => Some(BuiltinFilter::Inc),
         ("dec", 0) => Some(BuiltinFilter::Dec),
@@ -1083,61 +1873,179 @@ fn compile_builtin_filter(py: Python<'_>, name: &str, args: &[PyObject]) -> Opti
         ("string", 0) => Some(BuiltinFilter::String),
         ("int", 0) => Some(BuiltinFilter::Int),
         ("float", 0) => Some(BuiltinFilter::Float),
+        ("parse_number", 0) => Some(BuiltinFilter::ParseNumber),
         ("decimal", 0) => Some(BuiltinFilter::Decimal),
         ("round", 0) => Some(BuiltinFilter::Round(None)),
         ("round", 1) => Some(BuiltinFilter::Round(Some(args[0].clone_ref(py)))),
+        ("round_sig", 1) => Some(BuiltinFilter::RoundSig(args[0].clone_ref(py))),
         ("floor", 0) => Some(BuiltinFilter::Floor),
         ("ceil", 0) => Some(BuiltinFilter::Ceil),
         ("quote", 0) => Some(BuiltinFilter::Quote),
         ("even", 0) => Some(BuiltinFilter::Even),
+        ("nonzero", 0) => Some(BuiltinFilter::SignCheck("!=")),
+        ("positive", 0) => Some(BuiltinFilter::SignCheck(">")),
+        ("negative", 0) => Some(BuiltinFilter::SignCheck("<")),
         ("odd", 0) => Some(BuiltinFilter::Odd),
         ("neg", 0) => Some(BuiltinFilter::Neg),
         ("pow", 1) => Some(BuiltinFilter::Pow(args[0].clone_ref(py))),
         ("rpow", 1) => Some(BuiltinFilter::RPow(args[0].clone_ref(py))),
         ("sqrt", 0) => Some(BuiltinFilter::Sqrt),
         ("root", 1) => Some(BuiltinFilter::Root(args[0].clone_ref(py))),
-        ("max", 0) => Some(BuiltinFilter::Max),
-        ("min", 0) => Some(BuiltinFilter::Min),
+        ("max", 0) => Some(BuiltinFilter::Max(None)),
+        ("max", 1) => Some(BuiltinFilter::Max(Some(args[0].clone_ref(py)))),
+        ("min", 0) => Some(BuiltinFilter::Min(None)),
+        ("min", 1) => Some(BuiltinFilter::Min(Some(args[0].clone_ref(py)))),
         ("len", 0) => Some(BuiltinFilter::Len),
         ("pick", n) => Some(BuiltinFilter::Pick(
             args.iter().take(n).map(|arg| arg.clone_ref(py)).collect(),
         )),
+        ("to_ordered", 0) => Some(BuiltinFilter::ToOrdered(None)),
+        ("to_ordered", 1) => Some(BuiltinFilter::ToOrdered(Some(args[0].clone_ref(py)))),
         ("unpick", n) => Some(BuiltinFilter::Unpick(
             args.iter().take(n).map(|arg| arg.clone_ref(py)).collect(),
         )),
+        ("flatten_keys", 0) => Some(BuiltinFilter::FlattenKeys(None)),
+        ("flatten_keys", 1) => Some(BuiltinFilter::FlattenKeys(Some(args[0].clone_ref(py)))),
+        ("nest_keys", 0) => Some(BuiltinFilter::NestKeys(None)),
+        ("nest_keys", 1) => Some(BuiltinFilter::NestKeys(Some(args[0].clone_ref(py)))),
+        ("deep_get", 1) => Some(BuiltinFilter::DeepGet(args[0].clone_ref(py))),
+        ("json_pointer", 1) => Some(BuiltinFilter::JsonPointer(args[0].clone_ref(py))),
+        ("title_keys", 0) => Some(BuiltinFilter::CaseKeys("title")),
+        ("lower_keys", 0) => Some(BuiltinFilter::CaseKeys("lower")),
+        ("upper_keys", 0) => Some(BuiltinFilter::CaseKeys("upper")),
+        ("wrap", 1) => Some(BuiltinFilter::Wrap(args[0].clone_ref(py))),
+        ("unwrap", 1) => Some(BuiltinFilter::Unwrap(args[0].clone_ref(py))),
         ("abs", 0) => Some(BuiltinFilter::Abs),
         ("clamp", 2) => Some(BuiltinFilter::Clamp(
             args[0].clone_ref(py),
             args[1].clone_ref(py),
         )),
+        ("clamp_each", 2) => Some(BuiltinFilter::ClampEach(
+            args[0].clone_ref(py),
+            args[1].clone_ref(py),
+        )),
         ("sign", 0) => Some(BuiltinFilter::Sign),
         ("log", 0) => Some(BuiltinFilter::Log(None)),
         ("log", 1) => Some(BuiltinFilter::Log(Some(args[0].clone_ref(py)))),
         ("exp", 0) => Some(BuiltinFilter::Exp),
         ("pct", 1) => Some(BuiltinFilter::Pct(args[0].clone_ref(py))),
+        ("ratio", 1) => Some(BuiltinFilter::Ratio(args[0].clone_ref(py))),
+        ("abs_error", 1) => Some(BuiltinFilter::AbsError(args[0].clone_ref(py))),
+        ("rel_error", 1) => Some(BuiltinFilter::RelError(args[0].clone_ref(py))),
         ("pctile", 1) => Some(BuiltinFilter::Pctile(args[0].clone_ref(py))),
+        ("winsorize", 1) => Some(BuiltinFilter::Winsorize(args[0].clone_ref(py))),
         ("median", 0) => Some(BuiltinFilter::Median),
         ("q1", 0) => Some(BuiltinFilter::Q1),
         ("q3", 0) => Some(BuiltinFilter::Q3),
         ("iqr", 0) => Some(BuiltinFilter::Iqr),
         ("mode", 0) => Some(BuiltinFilter::Mode),
         ("stdev", 0) => Some(BuiltinFilter::Stdev),
+        ("within", 2) => Some(BuiltinFilter::Within(
+            args[0].clone_ref(py),
+            args[1].clone_ref(py),
+        )),
         ("between", 2) => Some(BuiltinFilter::Between(
             args[0].clone_ref(py),
             args[1].clone_ref(py),
         )),
-        ("sum", 0) => Some(BuiltinFilter::Sum),
-        ("avg", 0) => Some(BuiltinFilter::Avg),
+        ("sum", 0) => Some(BuiltinFilter::Sum(None)),
+        ("sum", 1) => Some(BuiltinFilter::Sum(Some(args[0].clone_ref(py)))),
+        ("cumsum", 0) => Some(BuiltinFilter::Cumsum),
+        ("cumulative_pct", 0) => Some(BuiltinFilter::CumulativePct),
+        ("min_max_scale", 2) => Some(BuiltinFilter::MinMaxScale(
+            args[0].clone_ref(py),
+            args[1].clone_ref(py),
+        )),
+        ("fill_forward", 0) => Some(BuiltinFilter::FillNone(false)),
+        ("fill_backward", 0) => Some(BuiltinFilter::FillNone(true)),
+        ("running_avg", 1) => Some(BuiltinFilter::RunningAvg(args[0].clone_ref(py), false)),
+        ("running_avg", 2) => Some(BuiltinFilter::RunningAvg(
+            args[0].clone_ref(py),
+            args[1].bind(py).is_truthy().ok()?,
+        )),
+        ("avg", 0) => Some(BuiltinFilter::Avg(None)),
+        ("avg", 1) => Some(BuiltinFilter::Avg(Some(args[0].clone_ref(py)))),
         ("unique", 0) => Some(BuiltinFilter::Unique),
+        ("tally", 0) => Some(BuiltinFilter::Tally),
+        ("count_true", 0) => Some(BuiltinFilter::CountTrue),
+        ("proportion_true", 0) => Some(BuiltinFilter::ProportionTrue),
+        ("dedup_keys", 0) => Some(BuiltinFilter::DedupKeys),
+        ("to_set", 0) => Some(BuiltinFilter::ToSet),
+        ("union", 1) => Some(BuiltinFilter::SetOp("union", args[0].clone_ref(py))),
+        ("intersection", 1) => Some(BuiltinFilter::SetOp("intersection", args[0].clone_ref(py))),
+        ("difference", 1) => Some(BuiltinFilter::SetOp("difference", args[0].clone_ref(py))),
+        ("partition", 1) => {
+            let predicate = args[0].bind(py).extract::<String>().ok()?;
+            compile_builtin_or_boolean_predicate(py, &predicate, root_data)
+                .ok()
+                .flatten()
+                .map(BuiltinFilter::Partition)
+        }
+        ("apply_if", 2) => {
+            let predicate = args[0].bind(py).extract::<String>().ok()?;
+            let transform = args[1].bind(py).extract::<String>().ok()?;
+            let predicate =
+                compile_builtin_or_boolean_predicate(py, &predicate, root_data).ok()??;
+            let transform = compile_builtin_pipeline(py, &transform, root_data)?;
+            Some(BuiltinFilter::ApplyIf(predicate, transform))
+        }
+        ("map_recursive", 1) => {
+            let transform = args[0].bind(py).extract::<String>().ok()?;
+            let transform = compile_builtin_pipeline(py, &transform, root_data)?;
+            Some(BuiltinFilter::MapRecursive(transform))
+        }
+        ("switch", n) if n >= 2 => Some(BuiltinFilter::Switch(
+            args.iter().map(|arg| arg.clone_ref(py)).collect(),
+        )),
+        ("lookup", 1) => Some(BuiltinFilter::Lookup(args[0].clone_ref(py), None)),
+        ("lookup", 2) => Some(BuiltinFilter::Lookup(
+            args[0].clone_ref(py),
+            Some(args[1].clone_ref(py)),
+        )),
+        ("with_default_map", 1) => Some(BuiltinFilter::WithDefaultMap(args[0].clone_ref(py))),
+        ("require", n) if n >= 1 => Some(BuiltinFilter::Require(
+            args.iter().map(|arg| arg.clone_ref(py)).collect(),
+        )),
+        ("symdiff", 1) | ("xor_lists", 1) => Some(BuiltinFilter::SymDiff(args[0].clone_ref(py))),
         ("reverse", 0) => Some(BuiltinFilter::Reverse),
         ("chunk", 1) => Some(BuiltinFilter::Chunk(args[0].clone_ref(py))),
+        ("split_at", 1) => Some(BuiltinFilter::SplitAt(args[0].clone_ref(py))),
+        ("rotate", 1) => Some(BuiltinFilter::Rotate(args[0].clone_ref(py))),
+        ("transpose", 0) => Some(BuiltinFilter::Transpose(false, None)),
+        ("transpose", 1) => Some(BuiltinFilter::Transpose(
+            args[0].bind(py).is_truthy().ok()?,
+            None,
+        )),
+        ("transpose", 2) => Some(BuiltinFilter::Transpose(
+            args[0].bind(py).is_truthy().ok()?,
+            Some(args[1].clone_ref(py)),
+        )),
+        ("zip", 1) => Some(BuiltinFilter::Zip(args[0].clone_ref(py))),
+        ("interleave", 1) => Some(BuiltinFilter::Interleave(args[0].clone_ref(py))),
+        ("repeat", 1) => Some(BuiltinFilter::Repeat(args[0].clone_ref(py))),
+        ("clamp_len", 1) => Some(BuiltinFilter::ClampLen(args[0].clone_ref(py))),
+        ("pad_list", 1) => Some(BuiltinFilter::PadList(args[0].clone_ref(py), None, false)),
+        ("pad_list", 2) => Some(BuiltinFilter::PadList(
+            args[0].clone_ref(py),
+            Some(args[1].clone_ref(py)),
+            false,
+        )),
+        ("pad_list", 3) => Some(BuiltinFilter::PadList(
+            args[0].clone_ref(py),
+            Some(args[1].clone_ref(py)),
+            args[2].bind(py).is_truthy().ok()?,
+        )),
         ("flatten", 0) => Some(BuiltinFilter::Flatten),
         ("flatten_deep", 0) => Some(BuiltinFilter::FlattenDeep),
         ("sorted", 0) => Some(BuiltinFilter::Sorted(None)),
         ("sorted", 1) => Some(BuiltinFilter::Sorted(Some(args[0].clone_ref(py)))),
+        ("sorted_by_abs", 0) => Some(BuiltinFilter::SortedByAbs(None)),
+        ("sorted_by_abs", 1) => Some(BuiltinFilter::SortedByAbs(Some(args[0].clone_ref(py)))),
         ("first", 0) => Some(BuiltinFilter::First),
         ("last", 0) => Some(BuiltinFilter::Last),
         ("contains", 1) => Some(BuiltinFilter::Contains(args[0].clone_ref(py))),
+        ("index_of", 1) => Some(BuiltinFilter::IndexOf(args[0].clone_ref(py))),
+        ("count_occurrences", 1) => Some(BuiltinFilter::CountOccurrences(args[0].clone_ref(py))),
         ("in", 1) => Some(BuiltinFilter::In(args[0].clone_ref(py))),
         ("lower", 0) => Some(BuiltinFilter::Lower),
         ("upper", 0) => Some(BuiltinFilter::Upper),
@@ -1148,20 +2056,51 @@ fn compile_builtin_filter(py: Python<'_>, name: &str, args: &[PyObject]) -> Opti
             args[0].clone_ref(py),
             args[1].clone_ref(py),
         )),
+        ("replace_map", 1) => Some(BuiltinFilter::ReplaceMap(args[0].clone_ref(py))),
         ("regex_replace", 2) => Some(BuiltinFilter::RegexReplace(
             args[0].clone_ref(py),
             args[1].clone_ref(py),
         )),
         ("split", 0) => Some(BuiltinFilter::Split(None)),
         ("split", 1) => Some(BuiltinFilter::Split(Some(args[0].clone_ref(py)))),
+        ("split_lines", 0) => Some(BuiltinFilter::SplitLines(false)),
+        ("split_lines", 1) => Some(BuiltinFilter::SplitLines(
+            args[0].bind(py).is_truthy().ok()?,
+        )),
+        ("strip_html", 0) => Some(BuiltinFilter::StripHtml(false)),
+        ("escape_html", 0) => Some(BuiltinFilter::Html("escape")),
+        ("unescape_html", 0) => Some(BuiltinFilter::Html("unescape")),
+        ("strip_html", 1) => Some(BuiltinFilter::StripHtml(args[0].bind(py).is_truthy().ok()?)),
         ("join", 1) => Some(BuiltinFilter::Join(args[0].clone_ref(py))),
+        ("to_csv_row", 0) => Some(BuiltinFilter::ToCsvRow(None)),
+        ("to_csv_row", 1) => Some(BuiltinFilter::ToCsvRow(Some(args[0].clone_ref(py)))),
         ("startswith", 1) => Some(BuiltinFilter::Startswith(args[0].clone_ref(py))),
         ("endswith", 1) => Some(BuiltinFilter::Endswith(args[0].clone_ref(py))),
         ("matches", 1) => Some(BuiltinFilter::Matches(args[0].clone_ref(py))),
+        ("similarity", 1) => Some(BuiltinFilter::Similarity(args[0].clone_ref(py))),
+        ("indent", 1) => Some(BuiltinFilter::Indent(args[0].clone_ref(py))),
+        ("dedent", 0) => Some(BuiltinFilter::Dedent),
+        ("wrap_text", 1) => Some(BuiltinFilter::WrapText(args[0].clone_ref(py), None)),
+        ("wrap_text", 2) => Some(BuiltinFilter::WrapText(
+            args[0].clone_ref(py),
+            Some(args[1].clone_ref(py)),
+        )),
         ("keys", 0) => Some(BuiltinFilter::Keys),
+        ("keys_matching" | "select_keys", 1 | 2) => {
+            let syntax = args.get(1).map(|syntax| syntax.clone_ref(py));
+            let compiled = compile_key_pattern(py, name, &args[0], syntax.as_ref()).ok();
+            let pattern = args[0].clone_ref(py);
+            Some(if name == "keys_matching" {
+                BuiltinFilter::KeysMatching(compiled, pattern, syntax)
+            } else {
+                BuiltinFilter::SelectKeys(compiled, pattern, syntax)
+            })
+        }
         ("values", 0) => Some(BuiltinFilter::Values),
         ("items", 0) => Some(BuiltinFilter::Items),
         ("sort_by", 1) => Some(BuiltinFilter::SortBy(args[0].clone_ref(py), None)),
+        ("rank", 0) => Some(BuiltinFilter::Rank(None)),
+        ("rank", 1) => Some(BuiltinFilter::Rank(Some(args[0].clone_ref(py)))),
         ("sort_by", 2) => Some(BuiltinFilter::SortBy(
             args[0].clone_ref(py),
             Some(args[1].clone_ref(py)),
@@ -1169,25 +2108,112 @@ fn compile_builtin_filter(py: Python<'_>, name: &str, args: &[PyObject]) -> Opti
         ("unique_by", 1) => Some(BuiltinFilter::UniqueBy(args[0].clone_ref(py))),
         ("index_by", 1) => Some(BuiltinFilter::IndexBy(args[0].clone_ref(py))),
         ("group_by", 1) => Some(BuiltinFilter::GroupBy(args[0].clone_ref(py))),
+        ("pivot", 3) => Some(BuiltinFilter::Pivot(
+            args[0].clone_ref(py),
+            args[1].clone_ref(py),
+            args[2].clone_ref(py),
+            None,
+        )),
+        ("pivot", 4) => Some(BuiltinFilter::Pivot(
+            args[0].clone_ref(py),
+            args[1].clone_ref(py),
+            args[2].clone_ref(py),
+            Some(args[3].clone_ref(py)),
+        )),
+        ("sum_by", 2) => Some(BuiltinFilter::AggregateBy(
+            "sum_by",
+            args[0].clone_ref(py),
+            args[1].clone_ref(py),
+        )),
+        ("avg_by", 2) => Some(BuiltinFilter::AggregateBy(
+            "avg_by",
+            args[0].clone_ref(py),
+            args[1].clone_ref(py),
+        )),
         ("const", 1) => Some(BuiltinFilter::Const(args[0].clone_ref(py))),
         ("default", 1) => Some(BuiltinFilter::Default(args[0].clone_ref(py))),
+        ("coalesce_empty_to", 1) => Some(BuiltinFilter::CoalesceEmptyTo(args[0].clone_ref(py))),
+        ("typed_default", 1) => Some(BuiltinFilter::TypedDefault(args[0].clone_ref(py))),
         ("coalesce", n) if n >= 1 => Some(BuiltinFilter::Coalesce(
             args.iter().map(|arg| arg.clone_ref(py)).collect(),
         )),
-        ("bool", 0) => Some(BuiltinFilter::Bool),
+        ("coalesce_flat", 0) => Some(BuiltinFilter::CoalesceFlat),
+        ("coalesce_paths", n) if n >= 1 => Some(BuiltinFilter::CoalescePaths(
+            args.iter().map(|arg| arg.clone_ref(py)).collect(),
+        )),
+        ("bool", 0) => Some(BuiltinFilter::Bool(false)),
+        ("bool", 1) => Some(BuiltinFilter::Bool(args[0].bind(py).is_truthy().ok()?)),
+        ("to_bool_strict", 0) => Some(BuiltinFilter::ToBoolStrict),
+        ("coerce", 1) => Some(BuiltinFilter::Coerce(args[0].clone_ref(py))),
+        ("coerce_keys", 1) => Some(BuiltinFilter::CoerceKeys(args[0].clone_ref(py))),
+        ("coerce_list_of", 1) => Some(BuiltinFilter::CoerceListOf(args[0].clone_ref(py), false)),
+        ("coerce_list_of", 2) => Some(BuiltinFilter::CoerceListOf(
+            args[0].clone_ref(py),
+            args[1].bind(py).is_truthy().ok()?,
+        )),
         ("type_is", 1) => Some(BuiltinFilter::TypeIs(args[0].clone_ref(py))),
+        ("assert_type", 1) => Some(BuiltinFilter::AssertType(args[0].clone_ref(py))),
         ("is_empty", 0) => Some(BuiltinFilter::IsEmpty),
         ("non_empty", 0) => Some(BuiltinFilter::NonEmpty),
         ("compact", 0) => Some(BuiltinFilter::Compact),
+        ("prune_nulls", 0) => Some(BuiltinFilter::PruneNulls(false)),
+        ("sort_keys_recursive", 0) => Some(BuiltinFilter::SortKeysRecursive),
+        ("prune_nulls", 1) => Some(BuiltinFilter::PruneNulls(
+            args[0].bind(py).is_truthy().ok()?,
+        )),
+        ("nan_to", 1) => Some(BuiltinFilter::NanTo(args[0].clone_ref(py), false)),
+        ("nan_to", 2) => Some(BuiltinFilter::NanTo(
+            args[0].clone_ref(py),
+            args[1].bind(py).is_truthy().ok()?,
+        )),
         ("from_json", 0) => Some(BuiltinFilter::FromJson),
         ("to_json", 0) => Some(BuiltinFilter::ToJson),
+        ("canonical_json", 0) => Some(BuiltinFilter::CanonicalJson),
+        ("json_path", 1) => Some(BuiltinFilter::JsonPath(args[0].clone_ref(py))),
+        ("hash", 1) => Some(BuiltinFilter::Hash(args[0].clone_ref(py))),
+        ("crc32", 0) => Some(BuiltinFilter::Checksum("crc32", None)),
+        ("crc32", 1) => Some(BuiltinFilter::Checksum(
+            "crc32",
+            Some(args[0].clone_ref(py)),
+        )),
+        ("adler32", 0) => Some(BuiltinFilter::Checksum("adler32", None)),
+        ("adler32", 1) => Some(BuiltinFilter::Checksum(
+            "adler32",
+            Some(args[0].clone_ref(py)),
+        )),
+        ("md5", 0) | ("sha1", 0) | ("sha256", 0) => Some(BuiltinFilter::Hash(name.to_object(py))),
         ("to_datetime", 0) => Some(BuiltinFilter::ToDatetime(None)),
         ("to_datetime", 1) => Some(BuiltinFilter::ToDatetime(Some(args[0].clone_ref(py)))),
         ("strftime", 1) => Some(BuiltinFilter::Strftime(args[0].clone_ref(py))),
         ("timestamp", 0) => Some(BuiltinFilter::Timestamp),
+        ("timestamp_ms", 0) => Some(BuiltinFilter::TimestampMs),
+        ("from_timestamp_ms", 0) => Some(BuiltinFilter::FromTimestampMs),
         ("age_seconds", 0) => Some(BuiltinFilter::AgeSeconds),
+        ("age_days", 0) => Some(BuiltinFilter::AgeDays),
+        ("humanize_duration", 0) => Some(BuiltinFilter::HumanizeDuration(false)),
+        ("humanize_duration", 1) => Some(BuiltinFilter::HumanizeDuration(
+            args[0].bind(py).is_truthy().ok()?,
+        )),
+        ("duration", 1) => Some(BuiltinFilter::Duration(args[0].clone_ref(py))),
+        ("business_days_between", 1) => Some(BuiltinFilter::BusinessDaysBetween(
+            args[0].clone_ref(py),
+            None,
+        )),
+        ("business_days_between", 2) => Some(BuiltinFilter::BusinessDaysBetween(
+            args[0].clone_ref(py),
+            Some(args[1].clone_ref(py)),
+        )),
+        ("date_trunc", 1) => Some(BuiltinFilter::DateTrunc(args[0].clone_ref(py))),
+        ("iso_week", 0) => Some(BuiltinFilter::IsoWeek),
+        ("iso_year", 0) => Some(BuiltinFilter::IsoYear),
+        ("is_weekend", 0) => Some(BuiltinFilter::IsWeekend),
+        ("is_weekday", 0) => Some(BuiltinFilter::IsWeekday),
         ("before", 1) => Some(BuiltinFilter::Before(args[0].clone_ref(py))),
         ("after", 1) => Some(BuiltinFilter::After(args[0].clone_ref(py))),
+        ("between_dates", 2) => Some(BuiltinFilter::BetweenDates(
+            args[0].clone_ref(py),
+            args[1].clone_ref(py),
+        )),
         ("gt", 1) => Some(BuiltinFilter::Gt(args[0].clone_ref(py))),
         ("lt", 1) => Some(BuiltinFilter::Lt(args[0].clone_ref(py))),
         ("gte", 1) => Some(BuiltinFilter::Gte(args[0].clone_ref(py))),
@@ -1202,26 +2228,292 @@ fn compile_builtin_filter(py: Python<'_>, name: &str, args: &[PyObject]) -> Opti
     }
 }
 
-fn compile_builtin_pipeline(
-    py: Python<'_>,
-    expression: &str,
-    root_data: Option<&PyObject>,
-) -> Option<BuiltinFilterPipeline> {
-    if !expression.starts_with('$') {
-        return None;
+fn split_pipeline_segments(expression: &str) -> Vec<&str> {
+    let mut out: Vec<&str> = Vec::new();
+    let mut depth = 0i32;
+    let mut quotes = QuoteState::default();
+    let mut start = 0usize;
+
+    for (idx, ch) in expression.char_indices() {
+        if !quotes.consume(ch) {
+            match ch {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                '|' if depth == 0 => {
+                    out.push(&expression[start..idx]);
+                    start = idx + 1;
+                }
+                _ => {}
+            }
+        }
+    }
+    out.push(&expression[start..]);
+    out
+}
+
+/// Compiles a `$keys_matching`/`$select_keys` pattern once, when the pipeline is compiled.
+/// Glob patterns go through `fnmatch.translate`, so they must match the whole key.
+fn compile_key_pattern(
+    py: Python<'_>,
+    filter_name: &str,
+    pattern: &PyObject,
+    syntax: Option<&PyObject>,
+) -> PyResult<PyObject> {
+    let pattern = extract_string_arg(py, pattern, filter_name, "pattern")?;
+    let syntax = match syntax {
+        Some(syntax) => extract_string_arg(py, syntax, filter_name, "syntax")?,
+        None => "regex".to_string(),
+    };
+    let source = match syntax.as_str() {
+        "regex" => pattern,
+        "glob" => {
+            let translated = py
+                .import_bound("fnmatch")?
+                .getattr("translate")?
+                .call1((pattern,))?
+                .extract::<String>()?;
+            format!("\\A{translated}")
+        }
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "Filter '${filter_name}' got unsupported syntax '{other}'. Expected one of: regex, glob."
+            )))
+        }
+    };
+    py.import_bound("re")?
+        .getattr("compile")?
+        .call1((source,))
+        .map(|compiled| compiled.into())
+}
+
+/// One `$json_path` step; after `..` the selector also applies to every descendant.
+struct JsonPathSegment {
+    recursive: bool,
+    selector: JsonPathSelector,
+}
+
+enum JsonPathSelector {
+    Name(String),
+    Wildcard,
+    Index(isize),
+    Slice(Option<isize>, Option<isize>),
+}
+
+/// Parses the supported JSONPath subset: `$`, `.name`, `['name']`, `*`, `[n]`,
+/// `[start:end]`, and `..` recursive descent.
+fn parse_json_path(expression: &str) -> Result<Vec<JsonPathSegment>, String> {
+    let chars: Vec<char> = expression.trim().chars().collect();
+    if chars.first() != Some(&'$') {
+        return Err("it must start with '$'".to_string());
+    }
+
+    let mut segments = Vec::new();
+    let mut pos = 1;
+    while pos < chars.len() {
+        match chars[pos] {
+            '.' => {
+                let recursive = chars.get(pos + 1) == Some(&'.');
+                pos += if recursive { 2 } else { 1 };
+                if recursive && chars.get(pos) == Some(&'[') {
+                    let (selector, next) = parse_json_path_bracket(&chars, pos)?;
+                    segments.push(JsonPathSegment {
+                        recursive,
+                        selector,
+                    });
+                    pos = next;
+                    continue;
+                }
+                let start = pos;
+                while pos < chars.len() && chars[pos] != '.' && chars[pos] != '[' {
+                    pos += 1;
+                }
+                let name: String = chars[start..pos].iter().collect();
+                let selector = match name.as_str() {
+                    "" => return Err(format!("expected a name at position {start}")),
+                    "*" => JsonPathSelector::Wildcard,
+                    _ => JsonPathSelector::Name(name),
+                };
+                segments.push(JsonPathSegment {
+                    recursive,
+                    selector,
+                });
+            }
+            '[' => {
+                let (selector, next) = parse_json_path_bracket(&chars, pos)?;
+                segments.push(JsonPathSegment {
+                    recursive: false,
+                    selector,
+                });
+                pos = next;
+            }
+            other => return Err(format!("unexpected '{other}' at position {pos}")),
+        }
+    }
+    Ok(segments)
+}
+
+fn parse_json_path_bracket(
+    chars: &[char],
+    open: usize,
+) -> Result<(JsonPathSelector, usize), String> {
+    let mut pos = open + 1;
+    let selector = if let Some(&quote @ ('\'' | '"')) = chars.get(pos) {
+        pos += 1;
+        let mut name = String::new();
+        loop {
+            match chars.get(pos) {
+                None => return Err("unterminated quoted name".to_string()),
+                Some('\\') => {
+                    if let Some(&escaped) = chars.get(pos + 1) {
+                        name.push(escaped);
+                    }
+                    pos += 2;
+                }
+                Some(&ch) if ch == quote => {
+                    pos += 1;
+                    break;
+                }
+                Some(&ch) => {
+                    name.push(ch);
+                    pos += 1;
+                }
+            }
+        }
+        JsonPathSelector::Name(name)
+    } else {
+        let start = pos;
+        while pos < chars.len() && chars[pos] != ']' {
+            pos += 1;
+        }
+        let content: String = chars[start..pos].iter().collect();
+        parse_json_path_index(content.trim())?
+    };
+    if chars.get(pos) != Some(&']') {
+        return Err(format!("expected ']' at position {pos}"));
+    }
+    Ok((selector, pos + 1))
+}
+
+fn parse_json_path_index(content: &str) -> Result<JsonPathSelector, String> {
+    if content == "*" {
+        return Ok(JsonPathSelector::Wildcard);
+    }
+    let parse_bound = |text: &str| -> Result<Option<isize>, String> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Ok(None);
+        }
+        text.parse::<isize>()
+            .map(Some)
+            .map_err(|_| format!("unsupported selector '[{content}]'"))
+    };
+    if let Some((start, end)) = content.split_once(':') {
+        return Ok(JsonPathSelector::Slice(
+            parse_bound(start)?,
+            parse_bound(end)?,
+        ));
+    }
+    match parse_bound(content)? {
+        Some(index) => Ok(JsonPathSelector::Index(index)),
+        None => Err("empty brackets".to_string()),
+    }
+}
+
+/// Array items or object values; JSONPath treats lists and tuples as arrays.
+fn json_path_children(py: Python<'_>, node: &Bound<'_, PyAny>) -> PyResult<Vec<PyObject>> {
+    if is_list_or_tuple(node) {
+        return node
+            .iter()?
+            .map(|item| item.map(|item| item.into()))
+            .collect();
+    }
+    iter_child_nodes(py, node)
+}
+
+/// `node` followed by all of its descendants, in document order.
+fn json_path_descendants(
+    py: Python<'_>,
+    node: &PyObject,
+    out: &mut Vec<PyObject>,
+    depth: usize,
+) -> PyResult<()> {
+    check_recursion_depth_limit(py, depth)?;
+    out.push(node.clone_ref(py));
+    check_result_size_limit(py, out.len())?;
+    for child in json_path_children(py, node.bind(py))? {
+        json_path_descendants(py, &child, out, depth + 1)?;
+    }
+    Ok(())
+}
+
+fn json_path_select(
+    py: Python<'_>,
+    node: &PyObject,
+    selector: &JsonPathSelector,
+    out: &mut Vec<PyObject>,
+) -> PyResult<()> {
+    let bound = node.bind(py);
+    match selector {
+        JsonPathSelector::Name(name) => {
+            if let Some(mapping) = as_read_mapping(&bound) {
+                if mapping.contains(name)? {
+                    out.push(mapping.get_item(name)?.into());
+                }
+            }
+        }
+        JsonPathSelector::Wildcard => out.extend(json_path_children(py, &bound)?),
+        JsonPathSelector::Index(index) => {
+            if is_list_or_tuple(&bound) {
+                let len = bound.len()? as isize;
+                let index = if *index < 0 { len + index } else { *index };
+                if (0..len).contains(&index) {
+                    out.push(bound.get_item(index as usize)?.into());
+                }
+            }
+        }
+        JsonPathSelector::Slice(start, end) => {
+            if is_list_or_tuple(&bound) {
+                let len = bound.len()? as isize;
+                let clamp = |position: isize| {
+                    if position < 0 {
+                        (len + position).max(0)
+                    } else {
+                        position.min(len)
+                    }
+                };
+                let start = start.map_or(0, clamp);
+                let end = end.map_or(len, clamp);
+                for index in start..end {
+                    out.push(bound.get_item(index as usize)?.into());
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn compile_builtin_pipeline(
+    py: Python<'_>,
+    expression: &str,
+    root_data: Option<&PyObject>,
+) -> Option<BuiltinFilterPipeline> {
+    if !expression.starts_with('$') {
+        return None;
     }
 
     let mut out: BuiltinFilterPipeline = Vec::new();
-    for segment in expression.split('|') {
+    for segment in split_pipeline_segments(expression) {
         let captures = PATH_FILTER_SEGMENT_RE.captures(segment)?;
         let name = captures.get(1)?.as_str();
-        let args = if let Some(args_match) = captures.get(2) {
-            parse_filter_args(py, args_match.as_str(), root_data)?
-        } else {
-            Vec::new()
+        let args = match captures.get(2) {
+            Some(args_match) if name == "coalesce_paths" => {
+                parse_root_path_args(py, args_match.as_str(), root_data?)?
+            }
+            Some(args_match) => parse_filter_args(py, args_match.as_str(), root_data)?,
+            None => Vec::new(),
         };
         let map_suffix = captures.get(3).is_some();
-        let filter = compile_builtin_filter(py, name, &args)?;
+        let filter = compile_builtin_filter(py, name, &args, root_data)?;
         out.push(BuiltinFilterStep { filter, map_suffix });
     }
 
@@ -1321,6 +2613,32 @@ fn is_list_or_tuple(bound: &Bound<'_, PyAny>) -> bool {
     bound.is_instance_of::<PyList>() || bound.is_instance_of::<PyTuple>()
 }
 
+fn apply_set_operation(
+    py: Python<'_>,
+    value: &PyObject,
+    method: &str,
+    filter_name: &str,
+    other: &PyObject,
+) -> PyResult<PyObject> {
+    if !is_set_operand(&other.bind(py)) {
+        return Err(PyTypeError::new_err(format!(
+            "Filter '${filter_name}' expects other to be a list."
+        )));
+    }
+    let left = call_builtin1(py, "set", value)?;
+    let result: PyObject = left
+        .bind(py)
+        .call_method1(method, (other.clone_ref(py),))?
+        .into();
+    call_builtin1(py, "sorted", &result)
+}
+
+fn is_set_operand(bound: &Bound<'_, PyAny>) -> bool {
+    is_list_or_tuple(bound)
+        || bound.is_instance_of::<PySet>()
+        || bound.is_instance_of::<PyFrozenSet>()
+}
+
 fn collect_sequence_items(py: Python<'_>, value: &PyObject) -> PyResult<Option<Vec<PyObject>>> {
     let value_bound = value.bind(py);
     if !is_list_or_tuple(&value_bound) {
@@ -1336,6 +2654,155 @@ fn collect_sequence_items(py: Python<'_>, value: &PyObject) -> PyResult<Option<V
     Ok(Some(out))
 }
 
+/// `int` (not `bool`), `float`, or `Decimal`.
+fn is_number(py: Python<'_>, bound: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if bound.is_instance_of::<PyBool>() {
+        return Ok(false);
+    }
+    if bound.is_instance_of::<PyInt>() || bound.is_instance_of::<PyFloat>() {
+        return Ok(true);
+    }
+    bound.is_instance(&py.import_bound("decimal")?.getattr("Decimal")?)
+}
+
+/// Applies the `'strict'` (default) / `'skip'` policy of `$sum`, `$avg`, `$min`
+/// and `$max`. `'skip'` keeps only `is_number` items; the flag reports whether it
+/// was requested.
+fn aggregate_policy_items(
+    py: Python<'_>,
+    value: &PyObject,
+    policy: &Option<PyObject>,
+    filter_name: &str,
+) -> PyResult<(PyObject, bool)> {
+    let skip = match policy {
+        Some(policy) => match extract_string_arg(py, policy, filter_name, "policy")?.as_str() {
+            "strict" => false,
+            "skip" => true,
+            other => {
+                return Err(PyValueError::new_err(format!(
+                    "Filter '${filter_name}' got unsupported policy '{other}'. Expected one of: strict, skip."
+                )))
+            }
+        },
+        None => false,
+    };
+    if !skip {
+        return Ok((value.clone_ref(py), false));
+    }
+
+    let out = PyList::empty_bound(py);
+    for item in value.bind(py).iter()? {
+        let item = item?;
+        if is_number(py, &item)? {
+            out.append(item)?;
+        }
+    }
+    Ok((out.into(), true))
+}
+
+/// `abs(value - expected)` when both sides are int/float/Decimal, otherwise
+/// `None` (including `Decimal` mixed with `float`, which Python refuses).
+fn numeric_abs_error(
+    py: Python<'_>,
+    value: &PyObject,
+    expected: &PyObject,
+) -> PyResult<Option<PyObject>> {
+    if !is_number(py, value.bind(py))? || !is_number(py, expected.bind(py))? {
+        return Ok(None);
+    }
+    match value.bind(py).sub(expected.bind(py)) {
+        Ok(difference) => Ok(Some(difference.abs()?.into())),
+        Err(err) if err.is_instance_of::<PyTypeError>(py) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+fn running_sums(py: Python<'_>, items: &[PyObject]) -> PyResult<Vec<PyObject>> {
+    let mut out: Vec<PyObject> = Vec::with_capacity(items.len());
+    for item in items {
+        let next = match out.last() {
+            Some(previous) => apply_binary_op(py, previous, "__add__", item)?,
+            None => item.clone_ref(py),
+        };
+        out.push(next);
+    }
+    Ok(out)
+}
+
+/// Converts `value` with the builtin named by `type_name` (`int`, `float`, `str`,
+/// `decimal`, `bool`), returning `None` for `None` input or a failed conversion.
+fn coerce_value(py: Python<'_>, value: &PyObject, type_name: &str) -> PyResult<Option<PyObject>> {
+    let filter = match type_name {
+        "int" => BuiltinFilter::Int,
+        "float" => BuiltinFilter::Float,
+        "str" | "string" => BuiltinFilter::String,
+        "decimal" => BuiltinFilter::Decimal,
+        "bool" => BuiltinFilter::Bool(true),
+        other => {
+            return Err(PyValueError::new_err(format!(
+            "Unsupported coercion type '{other}'. Expected one of: int, float, str, decimal, bool."
+        )))
+        }
+    };
+    if value.bind(py).is_none() {
+        return Ok(None);
+    }
+    match apply_builtin_filter(py, value, &filter) {
+        Ok(coerced) if coerced.bind(py).is_none() => Ok(None),
+        Ok(coerced) => Ok(Some(coerced)),
+        Err(err)
+            if err.is_instance_of::<PyValueError>(py)
+                || err.is_instance_of::<PyTypeError>(py)
+                || err.is_instance_of::<PyArithmeticError>(py) =>
+        {
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Order-preserving dedupe of a list or tuple. Hashable items go through
+/// `dict.fromkeys`; unhashable items fall back to an equality scan.
+fn unique_items(py: Python<'_>, value: &PyObject) -> PyResult<PyObject> {
+    let fromkeys = py
+        .import_bound("builtins")?
+        .getattr("dict")?
+        .getattr("fromkeys")?;
+    match fromkeys.call1((value.clone_ref(py),)) {
+        Ok(dedup_dict) => return call_builtin1(py, "list", &dedup_dict.into()),
+        Err(err) if err.is_instance_of::<PyTypeError>(py) => {}
+        Err(err) => return Err(err),
+    }
+
+    let out = PyList::empty_bound(py);
+    let mut seen: Vec<PyObject> = Vec::new();
+    for item in value.bind(py).iter()? {
+        let item: PyObject = item?.into();
+        let mut is_duplicate = false;
+        for existing in &seen {
+            if compare_values(py, existing, &item, "==").unwrap_or(false) {
+                is_duplicate = true;
+                break;
+            }
+        }
+        if !is_duplicate {
+            seen.push(item.clone_ref(py));
+            out.append(item)?;
+        }
+    }
+    Ok(out.into())
+}
+
+/// Bytes for hash/checksum builtins: bytes pass through, anything else is
+/// stringified and UTF-8 encoded.
+fn hash_payload<'py>(py: Python<'py>, value: &PyObject) -> PyResult<Bound<'py, PyAny>> {
+    let value_bound = value.bind(py);
+    if value_bound.is_instance_of::<PyBytes>() {
+        return Ok(value_bound.clone());
+    }
+    value_bound.str()?.call_method1("encode", ("utf-8",))
+}
+
 fn extract_string_arg(
     py: Python<'_>,
     value: &PyObject,
@@ -1349,6 +2816,19 @@ fn extract_string_arg(
     })
 }
 
+fn extract_list_arg(
+    py: Python<'_>,
+    value: &PyObject,
+    filter_name: &str,
+    arg_name: &str,
+) -> PyResult<Vec<PyObject>> {
+    collect_sequence_items(py, value)?.ok_or_else(|| {
+        PyTypeError::new_err(format!(
+            "Filter '${filter_name}' expects {arg_name} to be a list or tuple."
+        ))
+    })
+}
+
 fn as_datetime(
     py: Python<'_>,
     value: &PyObject,
@@ -1384,6 +2864,154 @@ fn as_datetime(
     Ok(Some(dt.into()))
 }
 
+/// Proleptic Gregorian ordinal of a datetime-like value's calendar date.
+fn datetime_ordinal(py: Python<'_>, value: &PyObject) -> PyResult<Option<i64>> {
+    let Some(dt) = as_datetime_or_none(py, value)? else {
+        return Ok(None);
+    };
+    dt.bind(py)
+        .call_method0("toordinal")?
+        .extract::<i64>()
+        .map(Some)
+}
+
+/// Number of Monday-Friday days with ordinal in `1..ordinal` (ordinal 1 is a Monday).
+fn weekdays_before(ordinal: i64) -> i64 {
+    let days = ordinal - 1;
+    days.div_euclid(7) * 5 + days.rem_euclid(7).min(5)
+}
+
+/// The unix epoch as an aware UTC datetime, and a one-millisecond timedelta.
+fn epoch_and_millisecond(py: Python<'_>) -> PyResult<(Bound<'_, PyAny>, Bound<'_, PyAny>)> {
+    let datetime_mod = py.import_bound("datetime")?;
+    let utc = datetime_mod.getattr("timezone")?.getattr("utc")?;
+    let epoch = datetime_mod
+        .getattr("datetime")?
+        .call1((1970, 1, 1, 0, 0, 0, 0, utc))?;
+    let millisecond = datetime_mod.getattr("timedelta")?.call1((0, 0, 0, 1))?;
+    Ok((epoch, millisecond))
+}
+
+fn as_datetime_or_none(py: Python<'_>, value: &PyObject) -> PyResult<Option<PyObject>> {
+    match as_datetime(py, value, None) {
+        Err(err) if err.is_instance_of::<PyValueError>(py) => Ok(None),
+        result => result,
+    }
+}
+
+fn age_seconds(py: Python<'_>, dt: &PyObject) -> PyResult<f64> {
+    let datetime_mod = py.import_bound("datetime")?;
+    let datetime_type = datetime_mod.getattr("datetime")?;
+    let timezone_utc = datetime_mod.getattr("timezone")?.getattr("utc")?;
+    let tzinfo = dt.bind(py).getattr("tzinfo")?;
+    let now_override =
+        NOW_OVERRIDE.with(|slot| slot.borrow().as_ref().map(|now| now.clone_ref(py)));
+    let now = if let Some(now) = now_override {
+        now.into_bound(py)
+    } else if tzinfo.is_none() {
+        datetime_type.call_method1("now", (timezone_utc,))?
+    } else {
+        datetime_type.call_method1("now", (tzinfo,))?
+    };
+    now.call_method1("__sub__", (dt,))?
+        .call_method0("total_seconds")?
+        .extract::<f64>()
+}
+
+/// Coerces `value` to a timezone-aware datetime, assuming UTC for naive values.
+fn as_utc_datetime(py: Python<'_>, value: &PyObject) -> PyResult<Option<PyObject>> {
+    match as_datetime(py, value, None)? {
+        Some(dt) => assume_utc(py, dt).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Attaches UTC to a naive datetime; aware datetimes are returned as-is.
+fn assume_utc(py: Python<'_>, dt: PyObject) -> PyResult<PyObject> {
+    let dt_bound = dt.bind(py);
+    if !dt_bound.getattr("tzinfo")?.is_none() {
+        return Ok(dt);
+    }
+    let utc = py
+        .import_bound("datetime")?
+        .getattr("timezone")?
+        .getattr("utc")?;
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item("tzinfo", utc)?;
+    Ok(dt_bound.call_method("replace", (), Some(&kwargs))?.into())
+}
+
+fn humanize_duration(seconds: f64, largest_only: bool) -> String {
+    const UNITS: [(u64, &str, &str); 4] = [
+        (86_400, "d", "day"),
+        (3_600, "h", "hour"),
+        (60, "m", "minute"),
+        (1, "s", "second"),
+    ];
+
+    let sign = if seconds < 0.0 { "-" } else { "" };
+    let mut remaining = seconds.abs().round() as u64;
+    let mut parts: Vec<String> = Vec::new();
+    for (size, short, long) in UNITS {
+        let count = remaining / size;
+        remaining %= size;
+        if count == 0 {
+            continue;
+        }
+        if largest_only {
+            let plural = if count == 1 { "" } else { "s" };
+            return format!("{sign}{count} {long}{plural}");
+        }
+        parts.push(format!("{count}{short}"));
+    }
+
+    if parts.is_empty() {
+        return if largest_only {
+            "0 seconds".to_string()
+        } else {
+            "0s".to_string()
+        };
+    }
+    format!("{sign}{}", parts.join(" "))
+}
+
+fn truncate_datetime(py: Python<'_>, dt: &PyObject, unit: &str) -> PyResult<PyObject> {
+    let fields: &[(&str, u32)] = match unit {
+        "second" => &[("microsecond", 0)],
+        "minute" => &[("microsecond", 0), ("second", 0)],
+        "hour" => &[("microsecond", 0), ("second", 0), ("minute", 0)],
+        "day" => &[("microsecond", 0), ("second", 0), ("minute", 0), ("hour", 0)],
+        "month" => &[
+            ("microsecond", 0),
+            ("second", 0),
+            ("minute", 0),
+            ("hour", 0),
+            ("day", 1),
+        ],
+        "year" => &[
+            ("microsecond", 0),
+            ("second", 0),
+            ("minute", 0),
+            ("hour", 0),
+            ("day", 1),
+            ("month", 1),
+        ],
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Filter '$date_trunc' got unsupported unit '{unit}'. Expected one of: second, minute, hour, day, month, year."
+            )))
+        }
+    };
+
+    let kwargs = PyDict::new_bound(py);
+    for (name, value) in fields {
+        kwargs.set_item(*name, *value)?;
+    }
+    dt.bind(py)
+        .call_method("replace", (), Some(&kwargs))
+        .map(|v| v.into())
+}
+
 fn collect_numeric_sequence(py: Python<'_>, value: &PyObject) -> PyResult<Option<Vec<f64>>> {
     let value_bound = value.bind(py);
     if !is_list_or_tuple(&value_bound) {
@@ -1401,6 +3029,71 @@ fn collect_numeric_sequence(py: Python<'_>, value: &PyObject) -> PyResult<Option
     Ok(Some(values))
 }
 
+/// Returns the sorted items as `Decimal`s when a list/tuple mixes only `Decimal`
+/// and `int` values (with at least one `Decimal`), so quantiles keep precision.
+fn collect_decimal_sequence(py: Python<'_>, value: &PyObject) -> PyResult<Option<Vec<PyObject>>> {
+    let value_bound = value.bind(py);
+    if !is_list_or_tuple(&value_bound) || value_bound.len()? == 0 {
+        return Ok(None);
+    }
+
+    let decimal_type = py.import_bound("decimal")?.getattr("Decimal")?;
+    let mut has_decimal = false;
+    let mut values: Vec<PyObject> = Vec::new();
+    for item in value_bound.iter()? {
+        let item = item?;
+        if item.is_instance(&decimal_type)? {
+            has_decimal = true;
+            values.push(item.into());
+        } else if item.is_instance_of::<PyInt>() && !item.is_instance_of::<PyBool>() {
+            values.push(decimal_type.call1((item,))?.into());
+        } else {
+            return Ok(None);
+        }
+    }
+    if !has_decimal {
+        return Ok(None);
+    }
+
+    let sorted = py
+        .import_bound("builtins")?
+        .getattr("sorted")?
+        .call1((PyList::new_bound(py, values),))?;
+    Ok(Some(sorted.extract::<Vec<PyObject>>()?))
+}
+
+fn decimal_percentile_value(
+    py: Python<'_>,
+    sorted_values: &[PyObject],
+    percentile: f64,
+) -> PyResult<Option<PyObject>> {
+    if sorted_values.is_empty() || !(0.0..=100.0).contains(&percentile) {
+        return Ok(None);
+    }
+    if sorted_values.len() == 1 {
+        return Ok(Some(sorted_values[0].clone_ref(py)));
+    }
+
+    let decimal_type = py.import_bound("decimal")?.getattr("Decimal")?;
+    let rank = percentile / 100.0 * (sorted_values.len() as f64 - 1.0);
+    let lower_idx = rank.floor() as usize;
+    let upper_idx = rank.ceil() as usize;
+    let lower = &sorted_values[lower_idx];
+    if lower_idx == upper_idx {
+        return Ok(Some(lower.clone_ref(py)));
+    }
+
+    let upper = &sorted_values[upper_idx];
+    let exact_rank = decimal_type
+        .call1((percentile.to_string(),))?
+        .call_method1("__mul__", (sorted_values.len() - 1,))?
+        .call_method1("__truediv__", (100,))?;
+    let fraction: PyObject = exact_rank.call_method1("__sub__", (lower_idx,))?.into();
+    let spread = apply_binary_op(py, upper, "__sub__", lower)?;
+    let offset = apply_binary_op(py, &spread, "__mul__", &fraction)?;
+    apply_binary_op(py, lower, "__add__", &offset).map(Some)
+}
+
 fn percentile_value(sorted_values: &[f64], percentile: f64) -> Option<f64> {
     if sorted_values.is_empty() || !(0.0..=100.0).contains(&percentile) {
         return None;
@@ -1419,39 +3112,192 @@ fn percentile_value(sorted_values: &[f64], percentile: f64) -> Option<f64> {
     Some(lower + (upper - lower) * fraction)
 }
 
-fn flatten_deep_into(value: &Bound<'_, PyAny>, flattened: &Bound<'_, PyList>) -> PyResult<()> {
-    if is_list_or_tuple(value) {
-        let value_len = value.len()?;
-        for idx in 0..value_len {
-            let nested = value.get_item(idx)?;
-            flatten_deep_into(&nested, flattened)?;
+fn levenshtein_distance(left: &[char], right: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    let mut current: Vec<usize> = vec![0; right.len() + 1];
+
+    for (i, left_ch) in left.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, right_ch) in right.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left_ch != right_ch);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
         }
-        return Ok(());
+        std::mem::swap(&mut previous, &mut current);
     }
 
-    flattened.append(value)?;
-    Ok(())
+    previous[right.len()]
 }
 
-fn resolve_relative_read_path(
-    py: Python<'_>,
-    module: &Bound<'_, PyModule>,
-    registry: &Bound<'_, PyAny>,
-    item: &PyObject,
-    path: &str,
-) -> PyResult<Option<PyObject>> {
-    let (base_path, output_transform) = split_path_and_transform(path);
-    let mut current = item.clone_ref(py);
+/// Parses numbers such as `"$1,234.56"`, `"1 234,56"`, `"(12.5)"` or `"1.5e3"`.
+///
+/// The number is a single run of digits and separators, optionally followed by
+/// an exponent; text around it is ignored unless it holds more digits, in
+/// which case the input is ambiguous and `None` is returned. A space followed
+/// by exactly three digits groups thousands inside the run.
+///
+/// When both `.` and `,` appear, the last one is the decimal separator. A lone
+/// separator repeated, or a single `,` followed by exactly three digits, groups
+/// thousands; otherwise it is the decimal separator.
+fn parse_lenient_number(text: &str) -> Option<f64> {
+    let trimmed = text.trim();
+    let (negative_parens, inner) = match trimmed.strip_prefix('(') {
+        Some(rest) => (true, rest.strip_suffix(')')?),
+        None => (false, trimmed),
+    };
 
-    if base_path != "." {
-        let tokens = parse_path(py, module, registry, &base_path)?;
-        validate_read_path_root_token(py, &base_path, &tokens)?;
+    let chars: Vec<char> = inner.chars().collect();
+    let is_digit_at = |idx: usize| chars.get(idx).is_some_and(|ch| ch.is_ascii_digit());
+    let start = (0..chars.len()).find(|&idx| {
+        is_digit_at(idx) || (matches!(chars[idx], '.' | ',') && is_digit_at(idx + 1))
+    })?;
 
-        for token in tokens {
-            if matches!(token.kind, TokenKind::Root) {
-                current = item.clone_ref(py);
-                continue;
-            }
+    let mut run = String::new();
+    let mut end = start;
+    while end < chars.len() {
+        let ch = chars[end];
+        if ch.is_ascii_digit() || (matches!(ch, '.' | ',') && is_digit_at(end + 1)) {
+            run.push(ch);
+        } else if matches!(ch, ' ' | '\u{a0}' | '\u{202f}')
+            && is_digit_at(end.wrapping_sub(1))
+            && (1..=3).all(|offset| is_digit_at(end + offset))
+            && !is_digit_at(end + 4)
+        {
+            // Thousands grouping such as "1 234"; the space itself is dropped.
+        } else {
+            break;
+        }
+        end += 1;
+    }
+
+    let mut exponent = String::new();
+    if matches!(chars.get(end), Some('e' | 'E')) {
+        let sign_len = usize::from(matches!(chars.get(end + 1), Some('+' | '-')));
+        if is_digit_at(end + 1 + sign_len) {
+            exponent.push('e');
+            exponent.extend(&chars[end + 1..end + 1 + sign_len]);
+            end += 1 + sign_len;
+            while is_digit_at(end) {
+                exponent.push(chars[end]);
+                end += 1;
+            }
+        }
+    }
+    if chars[end..].iter().any(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+
+    // A sign counts when only symbols such as a currency sign sit between it
+    // and the number ("-$5", "$ -5").
+    let negative = chars[..start]
+        .iter()
+        .rev()
+        .take_while(|ch| !ch.is_alphanumeric())
+        .find(|ch| matches!(ch, '-' | '+'))
+        == Some(&'-');
+
+    let last_dot = run.rfind('.');
+    let last_comma = run.rfind(',');
+    let decimal_separator = match (last_dot, last_comma) {
+        (Some(dot), Some(comma)) => Some(if dot > comma { '.' } else { ',' }),
+        (Some(_), None) if run.matches('.').count() == 1 => Some('.'),
+        (None, Some(comma)) if run.matches(',').count() == 1 => {
+            let digits_after = run[comma + 1..]
+                .chars()
+                .filter(|ch| ch.is_ascii_digit())
+                .count();
+            (digits_after != 3).then_some(',')
+        }
+        _ => None,
+    };
+
+    let normalized: String = run
+        .chars()
+        .filter_map(|ch| match ch {
+            '.' | ',' if Some(ch) == decimal_separator => Some('.'),
+            '.' | ',' => None,
+            other => Some(other),
+        })
+        .collect();
+    let parsed = format!("{normalized}{exponent}").parse::<f64>().ok()?;
+    let parsed = if negative { -parsed } else { parsed };
+    Some(if negative_parens { -parsed } else { parsed })
+}
+
+fn similarity_ratio(left: &str, right: &str) -> f64 {
+    let left_chars: Vec<char> = left.chars().collect();
+    let right_chars: Vec<char> = right.chars().collect();
+    let longest = left_chars.len().max(right_chars.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    let distance = levenshtein_distance(&left_chars, &right_chars);
+    1.0 - distance as f64 / longest as f64
+}
+
+fn flatten_dict_keys_into(
+    py: Python<'_>,
+    source: &Bound<'_, PyDict>,
+    prefix: Option<&str>,
+    sep: &str,
+    out: &Bound<'_, PyDict>,
+    depth: usize,
+) -> PyResult<()> {
+    check_recursion_depth_limit(py, depth)?;
+    for (key, item) in source.iter() {
+        let key = key.str()?.to_string_lossy().to_string();
+        let full_key = match prefix {
+            Some(prefix) => format!("{prefix}{sep}{key}"),
+            None => key,
+        };
+        match item.downcast::<PyDict>() {
+            Ok(nested) if !nested.is_empty() => {
+                flatten_dict_keys_into(py, nested, Some(&full_key), sep, out, depth + 1)?
+            }
+            _ => out.set_item(full_key, item)?,
+        }
+    }
+    Ok(())
+}
+
+fn flatten_deep_into(
+    py: Python<'_>,
+    value: &Bound<'_, PyAny>,
+    flattened: &Bound<'_, PyList>,
+    depth: usize,
+) -> PyResult<()> {
+    check_recursion_depth_limit(py, depth)?;
+    if is_list_or_tuple(value) {
+        let value_len = value.len()?;
+        for idx in 0..value_len {
+            let nested = value.get_item(idx)?;
+            flatten_deep_into(py, &nested, flattened, depth + 1)?;
+        }
+        return Ok(());
+    }
+
+    flattened.append(value)?;
+    Ok(())
+}
+
+fn resolve_relative_read_path(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    item: &PyObject,
+    path: &str,
+) -> PyResult<Option<PyObject>> {
+    let (base_path, output_transform) = split_path_and_transform(path);
+    let mut current = item.clone_ref(py);
+
+    if base_path != "." {
+        let tokens = parse_path(py, module, registry, &base_path)?;
+        validate_read_path_root_token(py, &base_path, &tokens)?;
+
+        for token in tokens {
+            if matches!(token.kind, TokenKind::Root) {
+                current = item.clone_ref(py);
+                continue;
+            }
 
             match resolve_token(py, module, registry, &current, item, &token.kind) {
                 Ok(value) => current = value,
@@ -1495,11 +3341,46 @@ fn compare_selector_values(
     Ok(Ordering::Equal)
 }
 
+impl BuiltinFilter {
+    /// String builtins that stringify their input, so `None` would become `"None"`.
+    fn stringifies_input(&self) -> bool {
+        matches!(
+            self,
+            BuiltinFilter::Lower
+                | BuiltinFilter::Upper
+                | BuiltinFilter::Title
+                | BuiltinFilter::Strip(_)
+                | BuiltinFilter::Replace(_, _)
+                | BuiltinFilter::ReplaceMap(_)
+                | BuiltinFilter::RegexReplace(_, _)
+                | BuiltinFilter::Split(_)
+                | BuiltinFilter::SplitLines(_)
+                | BuiltinFilter::StripHtml(_)
+                | BuiltinFilter::Html(_)
+                | BuiltinFilter::Join(_)
+                | BuiltinFilter::Startswith(_)
+                | BuiltinFilter::Endswith(_)
+                | BuiltinFilter::Matches(_)
+                | BuiltinFilter::Similarity(_)
+                | BuiltinFilter::Indent(_)
+                | BuiltinFilter::Dedent
+                | BuiltinFilter::WrapText(_, _)
+        )
+    }
+}
+
 fn apply_builtin_filter(
     py: Python<'_>,
     value: &PyObject,
     filter: &BuiltinFilter,
 ) -> PyResult<PyObject> {
+    if filter.stringifies_input()
+        && value.bind(py).is_none()
+        && NONE_SAFE_STRINGS.load(AtomicOrdering::Relaxed)
+    {
+        return Ok(py.None());
+    }
+
     match filter {
         BuiltinFilter::Inc => apply_binary_op(py, value, "__add__", &1i32.to_object(py)),
         BuiltinFilter::Dec => apply_binary_op(py, value, "__sub__", &1i32.to_object(py)),
@@ -1508,6 +3389,19 @@ fn apply_builtin_filter(
         BuiltinFilter::String => value.bind(py).str().map(|s| s.into()),
         BuiltinFilter::Int => call_builtin1(py, "int", value),
         BuiltinFilter::Float => call_builtin1(py, "float", value),
+        BuiltinFilter::ParseNumber => {
+            let value_bound = value.bind(py);
+            if value_bound.is_instance_of::<PyBool>() {
+                return Ok(py.None());
+            }
+            if value_bound.is_instance_of::<PyInt>() || value_bound.is_instance_of::<PyFloat>() {
+                return call_builtin1(py, "float", value);
+            }
+            let Ok(text) = value_bound.extract::<String>() else {
+                return Ok(py.None());
+            };
+            Ok(parse_lenient_number(&text).to_object(py))
+        }
         BuiltinFilter::Decimal => py
             .import_bound("decimal")?
             .getattr("Decimal")?
@@ -1523,6 +3417,27 @@ fn apply_builtin_filter(
                 value.bind(py).call_method0("__round__").map(|v| v.into())
             }
         }
+        BuiltinFilter::RoundSig(figures) => {
+            let figures_obj = call_builtin1(py, "int", figures)?;
+            let figures = figures_obj.bind(py).extract::<i64>()?;
+            if figures <= 0 {
+                return Err(PyValueError::new_err(format!(
+                    "Filter '$round_sig' expects a positive number of significant figures, got {figures}."
+                )));
+            }
+            let value_bound = value.bind(py);
+            if !is_number(py, value_bound)? {
+                return Ok(py.None());
+            }
+            let magnitude = value_bound.extract::<f64>()?.abs();
+            if magnitude == 0.0 || !magnitude.is_finite() {
+                return Ok(value.clone_ref(py));
+            }
+            let ndigits = figures - 1 - magnitude.log10().floor() as i64;
+            value_bound
+                .call_method1("__round__", (ndigits,))
+                .map(|v| v.into())
+        }
         BuiltinFilter::Floor => py
             .import_bound("math")?
             .getattr("floor")?
@@ -1550,6 +3465,12 @@ fn apply_builtin_filter(
             };
             Ok(compare_values(py, &rem, &expected.to_object(py), "==")?.to_object(py))
         }
+        BuiltinFilter::SignCheck(operator) => {
+            if !is_number(py, value.bind(py))? {
+                return Ok(false.to_object(py));
+            }
+            Ok(compare_with_fallback(py, value, &0i32.to_object(py), operator)?.to_object(py))
+        }
         BuiltinFilter::Gt(threshold) => {
             Ok(compare_with_fallback(py, value, threshold, ">")?.to_object(py))
         }
@@ -1607,17 +3528,25 @@ fn apply_builtin_filter(
             let exponent = apply_binary_op(py, &1f64.to_object(py), "__truediv__", degree)?;
             call_builtin2(py, "pow", value, &exponent)
         }
-        BuiltinFilter::Max => {
+        BuiltinFilter::Max(policy) => {
             let value_bound = value.bind(py);
             if value_bound.is_instance_of::<PyList>() || value_bound.is_instance_of::<PyTuple>() {
-                return call_builtin1(py, "max", value);
+                let (items, skip) = aggregate_policy_items(py, value, policy, "max")?;
+                if skip && items.bind(py).len()? == 0 {
+                    return Ok(py.None());
+                }
+                return call_builtin1(py, "max", &items);
             }
             Ok(value.clone_ref(py))
         }
-        BuiltinFilter::Min => {
+        BuiltinFilter::Min(policy) => {
             let value_bound = value.bind(py);
             if value_bound.is_instance_of::<PyList>() || value_bound.is_instance_of::<PyTuple>() {
-                return call_builtin1(py, "min", value);
+                let (items, skip) = aggregate_policy_items(py, value, policy, "min")?;
+                if skip && items.bind(py).len()? == 0 {
+                    return Ok(py.None());
+                }
+                return call_builtin1(py, "min", &items);
             }
             Ok(value.clone_ref(py))
         }
@@ -1637,6 +3566,47 @@ fn apply_builtin_filter(
             }
             Ok(out.into())
         }
+        BuiltinFilter::ToOrdered(order) => {
+            let reverse = match order {
+                Some(order) => match extract_string_arg(py, order, "to_ordered", "order")?.as_str() {
+                    "insertion" => None,
+                    "sorted" => Some(false),
+                    "reverse_sorted" => Some(true),
+                    other => {
+                        return Err(PyValueError::new_err(format!(
+                            "Filter '$to_ordered' got unsupported order '{other}'. Expected one of: insertion, sorted, reverse_sorted."
+                        )))
+                    }
+                },
+                None => None,
+            };
+            if !value.bind(py).is_instance_of::<PyDict>() {
+                return Ok(value.clone_ref(py));
+            }
+            let source = value.bind(py).downcast::<PyDict>()?;
+            let keys = source.keys();
+            let keys = match reverse {
+                Some(reverse_flag) => {
+                    let kwargs = PyDict::new_bound(py);
+                    kwargs.set_item("reverse", reverse_flag)?;
+                    py.import_bound("builtins")?
+                        .getattr("sorted")?
+                        .call((keys,), Some(&kwargs))?
+                        .downcast_into::<PyList>()?
+                }
+                None => keys,
+            };
+            let out = py
+                .import_bound("collections")?
+                .getattr("OrderedDict")?
+                .call0()?;
+            for key in keys.iter() {
+                if let Some(v) = source.get_item(&key)? {
+                    out.set_item(key, v)?;
+                }
+            }
+            Ok(out.into())
+        }
         BuiltinFilter::Unpick(keys) => {
             if !value.bind(py).is_instance_of::<PyDict>() {
                 return Ok(py.None());
@@ -1658,6 +3628,89 @@ fn apply_builtin_filter(
             }
             Ok(out.into())
         }
+        BuiltinFilter::FlattenKeys(sep) => {
+            let Ok(source) = value.bind(py).downcast::<PyDict>() else {
+                return Ok(py.None());
+            };
+            let sep = match sep {
+                Some(sep) => extract_string_arg(py, sep, "flatten_keys", "separator")?,
+                None => ".".to_string(),
+            };
+            let out = PyDict::new_bound(py);
+            flatten_dict_keys_into(py, source, None, &sep, &out, 0)?;
+            Ok(out.into())
+        }
+        BuiltinFilter::NestKeys(sep) => {
+            let Ok(source) = value.bind(py).downcast::<PyDict>() else {
+                return Ok(py.None());
+            };
+            let sep = match sep {
+                Some(sep) => extract_string_arg(py, sep, "nest_keys", "separator")?,
+                None => ".".to_string(),
+            };
+            let out = PyDict::new_bound(py);
+            for (key, item) in source.iter() {
+                let key = key.str()?.to_string_lossy().to_string();
+                let parts: Vec<&str> = key.split(sep.as_str()).collect();
+                let mut target = out.clone();
+                for part in &parts[..parts.len() - 1] {
+                    let next = match target.get_item(*part)? {
+                        Some(existing) if existing.is_instance_of::<PyDict>() => {
+                            existing.downcast_into::<PyDict>()?
+                        }
+                        _ => {
+                            let created = PyDict::new_bound(py);
+                            target.set_item(*part, created.clone())?;
+                            created
+                        }
+                    };
+                    target = next;
+                }
+                target.set_item(parts[parts.len() - 1], item)?;
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::DeepGet(path) => {
+            let path = extract_string_arg(py, path, "deep_get", "path")?;
+            let rust_module = py.import_bound("dictwalk._dictwalk_rs")?;
+            let backend = rust_module.getattr("dictwalk")?;
+            backend
+                .call_method1("get", (value.clone_ref(py), path))
+                .map(|resolved| resolved.into())
+        }
+        BuiltinFilter::JsonPointer(pointer) => {
+            let pointer = extract_string_arg(py, pointer, "json_pointer", "pointer")?;
+            let tokens = split_json_pointer(&pointer).map_err(PyValueError::new_err)?;
+            Ok(resolve_json_pointer(py, value, &tokens)?.unwrap_or_else(|| py.None()))
+        }
+        BuiltinFilter::CaseKeys(method) => {
+            let Ok(source) = value.bind(py).downcast::<PyDict>() else {
+                return Ok(value.clone_ref(py));
+            };
+            let out = PyDict::new_bound(py);
+            for (key, item) in source.iter() {
+                if key.is_instance_of::<PyString>() {
+                    out.set_item(key.call_method0(*method)?, item)?;
+                } else {
+                    out.set_item(key, item)?;
+                }
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::Wrap(key) => {
+            let out = PyDict::new_bound(py);
+            out.set_item(key.clone_ref(py), value.clone_ref(py))?;
+            Ok(out.into())
+        }
+        BuiltinFilter::Unwrap(key) => {
+            let Ok(source) = value.bind(py).downcast::<PyDict>() else {
+                return Ok(py.None());
+            };
+            Ok(source
+                .get_item(key.clone_ref(py))?
+                .map(|v| v.into())
+                .unwrap_or_else(|| py.None()))
+        }
         BuiltinFilter::Keys => {
             if !value.bind(py).is_instance_of::<PyDict>() {
                 return Ok(py.None());
@@ -1669,6 +3722,62 @@ fn apply_builtin_filter(
             }
             Ok(out.into())
         }
+        BuiltinFilter::KeysMatching(compiled, pattern, syntax)
+        | BuiltinFilter::SelectKeys(compiled, pattern, syntax) => {
+            let Ok(source) = value.bind(py).downcast::<PyDict>() else {
+                return Ok(py.None());
+            };
+            let compiled = match compiled {
+                Some(compiled) => compiled.clone_ref(py),
+                None => {
+                    let filter_name = if matches!(filter, BuiltinFilter::SelectKeys(..)) {
+                        "select_keys"
+                    } else {
+                        "keys_matching"
+                    };
+                    compile_key_pattern(py, filter_name, pattern, syntax.as_ref())?
+                }
+            };
+            let search = compiled.bind(py).getattr("search")?;
+            let keys = PyList::empty_bound(py);
+            let selected = PyDict::new_bound(py);
+            for (key, item) in source.iter() {
+                if !search.call1((key.str()?,))?.is_none() {
+                    keys.append(&key)?;
+                    selected.set_item(key, item)?;
+                }
+            }
+            if matches!(filter, BuiltinFilter::SelectKeys(..)) {
+                return Ok(selected.into());
+            }
+            Ok(keys.into())
+        }
+        BuiltinFilter::JsonPath(expression) => {
+            let expression = extract_string_arg(py, expression, "json_path", "expression")?;
+            let segments = parse_json_path(&expression).map_err(|reason| {
+                PyValueError::new_err(format!(
+                    "Filter '$json_path' got invalid JSONPath '{expression}': {reason}."
+                ))
+            })?;
+            let mut nodes = vec![value.clone_ref(py)];
+            for segment in &segments {
+                let mut selected = Vec::new();
+                for node in &nodes {
+                    if segment.recursive {
+                        let mut scope = Vec::new();
+                        json_path_descendants(py, node, &mut scope, 0)?;
+                        for descendant in &scope {
+                            json_path_select(py, descendant, &segment.selector, &mut selected)?;
+                        }
+                    } else {
+                        json_path_select(py, node, &segment.selector, &mut selected)?;
+                    }
+                    check_result_size_limit(py, selected.len())?;
+                }
+                nodes = selected;
+            }
+            Ok(PyList::new_bound(py, nodes).into())
+        }
         BuiltinFilter::Values => {
             if !value.bind(py).is_instance_of::<PyDict>() {
                 return Ok(py.None());
@@ -1699,6 +3808,20 @@ fn apply_builtin_filter(
             let min_applied = call_builtin2(py, "max", min_value, value)?;
             call_builtin2(py, "min", max_value, &min_applied)
         }
+        BuiltinFilter::ClampEach(min_value, max_value) => {
+            let items = collect_sequence_items(py, value)?.ok_or_else(|| {
+                PyTypeError::new_err(format!(
+                    "Filter '$clamp_each' expects a list or tuple, got {}.",
+                    get_type_name(&value.bind(py))
+                ))
+            })?;
+            let clamp = BuiltinFilter::Clamp(min_value.clone_ref(py), max_value.clone_ref(py));
+            let out = PyList::empty_bound(py);
+            for item in &items {
+                out.append(apply_builtin_filter(py, item, &clamp)?)?;
+            }
+            Ok(out.into())
+        }
         BuiltinFilter::Sign => Ok((compare_with_fallback(py, value, &0i32.to_object(py), ">")?
             as i32
             - compare_with_fallback(py, value, &0i32.to_object(py), "<")? as i32)
@@ -1730,7 +3853,31 @@ fn apply_builtin_filter(
             let scale = apply_binary_op(py, &percent_float, "__truediv__", &100f64.to_object(py))?;
             apply_binary_op(py, &value_float, "__mul__", &scale)
         }
+        BuiltinFilter::Ratio(total) => {
+            if !total.bind(py).is_truthy()? {
+                return Ok(py.None());
+            }
+            apply_binary_op(py, value, "__truediv__", total)
+        }
+        BuiltinFilter::AbsError(expected) => {
+            Ok(numeric_abs_error(py, value, expected)?.unwrap_or_else(|| py.None()))
+        }
+        BuiltinFilter::RelError(expected) => {
+            let Some(error) = numeric_abs_error(py, value, expected)? else {
+                return Ok(py.None());
+            };
+            let expected_bound = expected.bind(py);
+            if !expected_bound.is_truthy()? {
+                return Ok(py.None());
+            }
+            error.bind(py).div(expected_bound.abs()?).map(|v| v.into())
+        }
         BuiltinFilter::Pctile(percentile) => {
+            let p_obj = call_builtin1(py, "float", percentile)?;
+            let p = p_obj.bind(py).extract::<f64>()?;
+            if let Some(values) = collect_decimal_sequence(py, value)? {
+                return Ok(decimal_percentile_value(py, &values, p)?.unwrap_or_else(|| py.None()));
+            }
             let Some(mut values) = collect_numeric_sequence(py, value)? else {
                 return Ok(value.clone_ref(py));
             };
@@ -1738,15 +3885,46 @@ fn apply_builtin_filter(
                 return Ok(py.None());
             }
 
-            let p_obj = call_builtin1(py, "float", percentile)?;
-            let p = p_obj.bind(py).extract::<f64>()?;
             values.sort_by(|a, b| a.total_cmp(b));
             let Some(result) = percentile_value(&values, p) else {
                 return Ok(py.None());
             };
             Ok(result.to_object(py))
         }
+        BuiltinFilter::Winsorize(fraction) => {
+            let fraction_obj = call_builtin1(py, "float", fraction)?;
+            let fraction = fraction_obj.bind(py).extract::<f64>()?;
+            let Some(values) = collect_numeric_sequence(py, value)? else {
+                return Ok(value.clone_ref(py));
+            };
+            if !(0.0..=0.5).contains(&fraction) {
+                return Ok(py.None());
+            }
+            let mut sorted = values.clone();
+            sorted.sort_by(|a, b| a.total_cmp(b));
+            let (Some(lower), Some(upper)) = (
+                percentile_value(&sorted, fraction * 100.0),
+                percentile_value(&sorted, (1.0 - fraction) * 100.0),
+            ) else {
+                return Ok(PyList::empty_bound(py).into());
+            };
+            let value_bound = value.bind(py);
+            let out = PyList::empty_bound(py);
+            for (idx, number) in values.iter().enumerate() {
+                if *number < lower {
+                    out.append(lower)?;
+                } else if *number > upper {
+                    out.append(upper)?;
+                } else {
+                    out.append(value_bound.get_item(idx)?)?;
+                }
+            }
+            Ok(out.into())
+        }
         BuiltinFilter::Median => {
+            if let Some(values) = collect_decimal_sequence(py, value)? {
+                return Ok(decimal_percentile_value(py, &values, 50.0)?.expect("non-empty checked"));
+            }
             let Some(mut values) = collect_numeric_sequence(py, value)? else {
                 return Ok(value.clone_ref(py));
             };
@@ -1758,6 +3936,9 @@ fn apply_builtin_filter(
             Ok(result.to_object(py))
         }
         BuiltinFilter::Q1 => {
+            if let Some(values) = collect_decimal_sequence(py, value)? {
+                return Ok(decimal_percentile_value(py, &values, 25.0)?.expect("non-empty checked"));
+            }
             let Some(mut values) = collect_numeric_sequence(py, value)? else {
                 return Ok(value.clone_ref(py));
             };
@@ -1769,6 +3950,9 @@ fn apply_builtin_filter(
             Ok(result.to_object(py))
         }
         BuiltinFilter::Q3 => {
+            if let Some(values) = collect_decimal_sequence(py, value)? {
+                return Ok(decimal_percentile_value(py, &values, 75.0)?.expect("non-empty checked"));
+            }
             let Some(mut values) = collect_numeric_sequence(py, value)? else {
                 return Ok(value.clone_ref(py));
             };
@@ -1780,6 +3964,11 @@ fn apply_builtin_filter(
             Ok(result.to_object(py))
         }
         BuiltinFilter::Iqr => {
+            if let Some(values) = collect_decimal_sequence(py, value)? {
+                let q1 = decimal_percentile_value(py, &values, 25.0)?.expect("non-empty checked");
+                let q3 = decimal_percentile_value(py, &values, 75.0)?.expect("non-empty checked");
+                return apply_binary_op(py, &q3, "__sub__", &q1);
+            }
             let Some(mut values) = collect_numeric_sequence(py, value)? else {
                 return Ok(value.clone_ref(py));
             };
@@ -1842,98 +4031,407 @@ fn apply_builtin_filter(
                 / n;
             Ok(variance.sqrt().to_object(py))
         }
+        BuiltinFilter::Within(center, tolerance) => {
+            if !is_number(py, tolerance.bind(py))? {
+                return Ok(false.to_object(py));
+            }
+            let Some(error) = numeric_abs_error(py, value, center)? else {
+                return Ok(false.to_object(py));
+            };
+            Ok(compare_with_fallback(py, &error, tolerance, "<=")?.to_object(py))
+        }
         BuiltinFilter::Between(min_value, max_value) => {
             let ge_min = compare_with_fallback(py, value, min_value, ">=")?;
             let le_max = compare_with_fallback(py, value, max_value, "<=")?;
             Ok((ge_min && le_max).to_object(py))
         }
-        BuiltinFilter::Sum => {
+        BuiltinFilter::Sum(policy) => {
             let value_bound = value.bind(py);
             if value_bound.is_instance_of::<PyList>() || value_bound.is_instance_of::<PyTuple>() {
-                return call_builtin1(py, "sum", value);
+                let (items, _) = aggregate_policy_items(py, value, policy, "sum")?;
+                return call_builtin1(py, "sum", &items);
             }
             Ok(value.clone_ref(py))
         }
-        BuiltinFilter::Avg => {
-            let value_bound = value.bind(py);
-            if value_bound.is_instance_of::<PyList>() || value_bound.is_instance_of::<PyTuple>() {
-                let len = value_bound.len()?;
-                if len == 0 {
-                    return Ok(py.None());
-                }
-                let sum_value = call_builtin1(py, "sum", value)?;
-                return apply_binary_op(py, &sum_value, "__truediv__", &(len as i64).to_object(py));
-            }
-            Ok(value.clone_ref(py))
-        }
-        BuiltinFilter::Unique => {
-            if !value.bind(py).is_instance_of::<PyList>() {
+        BuiltinFilter::Cumsum => {
+            let Some(items) = collect_sequence_items(py, value)? else {
                 return Ok(value.clone_ref(py));
-            }
-            let dict_type = py.import_bound("builtins")?.getattr("dict")?;
-            let fromkeys = dict_type.getattr("fromkeys")?;
-            let dedup_dict = fromkeys.call1((value.clone_ref(py),))?;
-            call_builtin1(py, "list", &dedup_dict.into())
+            };
+            Ok(PyList::new_bound(py, running_sums(py, &items)?).into())
         }
-        BuiltinFilter::SortBy(path_value, reverse_flag) => {
-            let selector_path = extract_string_arg(py, path_value, "sort_by", "selector path")?;
-            let reverse = reverse_flag
-                .as_ref()
-                .map(|flag| flag.bind(py).is_truthy())
-                .transpose()?
-                .unwrap_or(false);
-            let module = py.import_bound("dictwalk.dictwalk")?;
-            let registry = load_registry(py)?;
+        BuiltinFilter::FillNone(backward) => {
             let Some(mut items) = collect_sequence_items(py, value)? else {
                 return Ok(value.clone_ref(py));
             };
-
-            let mut keyed: Vec<(Option<PyObject>, PyObject)> = Vec::with_capacity(items.len());
-            for item in items.drain(..) {
-                let resolved =
-                    resolve_relative_read_path(py, &module, &registry, &item, &selector_path)?;
-                keyed.push((resolved, item));
-            }
-
-            let mut sorted: Vec<(Option<PyObject>, PyObject)> = Vec::with_capacity(keyed.len());
-            for entry in keyed {
-                let mut insert_at = sorted.len();
-                for (idx, (existing_key, _)) in sorted.iter().enumerate() {
-                    let ordering = match (&entry.0, existing_key) {
-                        (None, None) => Ordering::Equal,
-                        (None, Some(_)) => Ordering::Greater,
-                        (Some(_), None) => Ordering::Less,
-                        (Some(left), Some(right)) => {
-                            compare_selector_values(py, left, right, reverse)?
-                        }
-                    };
-                    if ordering == Ordering::Less {
-                        insert_at = idx;
-                        break;
+            if *backward {
+                items.reverse();
+            }
+            let mut last_valid: Option<PyObject> = None;
+            for item in items.iter_mut() {
+                if item.is_none(py) {
+                    if let Some(fill) = &last_valid {
+                        *item = fill.clone_ref(py);
                     }
+                } else {
+                    last_valid = Some(item.clone_ref(py));
                 }
-                sorted.insert(insert_at, entry);
             }
-
-            let out = PyList::empty_bound(py);
-            for (_, item) in sorted {
-                out.append(item)?;
+            if *backward {
+                items.reverse();
             }
-            Ok(out.into())
+            Ok(PyList::new_bound(py, items).into())
         }
-        BuiltinFilter::UniqueBy(path_value) => {
-            let selector_path = extract_string_arg(py, path_value, "unique_by", "selector path")?;
-            let module = py.import_bound("dictwalk.dictwalk")?;
-            let registry = load_registry(py)?;
+        BuiltinFilter::RunningAvg(window_value, pad) => {
             let Some(items) = collect_sequence_items(py, value)? else {
                 return Ok(value.clone_ref(py));
             };
+            let window_obj = call_builtin1(py, "int", window_value)?;
+            let window = window_obj.bind(py).extract::<isize>()?;
+            if window <= 0 {
+                return Ok(py.None());
+            }
 
+            let window = window as usize;
             let out = PyList::empty_bound(py);
-            let mut seen: Vec<PyObject> = Vec::new();
-            for item in items {
-                let Some(key) =
-                    resolve_relative_read_path(py, &module, &registry, &item, &selector_path)?
+            let window_len = (window as i64).to_object(py);
+            for end in 1..=items.len() {
+                if end < window {
+                    if *pad {
+                        out.append(py.None())?;
+                    }
+                    continue;
+                }
+                let span = PyList::new_bound(py, &items[end - window..end]);
+                let total = call_builtin1(py, "sum", &span.into_any().unbind())?;
+                out.append(apply_binary_op(py, &total, "__truediv__", &window_len)?)?;
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::CumulativePct => {
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(value.clone_ref(py));
+            };
+            let sums = running_sums(py, &items)?;
+            let Some(total) = sums.last() else {
+                return Ok(PyList::empty_bound(py).into());
+            };
+            if !total.bind(py).is_truthy()? {
+                return Ok(PyList::new_bound(py, sums.iter().map(|_| py.None())).into());
+            }
+            let hundred = 100i64.to_object(py);
+            let out = PyList::empty_bound(py);
+            for running in &sums {
+                let scaled = apply_binary_op(py, running, "__mul__", &hundred)?;
+                out.append(apply_binary_op(py, &scaled, "__truediv__", total)?)?;
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::MinMaxScale(lo, hi) => {
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(value.clone_ref(py));
+            };
+            if items.is_empty() {
+                return Ok(PyList::empty_bound(py).into());
+            }
+            let low = call_builtin1(py, "min", value)?;
+            let high = call_builtin1(py, "max", value)?;
+            let spread = apply_binary_op(py, &high, "__sub__", &low)?;
+            if !spread.bind(py).is_truthy()? {
+                return Ok(PyList::new_bound(py, items.iter().map(|_| lo.clone_ref(py))).into());
+            }
+            let target_spread = apply_binary_op(py, hi, "__sub__", lo)?;
+            let out = PyList::empty_bound(py);
+            for item in &items {
+                let offset = apply_binary_op(py, item, "__sub__", &low)?;
+                let scaled = apply_binary_op(py, &offset, "__mul__", &target_spread)?;
+                let scaled = apply_binary_op(py, &scaled, "__truediv__", &spread)?;
+                out.append(apply_binary_op(py, lo, "__add__", &scaled)?)?;
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::Avg(policy) => {
+            let value_bound = value.bind(py);
+            if value_bound.is_instance_of::<PyList>() || value_bound.is_instance_of::<PyTuple>() {
+                let (items, _) = aggregate_policy_items(py, value, policy, "avg")?;
+                let len = items.bind(py).len()?;
+                if len == 0 {
+                    return Ok(py.None());
+                }
+                let sum_value = call_builtin1(py, "sum", &items)?;
+                return apply_binary_op(py, &sum_value, "__truediv__", &(len as i64).to_object(py));
+            }
+            Ok(value.clone_ref(py))
+        }
+        BuiltinFilter::Unique => {
+            if !value.bind(py).is_instance_of::<PyList>() {
+                return Ok(value.clone_ref(py));
+            }
+            unique_items(py, value)
+        }
+        BuiltinFilter::CountTrue | BuiltinFilter::ProportionTrue => {
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(py.None());
+            };
+            let mut count = 0usize;
+            for item in &items {
+                if item.bind(py).is_truthy()? {
+                    count += 1;
+                }
+            }
+            if matches!(filter, BuiltinFilter::CountTrue) {
+                return Ok(count.to_object(py));
+            }
+            if items.is_empty() {
+                return Ok(py.None());
+            }
+            Ok((count as f64 / items.len() as f64).to_object(py))
+        }
+        BuiltinFilter::Tally => {
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(value.clone_ref(py));
+            };
+
+            let mut counts: Vec<(PyObject, usize)> = Vec::new();
+            for item in items {
+                match counts
+                    .iter_mut()
+                    .find(|(seen, _)| compare_values(py, seen, &item, "==").unwrap_or(false))
+                {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((item, 1)),
+                }
+            }
+            // Stable sort keeps first-appearance order among equal counts.
+            counts.sort_by_key(|(_, count)| Reverse(*count));
+
+            let out = PyList::empty_bound(py);
+            for (item, count) in counts {
+                out.append(PyList::new_bound(py, [item, count.to_object(py)]))?;
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::ToSet => {
+            if !is_set_operand(&value.bind(py)) {
+                return Ok(value.clone_ref(py));
+            }
+            call_builtin1(py, "set", value)
+        }
+        BuiltinFilter::SetOp(method, other) => {
+            if !is_set_operand(&value.bind(py)) {
+                return Ok(value.clone_ref(py));
+            }
+            apply_set_operation(py, value, method, method, other)
+        }
+        BuiltinFilter::Partition(predicate) => {
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(py.None());
+            };
+            let matching = PyList::empty_bound(py);
+            let non_matching = PyList::empty_bound(py);
+            for item in items {
+                if eval_predicate_expr(py, predicate, &item)? {
+                    matching.append(item)?;
+                } else {
+                    non_matching.append(item)?;
+                }
+            }
+            Ok(PyList::new_bound(py, [matching, non_matching]).into())
+        }
+        BuiltinFilter::ApplyIf(predicate, transform) => {
+            if eval_predicate_expr(py, predicate, value)? {
+                apply_builtin_pipeline(py, value.clone_ref(py), transform)
+            } else {
+                Ok(value.clone_ref(py))
+            }
+        }
+        BuiltinFilter::MapRecursive(transform) => map_leaves(py, value, transform, 0),
+        BuiltinFilter::SortKeysRecursive => sort_keys_recursive(py, value, 0),
+        BuiltinFilter::Switch(cases) => {
+            for pair in cases.chunks_exact(2) {
+                if compare_values(py, value, &pair[0], "==")? {
+                    return Ok(pair[1].clone_ref(py));
+                }
+            }
+            Ok(if cases.len() % 2 == 1 {
+                cases[cases.len() - 1].clone_ref(py)
+            } else {
+                py.None()
+            })
+        }
+        BuiltinFilter::Lookup(mapping, default_value) => {
+            let mapping = mapping.bind(py);
+            let mapping = mapping.downcast::<PyDict>().map_err(|_| {
+                PyTypeError::new_err("Filter '$lookup' expects mapping to be a dict.")
+            })?;
+            Ok(match mapping.get_item(value.clone_ref(py))? {
+                Some(mapped) => mapped.into(),
+                None => default_value
+                    .as_ref()
+                    .map(|default_value| default_value.clone_ref(py))
+                    .unwrap_or_else(|| py.None()),
+            })
+        }
+        BuiltinFilter::WithDefaultMap(defaults) => {
+            let defaults = defaults.bind(py);
+            let defaults = defaults.downcast::<PyDict>().map_err(|_| {
+                PyTypeError::new_err("Filter '$with_default_map' expects defaults to be a dict.")
+            })?;
+            let Ok(source) = value.bind(py).downcast::<PyDict>() else {
+                return Ok(defaults.copy()?.into());
+            };
+            let out = source.copy()?;
+            for (key, default_value) in defaults.iter() {
+                if !out.contains(&key)? {
+                    out.set_item(key, default_value)?;
+                }
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::Require(keys) => {
+            let key_reprs = keys
+                .iter()
+                .map(|key| Ok(key.bind(py).repr()?.to_string_lossy().to_string()))
+                .collect::<PyResult<Vec<String>>>()?;
+            let filter_display = format!("$require({})", key_reprs.join(", "));
+            let value_bound = value.bind(py);
+            let Ok(source) = value_bound.downcast::<PyDict>() else {
+                return Err(make_resolution_error(
+                    py,
+                    &filter_display,
+                    None,
+                    &format!("Expected a dict, got {}.", get_type_name(&value_bound)),
+                ));
+            };
+            let mut missing: Vec<String> = Vec::new();
+            for (key, key_repr) in keys.iter().zip(key_reprs) {
+                let present = match source.get_item(key.clone_ref(py))? {
+                    Some(item) => !item.is_none(),
+                    None => false,
+                };
+                if !present {
+                    missing.push(key_repr);
+                }
+            }
+            if !missing.is_empty() {
+                return Err(make_resolution_error(
+                    py,
+                    &filter_display,
+                    None,
+                    &format!("Missing required keys: {}.", missing.join(", ")),
+                ));
+            }
+            Ok(value.clone_ref(py))
+        }
+        BuiltinFilter::SymDiff(other) => {
+            if !is_set_operand(&value.bind(py)) {
+                return Ok(py.None());
+            }
+            apply_set_operation(py, value, "symmetric_difference", "symdiff", other)
+        }
+        BuiltinFilter::SortBy(path_value, reverse_flag) => {
+            let selector_path = extract_string_arg(py, path_value, "sort_by", "selector path")?;
+            let reverse = reverse_flag
+                .as_ref()
+                .map(|flag| flag.bind(py).is_truthy())
+                .transpose()?
+                .unwrap_or(false);
+            let module = py.import_bound("dictwalk.dictwalk")?;
+            let registry = load_registry(py)?;
+            let Some(mut items) = collect_sequence_items(py, value)? else {
+                return Ok(value.clone_ref(py));
+            };
+
+            let mut keyed: Vec<(Option<PyObject>, PyObject)> = Vec::with_capacity(items.len());
+            for item in items.drain(..) {
+                let resolved =
+                    resolve_relative_read_path(py, &module, &registry, &item, &selector_path)?;
+                keyed.push((resolved, item));
+            }
+
+            let mut sorted: Vec<(Option<PyObject>, PyObject)> = Vec::with_capacity(keyed.len());
+            for entry in keyed {
+                let mut insert_at = sorted.len();
+                for (idx, (existing_key, _)) in sorted.iter().enumerate() {
+                    let ordering = match (&entry.0, existing_key) {
+                        (None, None) => Ordering::Equal,
+                        (None, Some(_)) => Ordering::Greater,
+                        (Some(_), None) => Ordering::Less,
+                        (Some(left), Some(right)) => {
+                            compare_selector_values(py, left, right, reverse)?
+                        }
+                    };
+                    if ordering == Ordering::Less {
+                        insert_at = idx;
+                        break;
+                    }
+                }
+                sorted.insert(insert_at, entry);
+            }
+
+            let out = PyList::empty_bound(py);
+            for (_, item) in sorted {
+                out.append(item)?;
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::Rank(policy) => {
+            let dense = match policy {
+                Some(policy) => match extract_string_arg(py, policy, "rank", "policy")?.as_str() {
+                    "dense" => true,
+                    "competition" => false,
+                    other => {
+                        return Err(PyValueError::new_err(format!(
+                            "Filter '$rank' got unsupported policy '{other}'. Expected one of: dense, competition."
+                        )))
+                    }
+                },
+                None => false,
+            };
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(value.clone_ref(py));
+            };
+
+            let builtins = py.import_bound("builtins")?;
+            let key = PyList::new_bound(py, &items).getattr("__getitem__")?;
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item("key", key)?;
+            let order: Vec<usize> = builtins
+                .getattr("sorted")?
+                .call(
+                    (builtins.getattr("range")?.call1((items.len(),))?,),
+                    Some(&kwargs),
+                )?
+                .extract()?;
+
+            let mut ranks = vec![0usize; items.len()];
+            let mut current_rank = 0usize;
+            for (position, &idx) in order.iter().enumerate() {
+                let tied = position > 0
+                    && compare_values(py, &items[order[position - 1]], &items[idx], "==")?;
+                if !tied {
+                    current_rank = if dense {
+                        current_rank + 1
+                    } else {
+                        position + 1
+                    };
+                }
+                ranks[idx] = current_rank;
+            }
+            Ok(PyList::new_bound(py, ranks).into())
+        }
+        BuiltinFilter::UniqueBy(path_value) => {
+            let selector_path = extract_string_arg(py, path_value, "unique_by", "selector path")?;
+            let module = py.import_bound("dictwalk.dictwalk")?;
+            let registry = load_registry(py)?;
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(value.clone_ref(py));
+            };
+
+            let out = PyList::empty_bound(py);
+            let mut seen: Vec<PyObject> = Vec::new();
+            for item in items {
+                let Some(key) =
+                    resolve_relative_read_path(py, &module, &registry, &item, &selector_path)?
                 else {
                     out.append(item)?;
                     continue;
@@ -2001,6 +4499,145 @@ fn apply_builtin_filter(
             }
             Ok(out.into())
         }
+        BuiltinFilter::Pivot(row_value, col_value, cell_value, policy) => {
+            let sum = match policy {
+                Some(policy) => match extract_string_arg(py, policy, "pivot", "policy")?.as_str() {
+                    "last" => false,
+                    "sum" => true,
+                    other => {
+                        return Err(PyValueError::new_err(format!(
+                            "Filter '$pivot' got unsupported policy '{other}'. Expected one of: last, sum."
+                        )))
+                    }
+                },
+                None => false,
+            };
+            let row_path = extract_string_arg(py, row_value, "pivot", "row path")?;
+            let col_path = extract_string_arg(py, col_value, "pivot", "column path")?;
+            let cell_path = extract_string_arg(py, cell_value, "pivot", "value path")?;
+            let module = py.import_bound("dictwalk.dictwalk")?;
+            let registry = load_registry(py)?;
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(value.clone_ref(py));
+            };
+
+            let out = PyDict::new_bound(py);
+            for item in items {
+                if !is_read_mapping(item.bind(py)) {
+                    continue;
+                }
+                let resolve =
+                    |path: &str| resolve_relative_read_path(py, &module, &registry, &item, path);
+                let (Some(row), Some(col), Some(cell)) = (
+                    resolve(&row_path)?,
+                    resolve(&col_path)?,
+                    resolve(&cell_path)?,
+                ) else {
+                    continue;
+                };
+
+                let row_dict = match out.get_item(row.clone_ref(py))? {
+                    Some(existing) => existing.downcast_into::<PyDict>()?,
+                    None => {
+                        let new_row = PyDict::new_bound(py);
+                        out.set_item(row, &new_row)?;
+                        new_row
+                    }
+                };
+                if !sum {
+                    row_dict.set_item(col, cell)?;
+                    continue;
+                }
+                if cell.is_none(py) {
+                    continue;
+                }
+                let total = match row_dict.get_item(col.clone_ref(py))? {
+                    Some(existing) => apply_binary_op(py, &existing.into(), "__add__", &cell)?,
+                    None => cell,
+                };
+                row_dict.set_item(col, total)?;
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::AggregateBy(name, key_value, field_value) => {
+            let key_path = extract_string_arg(py, key_value, name, "key path")?;
+            let field_path = extract_string_arg(py, field_value, name, "value path")?;
+            let module = py.import_bound("dictwalk.dictwalk")?;
+            let registry = load_registry(py)?;
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(value.clone_ref(py));
+            };
+
+            let groups = PyDict::new_bound(py);
+            for item in items {
+                if !is_read_mapping(item.bind(py)) {
+                    continue;
+                }
+                let Some(key) =
+                    resolve_relative_read_path(py, &module, &registry, &item, &key_path)?
+                else {
+                    continue;
+                };
+                let Some(field) =
+                    resolve_relative_read_path(py, &module, &registry, &item, &field_path)?
+                else {
+                    continue;
+                };
+                if field.is_none(py) {
+                    continue;
+                }
+
+                match groups.get_item(key.clone_ref(py))? {
+                    Some(existing) => existing.downcast::<PyList>()?.append(field)?,
+                    None => groups.set_item(key, PyList::new_bound(py, [field]))?,
+                }
+            }
+
+            let out = PyDict::new_bound(py);
+            for (key, group) in groups.iter() {
+                let group: PyObject = group.into();
+                let total = call_builtin1(py, "sum", &group)?;
+                let aggregate = if *name == "avg_by" {
+                    let len = group.bind(py).len()? as i64;
+                    apply_binary_op(py, &total, "__truediv__", &len.to_object(py))?
+                } else {
+                    total
+                };
+                out.set_item(key, aggregate)?;
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::DedupKeys => {
+            let Some(entries) = collect_sequence_items(py, value)? else {
+                return Ok(py.None());
+            };
+
+            let mut groups: Vec<(PyObject, Bound<'_, PyList>)> = Vec::new();
+            for entry in entries {
+                let entry_bound = entry.bind(py);
+                if !is_list_or_tuple(entry_bound) || entry_bound.len()? != 2 {
+                    return Err(PyTypeError::new_err(format!(
+                        "Filter '$dedup_keys' expects [key, value] pairs, got {}.",
+                        entry_bound.repr()?
+                    )));
+                }
+                let key: PyObject = entry_bound.get_item(0)?.into();
+                let entry_value = entry_bound.get_item(1)?;
+                match groups
+                    .iter()
+                    .find(|(seen, _)| compare_values(py, seen, &key, "==").unwrap_or(false))
+                {
+                    Some((_, values)) => values.append(entry_value)?,
+                    None => groups.push((key, PyList::new_bound(py, [entry_value]))),
+                }
+            }
+
+            let out = PyList::empty_bound(py);
+            for (key, values) in groups {
+                out.append(PyList::new_bound(py, [key, values.into_any().unbind()]))?;
+            }
+            Ok(out.into())
+        }
         BuiltinFilter::Reverse => {
             let value_bound = value.bind(py);
             if !is_list_or_tuple(&value_bound) {
@@ -2014,19 +4651,89 @@ fn apply_builtin_filter(
             }
             Ok(reversed.into())
         }
-        BuiltinFilter::Chunk(size_value) => {
+        BuiltinFilter::Repeat(count) => {
             let value_bound = value.bind(py);
-            if !is_list_or_tuple(&value_bound) {
+            if !(value_bound.is_instance_of::<PyString>() || is_list_or_tuple(&value_bound)) {
                 return Ok(value.clone_ref(py));
             }
-
-            let chunk_size_obj = call_builtin1(py, "int", size_value)?;
-            let chunk_size = chunk_size_obj.bind(py).extract::<isize>()?;
-            if chunk_size <= 0 {
+            apply_binary_op(py, value, "__mul__", count)
+        }
+        BuiltinFilter::ClampLen(max_len) => {
+            let max_len_obj = call_builtin1(py, "int", max_len)?;
+            let max_len = max_len_obj.bind(py).extract::<isize>()?.max(0);
+            let value_bound = value.bind(py);
+            if let Ok(source) = value_bound.downcast::<PyDict>() {
+                let out = PyDict::new_bound(py);
+                for (key, item) in source.iter().take(max_len as usize) {
+                    out.set_item(key, item)?;
+                }
+                return Ok(out.into());
+            }
+            if !(value_bound.is_instance_of::<PyString>() || is_list_or_tuple(&value_bound)) {
+                return Ok(value.clone_ref(py));
+            }
+            let slice = PySlice::new_bound(py, 0, max_len, 1);
+            value_bound.get_item(slice).map(|v| v.into())
+        }
+        BuiltinFilter::PadList(length, fill, truncate) => {
+            let Some(mut items) = collect_sequence_items(py, value)? else {
                 return Ok(py.None());
+            };
+            let length_obj = call_builtin1(py, "int", length)?;
+            let length = length_obj.bind(py).extract::<isize>()?.max(0) as usize;
+            if *truncate {
+                items.truncate(length);
+            }
+            while items.len() < length {
+                items.push(match fill {
+                    Some(fill) => fill.clone_ref(py),
+                    None => py.None(),
+                });
             }
-
-            let chunked = PyList::empty_bound(py);
+            Ok(PyList::new_bound(py, items).into())
+        }
+        BuiltinFilter::Zip(other) => {
+            let other = extract_list_arg(py, other, "zip", "other")?;
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(py.None());
+            };
+            let out = PyList::empty_bound(py);
+            for (item, other_item) in items.into_iter().zip(other) {
+                out.append(PyList::new_bound(py, [item, other_item]))?;
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::Interleave(other) => {
+            let other = extract_list_arg(py, other, "interleave", "other")?;
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(py.None());
+            };
+            let out = PyList::empty_bound(py);
+            let mut items = items.into_iter();
+            let mut other = other.into_iter();
+            while items.len() > 0 || other.len() > 0 {
+                if let Some(item) = items.next() {
+                    out.append(item)?;
+                }
+                if let Some(other_item) = other.next() {
+                    out.append(other_item)?;
+                }
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::Chunk(size_value) => {
+            let value_bound = value.bind(py);
+            if !is_list_or_tuple(&value_bound) {
+                return Ok(value.clone_ref(py));
+            }
+
+            let chunk_size_obj = call_builtin1(py, "int", size_value)?;
+            let chunk_size = chunk_size_obj.bind(py).extract::<isize>()?;
+            if chunk_size <= 0 {
+                return Ok(py.None());
+            }
+
+            let chunked = PyList::empty_bound(py);
             let value_len = value_bound.len()? as isize;
             let mut start = 0isize;
             while start < value_len {
@@ -2040,6 +4747,72 @@ fn apply_builtin_filter(
             }
             Ok(chunked.into())
         }
+        BuiltinFilter::SplitAt(index) => {
+            let value_bound = value.bind(py);
+            if !(value_bound.is_instance_of::<PyString>() || is_list_or_tuple(&value_bound)) {
+                return Ok(py.None());
+            }
+            let index_obj = call_builtin1(py, "int", index)?;
+            let index = index_obj.bind(py).extract::<isize>()?;
+            let value_len = value_bound.len()? as isize;
+            let split = if index < 0 {
+                (value_len + index).max(0)
+            } else {
+                index.min(value_len)
+            };
+            let head = value_bound.get_item(PySlice::new_bound(py, 0, split, 1))?;
+            let tail = value_bound.get_item(PySlice::new_bound(py, split, value_len, 1))?;
+            Ok(PyList::new_bound(py, [head, tail]).into())
+        }
+        BuiltinFilter::Rotate(steps) => {
+            let value_bound = value.bind(py);
+            if !(value_bound.is_instance_of::<PyString>() || is_list_or_tuple(&value_bound)) {
+                return Ok(value.clone_ref(py));
+            }
+            let steps_obj = call_builtin1(py, "int", steps)?;
+            let steps = steps_obj.bind(py).extract::<isize>()?;
+            let value_len = value_bound.len()? as isize;
+            if value_len == 0 {
+                return Ok(value.clone_ref(py));
+            }
+            let split = steps.rem_euclid(value_len);
+            let head = value_bound.get_item(PySlice::new_bound(py, 0, split, 1))?;
+            let tail = value_bound.get_item(PySlice::new_bound(py, split, value_len, 1))?;
+            tail.add(head).map(|v| v.into())
+        }
+        BuiltinFilter::Transpose(pad, fill) => {
+            let Some(rows) = collect_sequence_items(py, value)? else {
+                return Ok(py.None());
+            };
+            let mut matrix: Vec<Vec<PyObject>> = Vec::with_capacity(rows.len());
+            for row in &rows {
+                let Some(cells) = collect_sequence_items(py, row)? else {
+                    return Ok(py.None());
+                };
+                matrix.push(cells);
+            }
+            let row_lengths = matrix.iter().map(Vec::len);
+            let width = if *pad {
+                row_lengths.max().unwrap_or(0)
+            } else {
+                row_lengths.min().unwrap_or(0)
+            };
+            let out = PyList::empty_bound(py);
+            for column in 0..width {
+                let out_row = PyList::empty_bound(py);
+                for cells in &matrix {
+                    match cells.get(column) {
+                        Some(cell) => out_row.append(cell)?,
+                        None => out_row.append(match fill {
+                            Some(fill) => fill.clone_ref(py),
+                            None => py.None(),
+                        })?,
+                    }
+                }
+                out.append(out_row)?;
+            }
+            Ok(out.into())
+        }
         BuiltinFilter::Flatten => {
             let value_bound = value.bind(py);
             if !is_list_or_tuple(&value_bound) {
@@ -2068,7 +4841,7 @@ fn apply_builtin_filter(
             }
 
             let flattened = PyList::empty_bound(py);
-            flatten_deep_into(value_bound, &flattened)?;
+            flatten_deep_into(py, value_bound, &flattened, 0)?;
             Ok(flattened.into())
         }
         BuiltinFilter::Sorted(reverse) => {
@@ -2087,6 +4860,22 @@ fn apply_builtin_filter(
                 call_builtin1(py, "sorted", value)
             }
         }
+        BuiltinFilter::SortedByAbs(reverse) => {
+            let value_bound = value.bind(py);
+            if !is_list_or_tuple(&value_bound) {
+                return Ok(value.clone_ref(py));
+            }
+            let builtins = py.import_bound("builtins")?;
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item("key", builtins.getattr("abs")?)?;
+            if let Some(reverse_flag) = reverse {
+                kwargs.set_item("reverse", reverse_flag.clone_ref(py))?;
+            }
+            builtins
+                .getattr("sorted")?
+                .call((value.clone_ref(py),), Some(&kwargs))
+                .map(|v| v.into())
+        }
         BuiltinFilter::First => {
             let value_bound = value.bind(py);
             if is_list_or_tuple(&value_bound) {
@@ -2111,6 +4900,41 @@ fn apply_builtin_filter(
         BuiltinFilter::Contains(needle) => {
             Ok(value.bind(py).contains(needle.clone_ref(py))?.to_object(py))
         }
+        BuiltinFilter::IndexOf(needle) => {
+            let value_bound = value.bind(py);
+            if value_bound.is_instance_of::<PyString>() {
+                let needle = needle.bind(py).str()?;
+                return value_bound
+                    .call_method1("find", (needle,))
+                    .map(|v| v.into());
+            }
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(py.None());
+            };
+            for (idx, item) in items.iter().enumerate() {
+                if compare_values(py, item, needle, "==").unwrap_or(false) {
+                    return Ok(idx.to_object(py));
+                }
+            }
+            Ok((-1i64).to_object(py))
+        }
+        BuiltinFilter::CountOccurrences(needle) => {
+            let value_bound = value.bind(py);
+            if value_bound.is_instance_of::<PyString>() {
+                let needle = needle.bind(py).str()?;
+                return value_bound
+                    .call_method1("count", (needle,))
+                    .map(|v| v.into());
+            }
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(py.None());
+            };
+            let count = items
+                .iter()
+                .filter(|item| compare_values(py, item, needle, "==").unwrap_or(false))
+                .count();
+            Ok(count.to_object(py))
+        }
         BuiltinFilter::In(haystack) => Ok(haystack
             .bind(py)
             .contains(value.clone_ref(py))?
@@ -2130,6 +4954,42 @@ fn apply_builtin_filter(
             .str()?
             .call_method0("title")
             .map(|v| v.into()),
+        BuiltinFilter::Indent(width) => {
+            let text = value.bind(py).str()?.to_string();
+            let width = call_builtin1(py, "int", width)?.bind(py).extract::<i64>()?;
+            let prefix = " ".repeat(width.max(0) as usize);
+            let indented: Vec<String> = text
+                .split('\n')
+                .map(|line| {
+                    if line.is_empty() {
+                        String::new()
+                    } else {
+                        format!("{prefix}{line}")
+                    }
+                })
+                .collect();
+            Ok(indented.join("\n").to_object(py))
+        }
+        BuiltinFilter::Dedent => py
+            .import_bound("textwrap")?
+            .call_method1("dedent", (value.bind(py).str()?,))
+            .map(|v| v.into()),
+        BuiltinFilter::WrapText(width, indent) => {
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item("width", call_builtin1(py, "int", width)?)?;
+            if let Some(indent) = indent {
+                let indent = call_builtin1(py, "int", indent)?
+                    .bind(py)
+                    .extract::<i64>()?;
+                let prefix = " ".repeat(indent.max(0) as usize);
+                kwargs.set_item("initial_indent", &prefix)?;
+                kwargs.set_item("subsequent_indent", &prefix)?;
+            }
+            py.import_bound("textwrap")?
+                .getattr("fill")?
+                .call((value.bind(py).str()?,), Some(&kwargs))
+                .map(|v| v.into())
+        }
         BuiltinFilter::Strip(chars) => {
             let s = value.bind(py).str()?;
             if let Some(chars) = chars {
@@ -2144,6 +5004,44 @@ fn apply_builtin_filter(
             .str()?
             .call_method1("replace", (old.clone_ref(py), new.clone_ref(py)))
             .map(|v| v.into()),
+        BuiltinFilter::ReplaceMap(mapping) => {
+            let mapping = mapping.bind(py);
+            let mapping = mapping.downcast::<PyDict>().map_err(|_| {
+                PyTypeError::new_err("Filter '$replace_map' expects mapping to be a dict.")
+            })?;
+            let mut replacements: Vec<(String, String)> = Vec::with_capacity(mapping.len());
+            for (old, new) in mapping.iter() {
+                let old = old.downcast::<PyString>().map_err(|_| {
+                    PyTypeError::new_err("Filter '$replace_map' expects string keys.")
+                })?;
+                let old = old.to_str()?.to_string();
+                if !old.is_empty() {
+                    replacements.push((old, new.str()?.to_str()?.to_string()));
+                }
+            }
+            // Stable sort: among keys of equal length, dict order decides.
+            replacements.sort_by_key(|(old, _)| std::cmp::Reverse(old.len()));
+
+            let text = value.bind(py).str()?.to_str()?.to_string();
+            let mut out = String::with_capacity(text.len());
+            let mut rest = text.as_str();
+            while let Some(ch) = rest.chars().next() {
+                match replacements
+                    .iter()
+                    .find(|(old, _)| rest.starts_with(old.as_str()))
+                {
+                    Some((old, new)) => {
+                        out.push_str(new);
+                        rest = &rest[old.len()..];
+                    }
+                    None => {
+                        out.push(ch);
+                        rest = &rest[ch.len_utf8()..];
+                    }
+                }
+            }
+            Ok(out.into_py(py))
+        }
         BuiltinFilter::RegexReplace(pattern, repl) => py
             .import_bound("re")?
             .getattr("sub")?
@@ -2162,6 +5060,42 @@ fn apply_builtin_filter(
                 s.call_method0("split").map(|v| v.into())
             }
         }
+        BuiltinFilter::SplitLines(keepends) => value
+            .bind(py)
+            .str()?
+            .call_method1("splitlines", (*keepends,))
+            .map(|v| v.into()),
+        BuiltinFilter::Html(function) => py
+            .import_bound("html")?
+            .call_method1(*function, (value.bind(py).str()?,))
+            .map(|v| v.into()),
+        BuiltinFilter::StripHtml(unescape) => {
+            let text = value.bind(py).str()?.to_string();
+            let stripped = HTML_MARKUP_RE.replace_all(&text, "").into_owned();
+            if !*unescape {
+                return Ok(stripped.to_object(py));
+            }
+            py.import_bound("html")?
+                .call_method1("unescape", (stripped,))
+                .map(|v| v.into())
+        }
+        BuiltinFilter::ToCsvRow(delimiter) => {
+            if !is_list_or_tuple(&value.bind(py)) {
+                return Ok(py.None());
+            }
+            let buffer = py.import_bound("io")?.getattr("StringIO")?.call0()?;
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item("lineterminator", "")?;
+            if let Some(delimiter) = delimiter {
+                kwargs.set_item("delimiter", delimiter.clone_ref(py))?;
+            }
+            let writer = py
+                .import_bound("csv")?
+                .getattr("writer")?
+                .call((buffer.clone(),), Some(&kwargs))?;
+            writer.call_method1("writerow", (value.clone_ref(py),))?;
+            buffer.call_method0("getvalue").map(|v| v.into())
+        }
         BuiltinFilter::Join(sep) => {
             let sep_obj = sep.bind(py).str()?;
             let join_input = if value.bind(py).is_instance_of::<PyList>()
@@ -2195,6 +5129,11 @@ fn apply_builtin_filter(
                 .call1((pattern.clone_ref(py), value.bind(py).str()?))?;
             Ok((!searched.is_none()).to_object(py))
         }
+        BuiltinFilter::Similarity(other) => {
+            let left = value.bind(py).str()?.to_string_lossy().to_string();
+            let right = other.bind(py).str()?.to_string_lossy().to_string();
+            Ok(similarity_ratio(&left, &right).to_object(py))
+        }
         BuiltinFilter::Const(constant) => Ok(constant.clone_ref(py)),
         BuiltinFilter::Default(default_value) => {
             if value.bind(py).is_none() {
@@ -2203,6 +5142,24 @@ fn apply_builtin_filter(
                 Ok(value.clone_ref(py))
             }
         }
+        BuiltinFilter::CoalesceEmptyTo(fallback) => {
+            if value.bind(py).is_none() || has_len_zero(py, value) {
+                Ok(fallback.clone_ref(py))
+            } else {
+                Ok(value.clone_ref(py))
+            }
+        }
+        BuiltinFilter::TypedDefault(default_value) => {
+            if value
+                .bind(py)
+                .get_type()
+                .is(&default_value.bind(py).get_type())
+            {
+                Ok(value.clone_ref(py))
+            } else {
+                Ok(default_value.clone_ref(py))
+            }
+        }
         BuiltinFilter::Coalesce(values) => {
             if !value.bind(py).is_none() {
                 return Ok(value.clone_ref(py));
@@ -2214,7 +5171,21 @@ fn apply_builtin_filter(
             }
             Ok(py.None())
         }
-        BuiltinFilter::Bool => {
+        BuiltinFilter::CoalesceFlat => {
+            let flattened = PyList::empty_bound(py);
+            flatten_deep_into(py, value.bind(py), &flattened, 0)?;
+            Ok(flattened
+                .iter()
+                .find(|item| !item.is_none())
+                .map(|item| item.into())
+                .unwrap_or_else(|| py.None()))
+        }
+        BuiltinFilter::CoalescePaths(values) => Ok(values
+            .iter()
+            .find(|item| !item.bind(py).is_none())
+            .map(|item| item.clone_ref(py))
+            .unwrap_or_else(|| py.None())),
+        BuiltinFilter::Bool(strict) => {
             if value.bind(py).is_instance_of::<PyString>() {
                 let normalized = value
                     .bind(py)
@@ -2222,12 +5193,83 @@ fn apply_builtin_filter(
                     .to_string_lossy()
                     .trim()
                     .to_lowercase();
-                return Ok(
-                    matches!(normalized.as_str(), "1" | "true" | "yes" | "y" | "on").to_object(py),
-                );
+                if matches!(normalized.as_str(), "1" | "true" | "yes" | "y" | "on") {
+                    return Ok(true.to_object(py));
+                }
+                if !strict || matches!(normalized.as_str(), "0" | "false" | "no" | "n" | "off") {
+                    return Ok(false.to_object(py));
+                }
+                return Ok(py.None());
             }
             Ok(value.bind(py).is_truthy()?.to_object(py))
         }
+        BuiltinFilter::Coerce(type_name) => {
+            let type_name = extract_string_arg(py, type_name, "coerce", "type name")?;
+            Ok(coerce_value(py, value, &type_name)?.unwrap_or_else(|| py.None()))
+        }
+        BuiltinFilter::CoerceKeys(type_name) => {
+            let type_name = extract_string_arg(py, type_name, "coerce_keys", "type name")?;
+            // Coercing `None` checks the type name even when there are no keys.
+            coerce_value(py, &py.None(), &type_name)?;
+            let Ok(source) = value.bind(py).downcast::<PyDict>() else {
+                return Ok(value.clone_ref(py));
+            };
+            let out = PyDict::new_bound(py);
+            for (key, item) in source.iter() {
+                let key: PyObject = key.into();
+                let coerced = coerce_value(py, &key, &type_name)?.unwrap_or(key);
+                out.set_item(coerced, item)?;
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::CoerceListOf(type_name, drop_failures) => {
+            let type_name = extract_string_arg(py, type_name, "coerce_list_of", "type name")?;
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(py.None());
+            };
+            let out = PyList::empty_bound(py);
+            for item in items {
+                match coerce_value(py, &item, &type_name)? {
+                    Some(coerced) => out.append(coerced)?,
+                    None if *drop_failures => {}
+                    None => out.append(py.None())?,
+                }
+            }
+            Ok(out.into())
+        }
+        BuiltinFilter::ToBoolStrict => {
+            let value_bound = value.bind(py);
+            let parsed = if value_bound.is_instance_of::<PyBool>() {
+                Some(value_bound.is_truthy()?)
+            } else if value_bound.is_instance_of::<PyInt>() {
+                match value_bound.extract::<i64>() {
+                    Ok(0) => Some(false),
+                    Ok(1) => Some(true),
+                    _ => None,
+                }
+            } else if value_bound.is_instance_of::<PyString>() {
+                let normalized = value_bound.str()?.to_string_lossy().trim().to_lowercase();
+                match normalized.as_str() {
+                    "1" | "true" | "yes" | "y" | "on" => Some(true),
+                    "0" | "false" | "no" | "n" | "off" => Some(false),
+                    _ => None,
+                }
+            } else {
+                None
+            };
+            match parsed {
+                Some(parsed) => Ok(parsed.to_object(py)),
+                None => Err(make_resolution_error(
+                    py,
+                    "$to_bool_strict",
+                    None,
+                    &format!(
+                        "Expected a boolean token, got {}.",
+                        value_bound.repr()?.to_string_lossy()
+                    ),
+                )),
+            }
+        }
         BuiltinFilter::TypeIs(name) => {
             let type_name = value
                 .bind(py)
@@ -2238,6 +5280,19 @@ fn apply_builtin_filter(
             let expected = name.bind(py).str()?.to_string_lossy().to_lowercase();
             Ok((type_name == expected).to_object(py))
         }
+        BuiltinFilter::AssertType(name) => {
+            let expected = extract_string_arg(py, name, "assert_type", "name")?;
+            let type_name = get_type_name(value.bind(py));
+            if type_name.to_lowercase() != expected.to_lowercase() {
+                return Err(make_resolution_error(
+                    py,
+                    &format!("$assert_type({})", name.bind(py).repr()?),
+                    None,
+                    &format!("Expected {expected}, got {type_name}."),
+                ));
+            }
+            Ok(value.clone_ref(py))
+        }
         BuiltinFilter::IsEmpty => {
             let result = value.bind(py).is_none() || has_len_zero(py, value);
             Ok(result.to_object(py))
@@ -2246,6 +5301,35 @@ fn apply_builtin_filter(
             let result = !(value.bind(py).is_none() || has_len_zero(py, value));
             Ok(result.to_object(py))
         }
+        BuiltinFilter::NanTo(replacement, include_inf) => {
+            let replace = |item: &PyObject| -> PyObject {
+                let item_bound = item.bind(py);
+                let is_target = item_bound.is_instance_of::<PyFloat>()
+                    && item_bound.extract::<f64>().is_ok_and(|number| {
+                        number.is_nan() || (*include_inf && number.is_infinite())
+                    });
+                if is_target {
+                    replacement.clone_ref(py)
+                } else {
+                    item.clone_ref(py)
+                }
+            };
+            let Some(items) = collect_sequence_items(py, value)? else {
+                return Ok(replace(value));
+            };
+            Ok(PyList::new_bound(py, items.iter().map(replace)).into())
+        }
+        BuiltinFilter::PruneNulls(drop_empty) => {
+            let bound = value.bind(py);
+            if !is_read_mapping(&bound) && !bound.is_instance_of::<PyList>() {
+                return Ok(value.clone_ref(py));
+            }
+            match prune_nulls(py, value, *drop_empty, 0)? {
+                Some(pruned) => Ok(pruned),
+                None if bound.is_instance_of::<PyList>() => Ok(PyList::empty_bound(py).into()),
+                None => Ok(PyDict::new_bound(py).into()),
+            }
+        }
         BuiltinFilter::Compact => {
             let Some(items) = collect_sequence_items(py, value)? else {
                 return Ok(value.clone_ref(py));
@@ -2271,11 +5355,47 @@ fn apply_builtin_filter(
                 Err(_) => Ok(py.None()),
             }
         }
+        BuiltinFilter::Hash(algorithm) => {
+            let algorithm = extract_string_arg(py, algorithm, "hash", "algorithm")?;
+            py.import_bound("hashlib")?
+                .call_method1("new", (algorithm, hash_payload(py, value)?))?
+                .call_method0("hexdigest")
+                .map(|v| v.into())
+        }
+        BuiltinFilter::Checksum(function, modulo) => {
+            if let Some(modulo) = modulo {
+                let buckets_obj = call_builtin1(py, "int", modulo)?;
+                let buckets = buckets_obj.bind(py).extract::<i64>()?;
+                if buckets <= 0 {
+                    return Err(PyValueError::new_err(format!(
+                        "Filter '${function}' expects a positive bucket count, got {buckets}."
+                    )));
+                }
+            }
+            let checksum: PyObject = py
+                .import_bound("zlib")?
+                .call_method1(*function, (hash_payload(py, value)?,))?
+                .into();
+            match modulo {
+                Some(modulo) => apply_binary_op(py, &checksum, "__mod__", modulo),
+                None => Ok(checksum),
+            }
+        }
         BuiltinFilter::ToJson => py
             .import_bound("json")?
             .getattr("dumps")?
             .call1((value.clone_ref(py),))
             .map(|v| v.into()),
+        BuiltinFilter::CanonicalJson => {
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item("sort_keys", true)?;
+            kwargs.set_item("separators", (",", ":"))?;
+            kwargs.set_item("allow_nan", false)?;
+            py.import_bound("json")?
+                .getattr("dumps")?
+                .call((value.clone_ref(py),), Some(&kwargs))
+                .map(|v| v.into())
+        }
         BuiltinFilter::ToDatetime(fmt) => {
             Ok(as_datetime(py, value, fmt.as_ref())?.unwrap_or_else(|| py.None()))
         }
@@ -2297,24 +5417,148 @@ fn apply_builtin_filter(
             };
             dt.bind(py).call_method0("timestamp").map(|v| v.into())
         }
+        BuiltinFilter::TimestampMs => {
+            let Some(dt) = as_datetime_or_none(py, value)? else {
+                return Ok(py.None());
+            };
+            // Whole-microsecond timedelta arithmetic keeps large timestamps exact,
+            // where `timestamp() * 1000` can land just below the true value.
+            let (epoch, millisecond) = epoch_and_millisecond(py)?;
+            let elapsed = dt
+                .bind(py)
+                .call_method1("astimezone", (epoch.getattr("tzinfo")?,))?
+                .call_method1("__sub__", (epoch,))?;
+            let millis = elapsed.call_method1("__truediv__", (millisecond,))?;
+            call_builtin1(py, "int", &millis.unbind())
+        }
+        BuiltinFilter::FromTimestampMs => {
+            let value_bound = value.bind(py);
+            if !is_number(py, &value_bound)? {
+                return Ok(py.None());
+            }
+            let millis = if value_bound.is_instance_of::<PyInt>() {
+                value.clone_ref(py)
+            } else {
+                call_builtin1(py, "float", value)?
+            };
+            let (epoch, millisecond) = epoch_and_millisecond(py)?;
+            let converted = millisecond
+                .call_method1("__mul__", (millis,))
+                .and_then(|offset| epoch.call_method1("__add__", (offset,)));
+            match converted {
+                Ok(dt) => Ok(dt.into()),
+                // Beyond the range `datetime` can represent, or NaN/infinity.
+                Err(err)
+                    if err.is_instance_of::<PyOverflowError>(py)
+                        || err.is_instance_of::<PyOSError>(py)
+                        || err.is_instance_of::<PyValueError>(py) =>
+                {
+                    Ok(py.None())
+                }
+                Err(err) => Err(err),
+            }
+        }
         BuiltinFilter::AgeSeconds => {
             let dt = match as_datetime(py, value, None)? {
                 Some(dt) => dt,
                 None => return Ok(py.None()),
             };
-            let datetime_mod = py.import_bound("datetime")?;
-            let datetime_type = datetime_mod.getattr("datetime")?;
-            let timezone_utc = datetime_mod.getattr("timezone")?.getattr("utc")?;
-            let tzinfo = dt.bind(py).getattr("tzinfo")?;
-            let now = if tzinfo.is_none() {
-                datetime_type.call_method1("now", (timezone_utc,))?
-            } else {
-                datetime_type.call_method1("now", (tzinfo,))?
+            Ok(age_seconds(py, &dt)?.to_object(py))
+        }
+        BuiltinFilter::AgeDays => {
+            let Some(dt) = as_datetime_or_none(py, value)? else {
+                return Ok(py.None());
+            };
+            Ok((age_seconds(py, &dt)? / 86_400.0).to_object(py))
+        }
+        BuiltinFilter::HumanizeDuration(largest_only) => {
+            let value_bound = value.bind(py);
+            if !(value_bound.is_instance_of::<PyInt>() || value_bound.is_instance_of::<PyFloat>()) {
+                return Ok(py.None());
+            }
+            let seconds = value_bound.extract::<f64>()?;
+            if !seconds.is_finite() {
+                return Ok(py.None());
+            }
+            Ok(humanize_duration(seconds, *largest_only).to_object(py))
+        }
+        BuiltinFilter::Duration(end) => {
+            let start = match as_datetime_or_none(py, value)? {
+                Some(dt) => dt,
+                None => return Ok(py.None()),
+            };
+            let end = match as_datetime_or_none(py, end)? {
+                Some(dt) => dt,
+                None => return Ok(py.None()),
             };
-            now.call_method1("__sub__", (dt,))
+            // Naive values are read as UTC, like `$between_dates`, so a naive and
+            // an aware datetime can still be subtracted.
+            let (start, end) = (assume_utc(py, start)?, assume_utc(py, end)?);
+            end.bind(py)
+                .call_method1("__sub__", (start,))
                 .and_then(|delta| delta.call_method0("total_seconds"))
                 .map(|v| v.into())
         }
+        BuiltinFilter::BusinessDaysBetween(end, holidays) => {
+            let Some(start) = datetime_ordinal(py, value)? else {
+                return Ok(py.None());
+            };
+            let Some(end) = datetime_ordinal(py, end)? else {
+                return Ok(py.None());
+            };
+            let mut count = weekdays_before(end) - weekdays_before(start);
+            if let Some(holidays) = holidays {
+                let holidays = extract_list_arg(py, holidays, "business_days_between", "holidays")?;
+                let mut seen: Vec<i64> = Vec::new();
+                for holiday in &holidays {
+                    let Some(day) = datetime_ordinal(py, holiday)? else {
+                        return Ok(py.None());
+                    };
+                    let is_weekday = (day - 1).rem_euclid(7) < 5;
+                    if !is_weekday || seen.contains(&day) {
+                        continue;
+                    }
+                    seen.push(day);
+                    if start <= day && day < end {
+                        count -= 1;
+                    } else if end <= day && day < start {
+                        count += 1;
+                    }
+                }
+            }
+            Ok(count.to_object(py))
+        }
+        BuiltinFilter::DateTrunc(unit) => {
+            let unit = extract_string_arg(py, unit, "date_trunc", "unit")?;
+            match as_datetime_or_none(py, value)? {
+                Some(dt) => truncate_datetime(py, &dt, &unit),
+                None => Ok(py.None()),
+            }
+        }
+        BuiltinFilter::IsoWeek | BuiltinFilter::IsoYear => {
+            let dt = match as_datetime_or_none(py, value)? {
+                Some(dt) => dt,
+                None => return Ok(py.None()),
+            };
+            // isocalendar() returns (year, week, weekday).
+            let idx = if matches!(filter, BuiltinFilter::IsoWeek) {
+                1
+            } else {
+                0
+            };
+            dt.bind(py)
+                .call_method0("isocalendar")?
+                .get_item(idx)
+                .map(|v| v.into())
+        }
+        BuiltinFilter::IsWeekend | BuiltinFilter::IsWeekday => {
+            let Some(dt) = as_datetime_or_none(py, value)? else {
+                return Ok(false.to_object(py));
+            };
+            // weekday() is 0 for Monday through 6 for Sunday.
+            let weekend = dt.bind(py).call_method0("weekday")?.extract::<u8>()? >= 5;
+            Ok((weekend == matches!(filter, BuiltinFilter::IsWeekend)).to_object(py))
+        }
         BuiltinFilter::Before(rhs) => {
             let left = match as_datetime(py, value, None)? {
                 Some(dt) => dt,
@@ -2326,6 +5570,16 @@ fn apply_builtin_filter(
             };
             Ok(compare_with_fallback(py, &left, &right, "<")?.to_object(py))
         }
+        BuiltinFilter::BetweenDates(start, end) => {
+            let coerce = |item: &PyObject| as_utc_datetime(py, item).ok().flatten();
+            let (Some(dt), Some(start), Some(end)) = (coerce(value), coerce(start), coerce(end))
+            else {
+                return Ok(false.to_object(py));
+            };
+            let within =
+                compare_values(py, &dt, &start, ">=")? && compare_values(py, &dt, &end, "<=")?;
+            Ok(within.to_object(py))
+        }
         BuiltinFilter::After(rhs) => {
             let left = match as_datetime(py, value, None)? {
                 Some(dt) => dt,
@@ -2405,6 +5659,27 @@ fn compare_values(
     left_bound.rich_compare(right_bound, op)?.is_truthy()
 }
 
+fn resolve_index_value(
+    py: Python<'_>,
+    index: &IndexValue,
+    root_data: &PyObject,
+) -> PyResult<isize> {
+    let reference = match index {
+        IndexValue::Literal(index) => return Ok(*index),
+        IndexValue::RootReference(reference) => reference,
+    };
+
+    let value = resolve_root_reference_value(py, root_data, reference)?;
+    let value_bound = value.bind(py);
+    if !value_bound.is_instance_of::<PyInt>() || value_bound.is_instance_of::<PyBool>() {
+        return Err(PyTypeError::new_err(format!(
+            "Index reference '{reference}' must resolve to an int, got {}.",
+            get_type_name(&value_bound)
+        )));
+    }
+    value_bound.extract::<isize>()
+}
+
 fn resolve_root_reference_value(
     py: Python<'_>,
     root_data: &PyObject,
@@ -2439,6 +5714,7 @@ enum PredicateExpr {
     Not(Box<PredicateExpr>),
     And(Box<PredicateExpr>, Box<PredicateExpr>),
     Or(Box<PredicateExpr>, Box<PredicateExpr>),
+    Coalesce(Box<PredicateExpr>, Box<PredicateExpr>),
 }
 
 fn tokenize_boolean_filter_expression(expression: &str) -> Vec<String> {
@@ -2462,11 +5738,21 @@ fn tokenize_boolean_filter_expression(expression: &str) -> Vec<String> {
             i += 2;
             continue;
         }
+        if i + 1 < bytes.len() && &expression[i..i + 2] == "??" {
+            tokens.push("??".to_string());
+            i += 2;
+            continue;
+        }
         if ch == '(' || ch == ')' || ch == '!' {
             tokens.push(ch.to_string());
             i += 1;
             continue;
         }
+        if let Some((operator, len)) = boolean_word_operator_at(bytes, i) {
+            tokens.push(operator.to_string());
+            i += len;
+            continue;
+        }
 
         let start = i;
         let mut paren_depth = 0i32;
@@ -2492,7 +5778,10 @@ fn tokenize_boolean_filter_expression(expression: &str) -> Vec<String> {
                 if i + 1 < bytes.len() && &expression[i..i + 2] == "||" {
                     break;
                 }
-                if c == '!' {
+                if i + 1 < bytes.len() && &expression[i..i + 2] == "??" {
+                    break;
+                }
+                if c == '!' || boolean_word_operator_at(bytes, i).is_some() {
                     break;
                 }
             }
@@ -2507,13 +5796,14 @@ fn tokenize_boolean_filter_expression(expression: &str) -> Vec<String> {
     tokens
 }
 
-struct PredicateParser<'py> {
+struct PredicateParser<'py, 'root> {
     py: Python<'py>,
+    root_data: Option<&'root PyObject>,
     tokens: Vec<String>,
     idx: usize,
 }
 
-impl PredicateParser<'_> {
+impl PredicateParser<'_, '_> {
     fn parse(mut self) -> Result<PredicateExpr, String> {
         let result = self.parse_or()?;
         if self.idx != self.tokens.len() {
@@ -2536,19 +5826,29 @@ impl PredicateParser<'_> {
     }
 
     fn parse_and(&mut self) -> Result<PredicateExpr, String> {
-        let mut left = self.parse_not()?;
+        let mut left = self.parse_coalesce()?;
         while self.peek() == Some("&&") {
             self.consume("&&")?;
-            let right = self.parse_not()?;
+            let right = self.parse_coalesce()?;
             left = PredicateExpr::And(Box::new(left), Box::new(right));
         }
         Ok(left)
     }
 
-    fn parse_not(&mut self) -> Result<PredicateExpr, String> {
-        if self.peek() == Some("!") {
-            self.consume("!")?;
-            let inner = self.parse_not()?;
+    fn parse_coalesce(&mut self) -> Result<PredicateExpr, String> {
+        let mut left = self.parse_not()?;
+        while self.peek() == Some("??") {
+            self.consume("??")?;
+            let right = self.parse_not()?;
+            left = PredicateExpr::Coalesce(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_not(&mut self) -> Result<PredicateExpr, String> {
+        if self.peek() == Some("!") {
+            self.consume("!")?;
+            let inner = self.parse_not()?;
             return Ok(PredicateExpr::Not(Box::new(inner)));
         }
         self.parse_primary()
@@ -2567,7 +5867,7 @@ impl PredicateParser<'_> {
             .ok_or("Unexpected end of boolean path filter expression.".to_string())?
             .to_string();
         self.idx += 1;
-        let pipeline = compile_builtin_pipeline(self.py, &token, None)
+        let pipeline = compile_builtin_pipeline(self.py, &token, self.root_data)
             .ok_or_else(|| format!("Invalid path filter token '{token}' in boolean expression."))?;
         Ok(PredicateExpr::Pipeline(pipeline))
     }
@@ -2593,20 +5893,63 @@ impl PredicateParser<'_> {
     }
 }
 
+/// Word operators only turn an expression into a boolean predicate when it opens
+/// like one, so an unquoted literal such as `salt and pepper` stays a literal.
+/// A word operator whose operand is a field (`0 and not .a==1`) also counts, so
+/// it is rejected like the `&&`/`!` spelling instead of read as a literal.
+fn has_boolean_word_operator(expr: &str) -> bool {
+    let trimmed = expr.trim_start();
+    let bytes = trimmed.as_bytes();
+    let opens_predicate =
+        trimmed.starts_with(['$', '(', '!']) || boolean_word_operator_at(bytes, 0).is_some();
+    (0..bytes.len()).any(|i| match boolean_word_operator_at(bytes, i) {
+        Some((_, len)) => opens_predicate || word_operand_is_field(bytes, i + len),
+        None => false,
+    })
+}
+
+/// Whether the operand starting at `i` is a field expression, possibly behind
+/// `(`, `!` or `not`.
+fn word_operand_is_field(bytes: &[u8], mut i: usize) -> bool {
+    loop {
+        match bytes.get(i) {
+            Some(b'(' | b'!') => i += 1,
+            Some(byte) if byte.is_ascii_whitespace() => i += 1,
+            Some(b'.') => return true,
+            _ => match boolean_word_operator_at(bytes, i) {
+                Some(("!", len)) => i += len,
+                _ => return false,
+            },
+        }
+    }
+}
+
 fn compile_builtin_or_boolean_predicate(
     py: Python<'_>,
     expr: &str,
+    root_data: Option<&PyObject>,
 ) -> Result<Option<PredicateExpr>, String> {
-    if expr.contains("&&") || expr.contains("||") || expr.contains('!') {
+    // Quoted literals are plain values even when they contain boolean operators.
+    if expr.starts_with('\'') || expr.starts_with('"') {
+        return Ok(None);
+    }
+
+    if expr.contains("&&")
+        || expr.contains("||")
+        || expr.contains("??")
+        || expr.contains('!')
+        || has_boolean_word_operator(expr)
+    {
         let parser = PredicateParser {
             py,
+            root_data,
             tokens: tokenize_boolean_filter_expression(expr),
             idx: 0,
         };
         return parser.parse().map(Some);
     }
 
-    if let Some(pipeline) = compile_builtin_pipeline(py, expr, None) {
+    if let Some(pipeline) = compile_builtin_pipeline(py, expr, root_data) {
         return Ok(Some(PredicateExpr::Pipeline(pipeline)));
     }
 
@@ -2633,6 +5976,30 @@ fn eval_predicate_expr(py: Python<'_>, expr: &PredicateExpr, value: &PyObject) -
             }
             eval_predicate_expr(py, right, value)
         }
+        PredicateExpr::Coalesce(..) => eval_predicate_value(py, expr, value)?.bind(py).is_truthy(),
+    }
+}
+
+/// Evaluates `expr` for its value rather than its truthiness: pipelines yield
+/// their output, `??` yields the first non-None operand, and boolean
+/// operators yield a bool.
+fn eval_predicate_value(
+    py: Python<'_>,
+    expr: &PredicateExpr,
+    value: &PyObject,
+) -> PyResult<PyObject> {
+    match expr {
+        PredicateExpr::Pipeline(pipeline) => {
+            apply_builtin_pipeline(py, value.clone_ref(py), pipeline)
+        }
+        PredicateExpr::Coalesce(left, right) => {
+            let left_value = eval_predicate_value(py, left, value)?;
+            if !left_value.is_none(py) {
+                return Ok(left_value);
+            }
+            eval_predicate_value(py, right, value)
+        }
+        _ => Ok(eval_predicate_expr(py, expr, value)?.to_object(py)),
     }
 }
 
@@ -2641,8 +6008,9 @@ fn resolve_predicate_filter(
     _registry: &Bound<'_, PyAny>,
     py: Python<'_>,
     expr: &str,
+    root_data: Option<&PyObject>,
 ) -> PyResult<Option<PredicateExpr>> {
-    compile_builtin_or_boolean_predicate(py, expr)
+    compile_builtin_or_boolean_predicate(py, expr, root_data)
         .map_err(|message| make_parse_error(py, expr, Some(expr), &message))
 }
 
@@ -2650,6 +6018,7 @@ enum FieldValueResolver {
     CurrentItem,
     CurrentItemBuiltinPipeline(BuiltinFilterPipeline),
     CurrentItemTransform(Option<BuiltinFilterPipeline>),
+    CurrentItemExpr(PredicateExpr),
     PredicateFilter(PredicateExpr),
     Key(String),
     RelativePath(Vec<ParsedToken>),
@@ -2661,24 +6030,71 @@ enum ValueMatcher {
     Literal(PyObject),
 }
 
-struct CompiledFilterMatcher {
+struct CompiledFilterClause {
     field_resolver: FieldValueResolver,
     value_matcher: ValueMatcher,
+    operator: String,
+    raw_field: String,
     raw_value: String,
 }
 
+enum CompiledFilterMatcher {
+    Clause(CompiledFilterClause),
+    And(Box<CompiledFilterMatcher>, Box<CompiledFilterMatcher>),
+    Or(Box<CompiledFilterMatcher>, Box<CompiledFilterMatcher>),
+}
+
 fn compile_filter_matcher(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    filter: &FilterExpr,
+    root_data: Option<&PyObject>,
+) -> PyResult<CompiledFilterMatcher> {
+    match filter {
+        FilterExpr::Clause {
+            field,
+            operator,
+            value,
+        } => compile_filter_clause(py, module, registry, field, operator, value, root_data)
+            .map(CompiledFilterMatcher::Clause),
+        FilterExpr::And(left, right) => Ok(CompiledFilterMatcher::And(
+            Box::new(compile_filter_matcher(
+                py, module, registry, left, root_data,
+            )?),
+            Box::new(compile_filter_matcher(
+                py, module, registry, right, root_data,
+            )?),
+        )),
+        FilterExpr::Or(left, right) => Ok(CompiledFilterMatcher::Or(
+            Box::new(compile_filter_matcher(
+                py, module, registry, left, root_data,
+            )?),
+            Box::new(compile_filter_matcher(
+                py, module, registry, right, root_data,
+            )?),
+        )),
+    }
+}
+
+fn compile_filter_clause(
     py: Python<'_>,
     module: &Bound<'_, PyModule>,
     registry: &Bound<'_, PyAny>,
     field: &str,
+    operator: &str,
     value: &str,
-) -> PyResult<CompiledFilterMatcher> {
+    root_data: Option<&PyObject>,
+) -> PyResult<CompiledFilterClause> {
     let field_resolver = if field == "." {
         FieldValueResolver::CurrentItem
     } else if let Some(field_transform) = field.strip_prefix(".|") {
-        if let Some(pipeline) = compile_builtin_pipeline(py, field_transform, None) {
+        if let Some(pipeline) = compile_builtin_pipeline(py, field_transform, root_data) {
             FieldValueResolver::CurrentItemBuiltinPipeline(pipeline)
+        } else if let Some(expr) =
+            resolve_predicate_filter(module, registry, py, field_transform, root_data)?
+        {
+            FieldValueResolver::CurrentItemExpr(expr)
         } else {
             FieldValueResolver::CurrentItemTransform(None)
         }
@@ -2689,23 +6105,29 @@ fn compile_filter_matcher(
         } else {
             FieldValueResolver::Key(field_key.to_string())
         }
-    } else if let Some(field_path_filter) = resolve_predicate_filter(module, registry, py, field)? {
+    } else if let Some(field_path_filter) =
+        resolve_predicate_filter(module, registry, py, field, root_data)?
+    {
         FieldValueResolver::PredicateFilter(field_path_filter)
     } else {
         FieldValueResolver::Key(field.to_string())
     };
 
-    let value_matcher = if let Some(pipeline) = compile_builtin_pipeline(py, value, None) {
+    let value_matcher = if let Some(pipeline) = compile_builtin_pipeline(py, value, root_data) {
         ValueMatcher::BuiltinPipeline(pipeline)
-    } else if let Some(path_filter) = resolve_predicate_filter(module, registry, py, value)? {
+    } else if let Some(path_filter) =
+        resolve_predicate_filter(module, registry, py, value, root_data)?
+    {
         ValueMatcher::PredicateExpr(path_filter)
     } else {
-        ValueMatcher::Literal(parse_literal(py, value))
+        ValueMatcher::Literal(parse_filter_literal(py, value))
     };
 
-    Ok(CompiledFilterMatcher {
+    Ok(CompiledFilterClause {
         field_resolver,
         value_matcher,
+        operator: operator.to_string(),
+        raw_field: field.to_string(),
         raw_value: value.to_string(),
     })
 }
@@ -2714,7 +6136,7 @@ fn resolve_filter_field_value_compiled(
     py: Python<'_>,
     module: &Bound<'_, PyModule>,
     registry: &Bound<'_, PyAny>,
-    matcher: &CompiledFilterMatcher,
+    matcher: &CompiledFilterClause,
     item: &PyObject,
 ) -> PyResult<PyObject> {
     match &matcher.field_resolver {
@@ -2729,13 +6151,14 @@ fn resolve_filter_field_value_compiled(
                 Ok(py.None())
             }
         }
+        FieldValueResolver::CurrentItemExpr(expr) => eval_predicate_value(py, expr, item),
         FieldValueResolver::PredicateFilter(path_filter) => {
             Ok(eval_predicate_expr(py, path_filter, item)?.to_object(py))
         }
         FieldValueResolver::Key(field) => {
             let item_bound = item.bind(py);
-            if let Ok(item_dict) = item_bound.downcast::<PyDict>() {
-                if let Some(value) = item_dict.get_item(field)? {
+            if let Some(item_mapping) = as_read_mapping(&item_bound) {
+                if let Some(value) = read_mapping_item(item_mapping, field)? {
                     return Ok(value.into());
                 }
             }
@@ -2768,13 +6191,61 @@ fn filter_matches_compiled(
     py: Python<'_>,
     module: &Bound<'_, PyModule>,
     registry: &Bound<'_, PyAny>,
-    operator: &str,
     matcher: &CompiledFilterMatcher,
     item: &PyObject,
     root_data: Option<&PyObject>,
+) -> PyResult<bool> {
+    match matcher {
+        CompiledFilterMatcher::Clause(clause) => {
+            filter_clause_matches(py, module, registry, clause, item, root_data)
+        }
+        CompiledFilterMatcher::And(left, right) => {
+            if !filter_matches_compiled(py, module, registry, left, item, root_data)? {
+                return Ok(false);
+            }
+            filter_matches_compiled(py, module, registry, right, item, root_data)
+        }
+        CompiledFilterMatcher::Or(left, right) => {
+            if filter_matches_compiled(py, module, registry, left, item, root_data)? {
+                return Ok(true);
+            }
+            filter_matches_compiled(py, module, registry, right, item, root_data)
+        }
+    }
+}
+
+fn filter_clause_matches(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    matcher: &CompiledFilterClause,
+    item: &PyObject,
+    root_data: Option<&PyObject>,
 ) -> PyResult<bool> {
     let field_value = resolve_filter_field_value_compiled(py, module, registry, matcher, item)?;
 
+    // Only comparison failures are lenient; limit errors and anything that is not
+    // an ordinary `TypeError`/`ValueError` still propagate.
+    match compare_filter_clause(py, matcher, field_value, root_data) {
+        Err(err)
+            if LENIENT_COMPARE.with(Cell::get)
+                && (err.is_instance_of::<PyTypeError>(py)
+                    || err.is_instance_of::<PyValueError>(py)) =>
+        {
+            Ok(false)
+        }
+        result => result,
+    }
+}
+
+fn compare_filter_clause(
+    py: Python<'_>,
+    matcher: &CompiledFilterClause,
+    field_value: PyObject,
+    root_data: Option<&PyObject>,
+) -> PyResult<bool> {
+    let operator = matcher.operator.as_str();
+
     if let ValueMatcher::BuiltinPipeline(pipeline) = &matcher.value_matcher {
         if operator == "==" || operator == "!=" {
             let predicate_value = apply_builtin_pipeline(py, field_value, pipeline)?;
@@ -2850,154 +6321,777 @@ fn filter_matches_compiled(
     compare_values(py, &left_obj, &right_obj, operator)
 }
 
-fn resolve_filter_token(
+/// Items of `source_list` accepted by `matcher`, paired with their list index.
+fn matching_filter_items<'py>(
+    py: Python<'py>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    matcher: &CompiledFilterMatcher,
+    source_list: &Bound<'py, PyList>,
+    root_data: &PyObject,
+) -> PyResult<Vec<(usize, Bound<'py, PyAny>)>> {
+    let mut out = Vec::new();
+    for (idx, item) in source_list.iter().enumerate() {
+        let item_obj: PyObject = item.clone().into();
+        if filter_matches_compiled(py, module, registry, matcher, &item_obj, Some(root_data))? {
+            out.push((idx, item));
+        }
+    }
+    Ok(out)
+}
+
+fn filter_source_list<'py>(
+    py: Python<'py>,
+    current: &PyObject,
+    list_key: &str,
+) -> PyResult<Bound<'py, PyList>> {
+    let current_bound = current.bind(py);
+    let source = match as_read_mapping(&current_bound) {
+        Some(current_mapping) => match read_mapping_item(current_mapping, list_key)? {
+            Some(list_value) => list_value,
+            None => PyList::empty_bound(py).into_any(),
+        },
+        None => current_bound.clone(),
+    };
+
+    source.downcast_into::<PyList>().map_err(|err| {
+        PyTypeError::new_err(format!(
+            "Expected a list for key '{list_key}', got {}.",
+            get_type_name(&err.into_inner())
+        ))
+    })
+}
+
+/// Checks that a final wildcard or filter token resolves without materializing
+/// its matches, stopping a filter at its first matching item. Returns `None` for
+/// token kinds that have no cheaper check than `resolve_token`.
+fn probe_final_token(
     py: Python<'_>,
     module: &Bound<'_, PyModule>,
     registry: &Bound<'_, PyAny>,
     current: &PyObject,
     root_data: &PyObject,
-    list_key: &str,
-    field: &str,
-    operator: &str,
-    value: &str,
-) -> PyResult<PyObject> {
-    let matcher = compile_filter_matcher(py, module, registry, field, value)?;
-    let source_list_obj = {
-        let current_bound = current.bind(py);
-        if let Ok(current_dict) = current_bound.downcast::<PyDict>() {
-            match current_dict.get_item(list_key)? {
-                Some(list_value) => list_value.into(),
-                None => PyList::empty_bound(py).into(),
+    kind: &TokenKind,
+) -> Option<PyResult<bool>> {
+    let (source, matcher) = match kind {
+        TokenKind::Wildcard | TokenKind::DeepWildcard => {
+            let bound = current.bind(py);
+            if is_read_mapping(&bound) || bound.is_instance_of::<PyList>() {
+                return Some(bound.len().map(|len| len > 0));
+            }
+            let token = if matches!(kind, TokenKind::Wildcard) {
+                "*"
+            } else {
+                "**"
+            };
+            return Some(Err(PyTypeError::new_err(format!(
+                "Expected dict or list for wildcard '{token}', got {}.",
+                get_type_name(&bound)
+            ))));
+        }
+        TokenKind::Filter { list_key, filter } => (
+            filter_source_list(py, current, list_key),
+            compile_filter_matcher(py, module, registry, filter, Some(root_data)),
+        ),
+        TokenKind::RootFilter { filter } => (
+            current.bind(py).downcast::<PyList>().cloned().map_err(|_| {
+                PyTypeError::new_err(format!(
+                    "Expected a list for root filter, got {}.",
+                    get_type_name(&current.bind(py))
+                ))
+            }),
+            compile_filter_matcher(py, module, registry, filter, Some(root_data)),
+        ),
+        _ => return None,
+    };
+
+    let probe = || -> PyResult<bool> {
+        let matcher = matcher?;
+        for item in source?.iter() {
+            let item_obj: PyObject = item.into();
+            if filter_matches_compiled(py, module, registry, &matcher, &item_obj, Some(root_data))?
+            {
+                return Ok(true);
             }
-        } else {
-            current.clone_ref(py)
         }
+        Ok(false)
     };
+    Some(probe())
+}
 
-    let source_bound = source_list_obj.bind(py);
-    let source_list = source_bound.downcast::<PyList>().map_err(|_| {
+fn root_filter_source_list<'py>(
+    py: Python<'py>,
+    current: &PyObject,
+) -> PyResult<Bound<'py, PyList>> {
+    let source_bound = current.bind(py);
+    source_bound.downcast::<PyList>().cloned().map_err(|_| {
         PyTypeError::new_err(format!(
-            "Expected a list for key '{list_key}', got {}.",
+            "Expected a list for root filter, got {}.",
             get_type_name(&source_bound)
         ))
-    })?;
+    })
+}
 
-    let out = PyList::empty_bound(py);
-    for item in source_list.iter() {
-        let item_obj: PyObject = item.clone().into();
-        if filter_matches_compiled(
-            py,
-            module,
-            registry,
-            operator,
-            &matcher,
-            &item_obj,
-            Some(root_data),
-        )? {
-            out.append(item)?;
+/// `(position, value)` pairs for a final filter or `*` token, used by
+/// `get(..., returns=...)`: list indexes for filters and list wildcards, keys
+/// for mapping wildcards.
+fn resolve_token_positions(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    current: &PyObject,
+    root_data: &PyObject,
+    kind: &TokenKind,
+) -> PyResult<Vec<(PyObject, PyObject)>> {
+    let indexed = |matches: Vec<(usize, Bound<'_, PyAny>)>| {
+        matches
+            .into_iter()
+            .map(|(idx, item)| (idx.to_object(py), item.unbind()))
+            .collect()
+    };
+    match kind {
+        TokenKind::Filter { list_key, filter } => {
+            let matcher = compile_filter_matcher(py, module, registry, filter, Some(root_data))?;
+            let source_list = filter_source_list(py, current, list_key)?;
+            Ok(indexed(matching_filter_items(
+                py,
+                module,
+                registry,
+                &matcher,
+                &source_list,
+                root_data,
+            )?))
+        }
+        TokenKind::RootFilter { filter } => {
+            let matcher = compile_filter_matcher(py, module, registry, filter, Some(root_data))?;
+            let source_list = root_filter_source_list(py, current)?;
+            Ok(indexed(matching_filter_items(
+                py,
+                module,
+                registry,
+                &matcher,
+                &source_list,
+                root_data,
+            )?))
+        }
+        TokenKind::Wildcard => {
+            let bound = current.bind(py);
+            if let Ok(list) = bound.downcast::<PyList>() {
+                return Ok(indexed(list.iter().enumerate().collect()));
+            }
+            if let Some(mapping) = as_read_mapping(&bound) {
+                let mut out = Vec::new();
+                for entry in mapping.items()?.iter()? {
+                    let (key, value): (PyObject, PyObject) = entry?.extract()?;
+                    out.push((key, value));
+                }
+                return Ok(out);
+            }
+            Err(PyTypeError::new_err(format!(
+                "Expected dict or list for wildcard '*', got {}.",
+                get_type_name(&bound)
+            )))
         }
+        _ => Err(PyValueError::new_err(POSITIONAL_RETURNS_MESSAGE)),
     }
+}
 
-    Ok(out.into())
+fn is_resolution_error(py: Python<'_>, err: &PyErr) -> bool {
+    match py.import_bound("dictwalk.errors") {
+        Ok(errors_module) => match errors_module.getattr("DictWalkResolutionError") {
+            Ok(resolution_error) => err
+                .value_bound(py)
+                .is_instance(&resolution_error)
+                .unwrap_or(false),
+            Err(_) => false,
+        },
+        Err(_) => false,
+    }
+}
+
+fn is_soft_resolution_error(py: Python<'_>, err: &PyErr) -> bool {
+    if err.is_instance_of::<PyKeyError>(py) || err.is_instance_of::<PyTypeError>(py) {
+        return true;
+    }
+    if registered_soft_error_types(py)
+        .iter()
+        .any(|exc_type| err.is_instance_bound(py, exc_type.bind(py)))
+    {
+        return true;
+    }
+
+    match py.import_bound("dictwalk.errors") {
+        Ok(errors_module) => match errors_module.getattr("DictWalkOperatorError") {
+            Ok(operator_error) => err
+                .value_bound(py)
+                .is_instance(&operator_error)
+                .unwrap_or(false),
+            Err(_) => false,
+        },
+        Err(_) => false,
+    }
+}
+
+/// Whether a visited value is the single node a token reached or one element
+/// of the projection it selected. A list node projects its items to the next
+/// token, like the projection a wildcard or filter produces.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Reach {
+    Node,
+    Element,
+}
+
+/// Where a value sits in the document, in every spelling that can still
+/// address it: dictwalk syntax while each key on the way is a plain string,
+/// and a JSON Pointer while each key is a string. Both are `None` below a
+/// non-string key such as an `int`.
+#[derive(Clone)]
+struct ConcretePath {
+    dictwalk: Option<String>,
+    pointer: Option<String>,
+}
+
+impl ConcretePath {
+    fn root() -> Self {
+        ConcretePath {
+            dictwalk: Some(String::new()),
+            pointer: Some(String::new()),
+        }
+    }
+
+    fn key(&self, key: &str) -> Self {
+        ConcretePath {
+            dictwalk: self
+                .dictwalk
+                .as_deref()
+                .filter(|_| is_plain_path_key(key))
+                .map(|parent| concrete_key_path(parent, key)),
+            pointer: self
+                .pointer
+                .as_deref()
+                .map(|parent| format!("{parent}/{}", key.replace('~', "~0").replace('/', "~1"))),
+        }
+    }
+
+    fn key_object(&self, key: &Bound<'_, PyAny>) -> Self {
+        match key.downcast::<PyString>().map(|key| key.to_cow()) {
+            Ok(Ok(key)) => self.key(&key),
+            _ => ConcretePath {
+                dictwalk: None,
+                pointer: None,
+            },
+        }
+    }
+
+    fn index(&self, index: usize) -> Self {
+        ConcretePath {
+            dictwalk: self
+                .dictwalk
+                .as_deref()
+                .map(|parent| concrete_index_path(parent, index)),
+            pointer: self
+                .pointer
+                .as_deref()
+                .map(|parent| format!("{parent}/{index}")),
+        }
+    }
+
+    /// The dictwalk spelling, else the JSON Pointer one (read back with
+    /// `dialect="jsonpointer"`), else `None`.
+    fn render(&self) -> Option<String> {
+        self.dictwalk.clone().or_else(|| self.pointer.clone())
+    }
 }
 
-fn resolve_root_filter_token(
+/// Whether `key` reads back as a single `Get` token in dictwalk syntax.
+fn is_plain_path_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.trim() == key
+        && !key.chars().any(|c| PATH_PARAM_RESERVED_CHARS.contains(c))
+}
+
+/// List position addressed by an index that `get_item` already accepted.
+fn list_position(index: isize, len: usize) -> usize {
+    if index < 0 {
+        (index + len as isize) as usize
+    } else {
+        index as usize
+    }
+}
+
+/// Receives each value a token selects, with its location when the walk
+/// tracks one; returning `false` stops the walk.
+type MatchSink<'s> = dyn FnMut(PyObject, Reach, Option<ConcretePath>) -> PyResult<bool> + 's;
+
+/// The compiled predicate of a filter token, `None` for every other kind.
+fn compile_token_matcher(
     py: Python<'_>,
     module: &Bound<'_, PyModule>,
     registry: &Bound<'_, PyAny>,
-    current: &PyObject,
+    kind: &TokenKind,
     root_data: &PyObject,
-    field: &str,
-    operator: &str,
-    value: &str,
-) -> PyResult<PyObject> {
-    let matcher = compile_filter_matcher(py, module, registry, field, value)?;
-    let source_bound = current.bind(py);
-    let source_list = source_bound.downcast::<PyList>().map_err(|_| {
+) -> PyResult<Option<CompiledFilterMatcher>> {
+    match kind {
+        TokenKind::Filter { filter, .. } | TokenKind::RootFilter { filter } => {
+            compile_filter_matcher(py, module, registry, filter, Some(root_data)).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Calls `visit` with each child value of a dict, list, or read mapping,
+/// without collecting them first. Child locations are only built when `at`
+/// is tracked.
+fn visit_child_nodes(
+    node: &Bound<'_, PyAny>,
+    at: Option<&ConcretePath>,
+    visit: &mut dyn FnMut(PyObject, Option<ConcretePath>) -> PyResult<bool>,
+) -> PyResult<bool> {
+    if let Ok(dict) = node.downcast::<PyDict>() {
+        for (key, value) in dict.iter() {
+            if !visit(value.unbind(), at.map(|at| at.key_object(&key)))? {
+                return Ok(false);
+            }
+        }
+    } else if let Ok(list) = node.downcast::<PyList>() {
+        for (idx, item) in list.iter().enumerate() {
+            if !visit(item.unbind(), at.map(|at| at.index(idx)))? {
+                return Ok(false);
+            }
+        }
+    } else if is_read_mapping(node) {
+        if let Some(at) = at {
+            for entry in node.call_method0("items")?.iter()? {
+                let (key, value): (Bound<'_, PyAny>, Bound<'_, PyAny>) = entry?.extract()?;
+                if !visit(value.unbind(), Some(at.key_object(&key)))? {
+                    return Ok(false);
+                }
+            }
+        } else {
+            for value in node.call_method0("values")?.iter()? {
+                if !visit(value?.unbind(), None)? {
+                    return Ok(false);
+                }
+            }
+        }
+    }
+    Ok(true)
+}
+
+fn visit_descendants(
+    py: Python<'_>,
+    node: &PyObject,
+    at: Option<ConcretePath>,
+    sink: &mut MatchSink<'_>,
+    depth: usize,
+) -> PyResult<bool> {
+    check_recursion_depth_limit(py, depth)?;
+    visit_child_nodes(node.bind(py), at.as_ref(), &mut |child, child_at| {
+        Ok(sink(child.clone_ref(py), Reach::Element, child_at.clone())?
+            && visit_descendants(py, &child, child_at, sink, depth + 1)?)
+    })
+}
+
+/// The list stored under `key` of a mapping node, for index and slice tokens.
+fn keyed_token_list<'py>(current: &Bound<'py, PyAny>, key: &str) -> PyResult<Bound<'py, PyList>> {
+    let mapping = as_read_mapping(current).ok_or_else(|| {
         PyTypeError::new_err(format!(
-            "Expected a list for root filter, got {}.",
-            get_type_name(&source_bound)
+            "Expected a dict for key '{key}', got {}.",
+            get_type_name(current)
         ))
     })?;
+    let list_value = match read_mapping_item(mapping, key)? {
+        Some(value) => value,
+        None => return Err(PyKeyError::new_err(key.to_string())),
+    };
+    list_value.downcast_into::<PyList>().map_err(|err| {
+        PyTypeError::new_err(format!(
+            "Expected a list for key '{key}', got {}.",
+            get_type_name(&err.into_inner())
+        ))
+    })
+}
 
-    let out = PyList::empty_bound(py);
-    for item in source_list.iter() {
-        let item_obj: PyObject = item.clone().into();
-        if filter_matches_compiled(
-            py,
-            module,
-            registry,
-            operator,
-            &matcher,
-            &item_obj,
-            Some(root_data),
-        )? {
-            out.append(item)?;
+fn root_token_list<'py>(
+    current: &Bound<'py, PyAny>,
+    selector: &str,
+) -> PyResult<Bound<'py, PyList>> {
+    current.downcast::<PyList>().cloned().map_err(|_| {
+        PyTypeError::new_err(format!(
+            "Expected a list for {selector}, got {}.",
+            get_type_name(current)
+        ))
+    })
+}
+
+fn visit_filter_matches(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    root_data: &PyObject,
+    matcher: Option<&CompiledFilterMatcher>,
+    source: &Bound<'_, PyList>,
+    source_at: Option<ConcretePath>,
+    sink: &mut MatchSink<'_>,
+) -> PyResult<bool> {
+    let matcher = matcher.expect("filter tokens are compiled before they are visited");
+    for (idx, item) in source.iter().enumerate() {
+        let item: PyObject = item.unbind();
+        if filter_matches_compiled(py, module, registry, matcher, &item, Some(root_data))?
+            && !sink(
+                item,
+                Reach::Element,
+                source_at.as_ref().map(|at| at.index(idx)),
+            )?
+        {
+            return Ok(false);
         }
     }
+    Ok(true)
+}
 
-    Ok(out.into())
+/// Feeds each value `kind` selects from `current` to `sink`, one at a time.
+/// An element is treated as a one-element projection, so every token after a
+/// wildcard or filter applies to each selected value independently. With `at`
+/// set, each value is passed along with its location in the document.
+fn visit_token(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    root_data: &PyObject,
+    kind: &TokenKind,
+    matcher: Option<&CompiledFilterMatcher>,
+    current: &PyObject,
+    reach: Reach,
+    at: Option<&ConcretePath>,
+    sink: &mut MatchSink<'_>,
+) -> PyResult<bool> {
+    let bound = current.bind(py);
+    let addresses_node = matches!(
+        kind,
+        TokenKind::Index { .. }
+            | TokenKind::Slice { .. }
+            | TokenKind::Root
+            | TokenKind::RootMap
+            | TokenKind::RootIndex { .. }
+            | TokenKind::RootSlice { .. }
+            | TokenKind::RootFilter { .. }
+    );
+
+    if reach == Reach::Node && !addresses_node {
+        if let Ok(list) = bound.downcast::<PyList>() {
+            for (idx, item) in list.iter().enumerate() {
+                let item: PyObject = item.unbind();
+                if !visit_token(
+                    py,
+                    module,
+                    registry,
+                    root_data,
+                    kind,
+                    matcher,
+                    &item,
+                    Reach::Element,
+                    at.map(|at| at.index(idx)).as_ref(),
+                    sink,
+                )? {
+                    return Ok(false);
+                }
+            }
+            return Ok(true);
+        }
+    }
+
+    if reach == Reach::Element {
+        match kind {
+            TokenKind::Get(key) | TokenKind::Map(key) => {
+                let value = match as_read_mapping(&bound) {
+                    Some(mapping) => read_mapping_item(mapping, key)?,
+                    None => None,
+                };
+                return match value {
+                    Some(value) => sink(value.unbind(), Reach::Element, at.map(|at| at.key(key))),
+                    None => Ok(true),
+                };
+            }
+            TokenKind::Wildcard => return sink(current.clone_ref(py), Reach::Element, at.cloned()),
+            TokenKind::DeepWildcard => {
+                return Ok(sink(current.clone_ref(py), Reach::Element, at.cloned())?
+                    && visit_descendants(py, current, at.cloned(), sink, 1)?);
+            }
+            TokenKind::Filter { .. } => {
+                let matcher = matcher.expect("filter tokens are compiled before they are visited");
+                if filter_matches_compiled(py, module, registry, matcher, current, Some(root_data))?
+                {
+                    return sink(current.clone_ref(py), Reach::Element, at.cloned());
+                }
+                return Ok(true);
+            }
+            TokenKind::Index { key, .. } | TokenKind::Slice { key, .. } => {
+                return Err(PyTypeError::new_err(format!(
+                    "Expected a dict for key '{key}', got list."
+                )));
+            }
+            // Root selectors only start a path, so they always see the document node.
+            _ => {}
+        }
+    }
+
+    match kind {
+        TokenKind::Root => sink(
+            root_data.clone_ref(py),
+            Reach::Node,
+            at.map(|_| ConcretePath::root()),
+        ),
+        TokenKind::RootMap => {
+            for (idx, item) in root_token_list(&bound, "root map '[]'")?.iter().enumerate() {
+                if !sink(item.unbind(), Reach::Element, at.map(|at| at.index(idx)))? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        TokenKind::RootIndex { index } => {
+            let list = root_token_list(&bound, &format!("root index '[{index}]'"))?;
+            let value = list.as_any().get_item(index.to_object(py))?;
+            let position = list_position(*index, list.len());
+            sink(value.unbind(), Reach::Node, at.map(|at| at.index(position)))
+        }
+        TokenKind::RootSlice { start, end } => {
+            let list = root_token_list(&bound, "root slice")?;
+            for idx in compute_slice_indexes(list.len(), *start, *end) {
+                if !sink(
+                    list.get_item(idx)?.unbind(),
+                    Reach::Element,
+                    at.map(|at| at.index(idx)),
+                )? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        TokenKind::RootFilter { .. } => {
+            let source = root_token_list(&bound, "root filter")?;
+            visit_filter_matches(
+                py,
+                module,
+                registry,
+                root_data,
+                matcher,
+                &source,
+                at.cloned(),
+                sink,
+            )
+        }
+        TokenKind::Get(key) => match as_read_mapping(&bound) {
+            Some(mapping) => match read_mapping_item(mapping, key)? {
+                Some(value) => sink(value.unbind(), Reach::Node, at.map(|at| at.key(key))),
+                None => Err(PyKeyError::new_err(key.to_string())),
+            },
+            None => Err(PyTypeError::new_err(format!(
+                "Key '{key}' not found in current context."
+            ))),
+        },
+        TokenKind::Map(key) => Err(PyTypeError::new_err(format!(
+            "Expected a list for key '{key}', got {}.",
+            get_type_name(&bound)
+        ))),
+        TokenKind::Wildcard | TokenKind::DeepWildcard => {
+            if !is_read_mapping(&bound) {
+                let token = if matches!(kind, TokenKind::Wildcard) {
+                    "*"
+                } else {
+                    "**"
+                };
+                return Err(PyTypeError::new_err(format!(
+                    "Expected dict or list for wildcard '{token}', got {}.",
+                    get_type_name(&bound)
+                )));
+            }
+            let deep = matches!(kind, TokenKind::DeepWildcard);
+            visit_child_nodes(&bound, at, &mut |child, child_at| {
+                Ok(sink(child.clone_ref(py), Reach::Element, child_at.clone())?
+                    && (!deep || visit_descendants(py, &child, child_at, sink, 1)?))
+            })
+        }
+        TokenKind::Index { key, index } => {
+            let index = resolve_index_value(py, index, root_data)?;
+            let list = keyed_token_list(&bound, key)?;
+            let value = list.as_any().get_item(index.to_object(py))?;
+            let position = list_position(index, list.len());
+            sink(
+                value.unbind(),
+                Reach::Node,
+                at.map(|at| at.key(key).index(position)),
+            )
+        }
+        TokenKind::Slice { key, start, end } => {
+            let list = keyed_token_list(&bound, key)?;
+            let list_at = at.map(|at| at.key(key));
+            for idx in compute_slice_indexes(list.len(), *start, *end) {
+                if !sink(
+                    list.get_item(idx)?.unbind(),
+                    Reach::Element,
+                    list_at.as_ref().map(|at| at.index(idx)),
+                )? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        TokenKind::Filter { list_key, .. } => {
+            let source = filter_source_list(py, current, list_key)?;
+            visit_filter_matches(
+                py,
+                module,
+                registry,
+                root_data,
+                matcher,
+                &source,
+                at.map(|at| at.key(list_key)),
+                sink,
+            )
+        }
+    }
 }
 
-fn is_soft_resolution_error(py: Python<'_>, err: &PyErr) -> bool {
-    if err.is_instance_of::<PyKeyError>(py) || err.is_instance_of::<PyTypeError>(py) {
-        return true;
+/// Resolves one token against `current`: the node it reaches, or a list of
+/// the values it selects.
+fn resolve_token(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    current: &PyObject,
+    root_data: &PyObject,
+    kind: &TokenKind,
+) -> PyResult<PyObject> {
+    let matcher = compile_token_matcher(py, module, registry, kind, root_data)?;
+    let limits_size = matches!(kind, TokenKind::DeepWildcard);
+    let mut node: Option<PyObject> = None;
+    let out = PyList::empty_bound(py);
+    visit_token(
+        py,
+        module,
+        registry,
+        root_data,
+        kind,
+        matcher.as_ref(),
+        current,
+        Reach::Node,
+        None,
+        &mut |value, reach, _| {
+            match reach {
+                Reach::Node => node = Some(value),
+                Reach::Element => {
+                    out.append(value)?;
+                    if limits_size {
+                        check_result_size_limit(py, out.len())?;
+                    }
+                }
+            }
+            Ok(true)
+        },
+    )?;
+    Ok(node.unwrap_or_else(|| out.into_any().unbind()))
+}
+
+fn concrete_key_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{parent}.{key}")
+    }
+}
+
+fn concrete_index_path(parent: &str, index: usize) -> String {
+    if parent.is_empty() {
+        format!(".[{index}]")
+    } else {
+        format!("{parent}[{index}]")
+    }
+}
+
+/// Concrete path of the node a write is visiting. Paths are only built when
+/// `set(report=True)` supplied a sink for the modified leaves.
+struct WriteTrace<'a> {
+    path: Option<ConcretePath>,
+    modified: Option<&'a RefCell<Vec<Option<String>>>>,
+}
+
+impl<'a> WriteTrace<'a> {
+    fn root(modified: Option<&'a RefCell<Vec<Option<String>>>>) -> Self {
+        WriteTrace {
+            path: modified.map(|_| ConcretePath::root()),
+            modified,
+        }
+    }
+
+    fn key(&self, key: &str) -> WriteTrace<'a> {
+        WriteTrace {
+            path: self.path.as_ref().map(|path| path.key(key)),
+            modified: self.modified,
+        }
+    }
+
+    fn key_object(&self, key: &Bound<'_, PyAny>) -> WriteTrace<'a> {
+        WriteTrace {
+            path: self.path.as_ref().map(|path| path.key_object(key)),
+            modified: self.modified,
+        }
     }
 
-    match py.import_bound("dictwalk.errors") {
-        Ok(errors_module) => match errors_module.getattr("DictWalkOperatorError") {
-            Ok(operator_error) => err
-                .value_bound(py)
-                .is_instance(&operator_error)
-                .unwrap_or(false),
-            Err(_) => false,
-        },
-        Err(_) => false,
+    fn index(&self, index: usize) -> WriteTrace<'a> {
+        WriteTrace {
+            path: self.path.as_ref().map(|path| path.index(index)),
+            modified: self.modified,
+        }
     }
-}
 
-fn resolve_token(
-    py: Python<'_>,
-    module: &Bound<'_, PyModule>,
-    registry: &Bound<'_, PyAny>,
-    current: &PyObject,
-    root_data: &PyObject,
-    kind: &TokenKind,
-) -> PyResult<PyObject> {
-    match kind {
-        TokenKind::RootMap => resolve_root_map_token(py, current),
-        TokenKind::RootIndex { index } => resolve_root_index_token(py, current, *index),
-        TokenKind::RootSlice { start, end } => resolve_root_slice_token(py, current, *start, *end),
-        TokenKind::RootFilter {
-            field,
-            operator,
-            value,
-        } => resolve_root_filter_token(
-            py, module, registry, current, root_data, field, operator, value,
-        ),
-        TokenKind::Get(key) => resolve_get_token(py, current, key),
-        TokenKind::Map(key) => resolve_map_token(py, current, key),
-        TokenKind::Wildcard => resolve_wildcard_token(py, current),
-        TokenKind::DeepWildcard => resolve_deep_wildcard_token(py, current),
-        TokenKind::Index { key, index } => resolve_index_token(py, current, key, *index),
-        TokenKind::Slice { key, start, end } => resolve_slice_token(py, current, key, *start, *end),
-        TokenKind::Filter {
-            list_key,
-            field,
-            operator,
-            value,
-        } => resolve_filter_token(
-            py, module, registry, current, root_data, list_key, field, operator, value,
-        ),
-        TokenKind::Root => Ok(current.clone_ref(py)),
+    fn record(&self) {
+        if let (Some(modified), Some(path)) = (self.modified, &self.path) {
+            modified.borrow_mut().push(path.render());
+        }
     }
 }
 
 #[derive(Clone, Copy)]
-struct WriteOptions {
+struct WriteOptions<'a> {
     create_missing: bool,
     create_filter_match: bool,
-    overwrite_incompatible: bool,
+    overwrite_scalar: bool,
+    overwrite_container: bool,
+    skip_unchanged: bool,
+    condition: Option<&'a PredicateExpr>,
+}
+
+impl WriteOptions<'_> {
+    /// A leaf is left alone (and not reported as modified) when the `set_if`
+    /// condition is falsy for its current value (`None` if missing), or, with
+    /// `skip_unchanged`, when its new value compares equal to the existing one.
+    fn skips_write(
+        &self,
+        py: Python<'_>,
+        existing: Option<&PyObject>,
+        resolved: &PyObject,
+    ) -> PyResult<bool> {
+        if let Some(condition) = self.condition {
+            let current = existing.map_or_else(|| py.None(), |value| value.clone_ref(py));
+            if !eval_predicate_expr(py, condition, &current)? {
+                return Ok(true);
+            }
+        }
+        match existing {
+            Some(existing) if self.skip_unchanged => compare_values(py, existing, resolved, "=="),
+            _ => Ok(false),
+        }
+    }
+
+    fn can_overwrite(&self, existing: &Bound<'_, PyAny>) -> bool {
+        if is_dict_or_list(existing) {
+            self.overwrite_container
+        } else {
+            self.overwrite_scalar
+        }
+    }
 }
 
 fn token_uses_root_selector(kind: &TokenKind) -> bool {
@@ -3053,6 +7147,254 @@ fn validate_write_path_root_selector(
     Ok(())
 }
 
+fn parse_write_path(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    path: &str,
+) -> PyResult<Vec<ParsedToken>> {
+    let tokens = parse_path(py, module, registry, path)?;
+
+    validate_write_path_root_selector(py, path, &tokens)?;
+
+    if path_uses_bare_root_token(&tokens) {
+        return Err(make_parse_error(
+            py,
+            path,
+            Some("$$root"),
+            "The '$$root' token is only supported in read paths.",
+        ));
+    }
+
+    Ok(tokens)
+}
+
+fn parse_unset_path(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    path: &str,
+) -> PyResult<Vec<ParsedToken>> {
+    let tokens = parse_write_path(py, module, registry, path)?;
+
+    if let Some(token) = tokens.iter().find(|token| {
+        matches!(
+            token.kind,
+            TokenKind::Index {
+                index: IndexValue::RootReference(_),
+                ..
+            }
+        )
+    }) {
+        return Err(make_parse_error(
+            py,
+            path,
+            Some(&token.raw),
+            "'$$root' index references are only supported in read and set paths.",
+        ));
+    }
+
+    Ok(tokens)
+}
+
+/// Characters that would change how an unquoted substituted value tokenizes.
+const PATH_PARAM_RESERVED_CHARS: &str = ".[]|'\"\\{}()*$?!&=<>,";
+
+/// Expands `{name}` placeholders in a path template from `params` before the
+/// path is parsed; `{{` and `}}` are literal braces. Values inside a quoted
+/// predicate literal are escaped and JSON Pointer values get `~0`/`~1`
+/// escapes. Bare dictwalk keys have no escape form, so a value containing
+/// path syntax there is rejected instead of being spliced in.
+fn expand_path_params(
+    py: Python<'_>,
+    template: &str,
+    params: Option<&Bound<'_, PyDict>>,
+    json_pointer: bool,
+) -> PyResult<String> {
+    let Some(params) = params else {
+        return Ok(template.to_string());
+    };
+
+    let chars: Vec<char> = template.chars().collect();
+    let mut out = String::with_capacity(template.len());
+    let mut bracket_depth = 0i32;
+    let mut quotes = QuoteState::default();
+    let mut i = 0usize;
+    while i < chars.len() {
+        let ch = chars[i];
+        if (ch == '{' || ch == '}') && chars.get(i + 1) == Some(&ch) {
+            out.push(ch);
+            i += 2;
+            continue;
+        }
+        if ch == '}' {
+            return Err(make_parse_error(
+                py,
+                template,
+                None,
+                "Unmatched '}' in path template. Use '}}' for a literal brace.",
+            ));
+        }
+        if ch == '{' {
+            let Some(close) = chars[i + 1..].iter().position(|c| *c == '}') else {
+                return Err(make_parse_error(
+                    py,
+                    template,
+                    None,
+                    "Unclosed '{' in path template. Use '{{' for a literal brace.",
+                ));
+            };
+            let name: String = chars[i + 1..i + 1 + close].iter().collect();
+            let placeholder = format!("{{{name}}}");
+            if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                return Err(make_parse_error(
+                    py,
+                    template,
+                    Some(&placeholder),
+                    "Path parameter names must be non-empty and contain only letters, digits, and '_'.",
+                ));
+            }
+            let Some(value) = params.get_item(&name)? else {
+                return Err(make_parse_error(
+                    py,
+                    template,
+                    Some(&placeholder),
+                    &format!("Missing path parameter '{name}'."),
+                ));
+            };
+            let text = value.str()?.to_string();
+            if quotes.in_quotes() {
+                for c in text.chars() {
+                    match c {
+                        '\\' | '\'' | '"' => {
+                            out.push('\\');
+                            out.push(c);
+                        }
+                        '\n' => out.push_str("\\n"),
+                        '\r' => out.push_str("\\r"),
+                        '\t' => out.push_str("\\t"),
+                        _ => out.push(c),
+                    }
+                }
+            } else if json_pointer {
+                out.push_str(&text.replace('~', "~0").replace('/', "~1"));
+            } else {
+                if text.is_empty() || text.chars().any(|c| PATH_PARAM_RESERVED_CHARS.contains(c)) {
+                    return Err(make_parse_error(
+                        py,
+                        template,
+                        Some(&placeholder),
+                        &format!(
+                            "Path parameter '{name}' value {} is empty or contains path syntax; it can only be substituted inside a quoted predicate literal or with dialect=\"jsonpointer\".",
+                            value.repr()?
+                        ),
+                    ));
+                }
+                out.push_str(&text);
+            }
+            i += close + 2;
+            continue;
+        }
+
+        if !(bracket_depth > 0 && quotes.consume(ch)) {
+            if ch == '[' {
+                bracket_depth += 1;
+            } else if ch == ']' {
+                bracket_depth = (bracket_depth - 1).max(0);
+            }
+        }
+        out.push(ch);
+        i += 1;
+    }
+    Ok(out)
+}
+
+/// Selects the path parser from a `dialect` argument; `true` means JSON Pointer.
+fn is_json_pointer_dialect(dialect: &str) -> PyResult<bool> {
+    match dialect {
+        "dictwalk" => Ok(false),
+        "jsonpointer" => Ok(true),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported path dialect '{other}'. Expected one of: dictwalk, jsonpointer."
+        ))),
+    }
+}
+
+/// Tokenizes an RFC 6901 pointer into the same tokens `parse_path` produces,
+/// following it through `data` as it goes. A segment shaped like an array
+/// index becomes `Index` (after a key) or `RootIndex` (at the start or after
+/// another index) unless the node it applies to is a mapping, where it is a
+/// member name like every other segment (a plain `Get`). Past the end of the
+/// existing document, index-shaped segments select list positions.
+fn parse_json_pointer_path(
+    py: Python<'_>,
+    pointer: &str,
+    data: &PyObject,
+) -> PyResult<Vec<ParsedToken>> {
+    let segments = split_json_pointer(pointer)
+        .map_err(|message| make_parse_error(py, pointer, None, &message))?;
+    check_path_token_limit(py, segments.len())?;
+
+    let mut current = Some(data.bind(py).clone());
+    let mut tokens: Vec<ParsedToken> = Vec::with_capacity(segments.len());
+    for segment in segments {
+        let raw = format!("/{}", segment.replace('~', "~0").replace('/', "~1"));
+        let names_member = current
+            .as_ref()
+            .is_some_and(|node| as_read_mapping(node).is_some());
+        current = match current {
+            Some(node) => json_pointer_step(&node, &segment)?,
+            None => None,
+        };
+        let index = json_pointer_index(&segment).and_then(|i| isize::try_from(i).ok());
+        let Some(index) = index.filter(|_| !names_member) else {
+            tokens.push(ParsedToken {
+                raw,
+                kind: TokenKind::Get(segment),
+            });
+            continue;
+        };
+        match tokens.pop() {
+            Some(ParsedToken {
+                raw: key_raw,
+                kind: TokenKind::Get(key),
+            }) => tokens.push(ParsedToken {
+                raw: format!("{key_raw}{raw}"),
+                kind: TokenKind::Index {
+                    key,
+                    index: IndexValue::Literal(index),
+                },
+            }),
+            previous => {
+                tokens.extend(previous);
+                tokens.push(ParsedToken {
+                    raw,
+                    kind: TokenKind::RootIndex { index },
+                });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Write paths must name a location inside the document, so the empty
+/// pointer (the whole document) is rejected.
+fn parse_json_pointer_write_path(
+    py: Python<'_>,
+    pointer: &str,
+    data: &PyObject,
+) -> PyResult<Vec<ParsedToken>> {
+    if pointer.is_empty() {
+        return Err(make_parse_error(
+            py,
+            pointer,
+            None,
+            "The empty JSON Pointer refers to the whole document and cannot be written.",
+        ));
+    }
+    parse_json_pointer_path(py, pointer, data)
+}
+
 fn ensure_path_resolves(
     py: Python<'_>,
     module: &Bound<'_, PyModule>,
@@ -3133,7 +7475,7 @@ fn resolve_new_value(
         }
 
         if !filter_value.starts_with("$$root") {
-            if let Some(pipeline) = compile_builtin_pipeline(py, &filter_value, None) {
+            if let Some(pipeline) = compile_builtin_pipeline(py, &filter_value, Some(root_data)) {
                 let existing = existing_value.unwrap_or_else(|| py.None());
                 return apply_builtin_pipeline(py, existing, &pipeline);
             }
@@ -3143,6 +7485,30 @@ fn resolve_new_value(
     Ok(new_value.clone_ref(py))
 }
 
+/// Resolves the new value for one leaf; `None` when `write_options` skip it.
+fn write_leaf(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    existing: Option<PyObject>,
+    new_value: &PyObject,
+    write_options: WriteOptions<'_>,
+    root_data: &PyObject,
+) -> PyResult<Option<PyObject>> {
+    let resolved = resolve_new_value(
+        py,
+        module,
+        registry,
+        existing.as_ref().map(|value| value.clone_ref(py)),
+        new_value,
+        root_data,
+    )?;
+    if write_options.skips_write(py, existing.as_ref(), &resolved)? {
+        return Ok(None);
+    }
+    Ok(Some(resolved))
+}
+
 fn dict_keys(dict: &Bound<'_, PyDict>) -> Vec<PyObject> {
     let mut keys: Vec<PyObject> = Vec::new();
     for (key, _) in dict.iter() {
@@ -3154,12 +7520,12 @@ fn dict_keys(dict: &Bound<'_, PyDict>) -> Vec<PyObject> {
 fn coerce_current_to_dict_for_write(
     py: Python<'_>,
     current: PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
 ) -> PyObject {
     if current.bind(py).is_instance_of::<PyDict>() {
         return current;
     }
-    if !write_options.overwrite_incompatible || !write_options.create_missing {
+    if !write_options.can_overwrite(current.bind(py)) || !write_options.create_missing {
         return current;
     }
     PyDict::new_bound(py).into()
@@ -3193,8 +7559,9 @@ fn set_recurse(
     current: PyObject,
     remaining: &[ParsedToken],
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     if remaining.is_empty() {
         return Ok(new_value.clone_ref(py));
@@ -3210,6 +7577,7 @@ fn set_recurse(
             new_value,
             write_options,
             root_data,
+            trace,
         ),
         TokenKind::RootIndex { index } => set_root_index_token(
             py,
@@ -3221,6 +7589,7 @@ fn set_recurse(
             new_value,
             write_options,
             root_data,
+            trace,
         ),
         TokenKind::RootSlice { start, end } => set_root_slice_token(
             py,
@@ -3233,23 +7602,19 @@ fn set_recurse(
             new_value,
             write_options,
             root_data,
+            trace,
         ),
-        TokenKind::RootFilter {
-            field,
-            operator,
-            value,
-        } => set_root_filter_token(
+        TokenKind::RootFilter { filter } => set_root_filter_token(
             py,
             module,
             registry,
             current,
             remaining,
-            field,
-            operator,
-            value,
+            filter,
             new_value,
             write_options,
             root_data,
+            trace,
         ),
         TokenKind::Get(key) => set_get_token(
             py,
@@ -3261,6 +7626,7 @@ fn set_recurse(
             new_value,
             write_options,
             root_data,
+            trace,
         ),
         TokenKind::Map(key) => set_map_token(
             py,
@@ -3272,6 +7638,7 @@ fn set_recurse(
             new_value,
             write_options,
             root_data,
+            trace,
         ),
         TokenKind::Wildcard => set_wildcard_token(
             py,
@@ -3282,6 +7649,7 @@ fn set_recurse(
             new_value,
             write_options,
             root_data,
+            trace,
         ),
         TokenKind::DeepWildcard => set_deep_wildcard_token(
             py,
@@ -3292,6 +7660,7 @@ fn set_recurse(
             new_value,
             write_options,
             root_data,
+            trace,
         ),
         TokenKind::Index { key, index } => set_index_token(
             py,
@@ -3300,10 +7669,11 @@ fn set_recurse(
             current,
             remaining,
             key,
-            *index,
+            resolve_index_value(py, index, root_data)?,
             new_value,
             write_options,
             root_data,
+            trace,
         ),
         TokenKind::Slice { key, start, end } => set_slice_token(
             py,
@@ -3317,25 +7687,20 @@ fn set_recurse(
             new_value,
             write_options,
             root_data,
+            trace,
         ),
-        TokenKind::Filter {
-            list_key,
-            field,
-            operator,
-            value,
-        } => set_filter_token(
+        TokenKind::Filter { list_key, filter } => set_filter_token(
             py,
             module,
             registry,
             current,
             remaining,
             list_key,
-            field,
-            operator,
-            value,
+            filter,
             new_value,
             write_options,
             root_data,
+            trace,
         ),
         TokenKind::Root => Ok(current),
     }
@@ -3349,8 +7714,9 @@ fn set_get_token(
     remaining: &[ParsedToken],
     key: &str,
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     let next_kind = remaining.get(1).map(|token| &token.kind);
     let current = coerce_current_to_dict_for_write(py, current, write_options);
@@ -3360,12 +7726,24 @@ fn set_get_token(
 
     let dict = current.bind(py).downcast::<PyDict>()?;
     if remaining.len() == 1 {
-        let existing = dict.get_item(key)?.map(|value| value.into());
+        let existing: Option<PyObject> = dict.get_item(key)?.map(|value| value.into());
         if existing.is_none() && !write_options.create_missing {
             return Ok(current);
         }
-        let resolved = resolve_new_value(py, module, registry, existing, new_value, root_data)?;
+        let Some(resolved) = write_leaf(
+            py,
+            module,
+            registry,
+            existing,
+            new_value,
+            write_options,
+            root_data,
+        )?
+        else {
+            return Ok(current);
+        };
         dict.set_item(key, resolved)?;
+        trace.key(key).record();
         return Ok(current);
     }
 
@@ -3382,7 +7760,7 @@ fn set_get_token(
     };
 
     if had_child && next_kind.is_some() && !is_dict_or_list(&child.bind(py)) {
-        if !write_options.overwrite_incompatible {
+        if !write_options.overwrite_scalar {
             return Ok(current);
         }
         child = new_write_container(py);
@@ -3397,6 +7775,7 @@ fn set_get_token(
         new_value,
         write_options,
         root_data,
+        &trace.key(key),
     )?;
     dict.set_item(key, updated)?;
     Ok(current)
@@ -3410,8 +7789,9 @@ fn set_map_token(
     remaining: &[ParsedToken],
     key: &str,
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     let next_kind = remaining.get(1).map(|token| &token.kind);
     let current = coerce_current_to_dict_for_write(py, current, write_options);
@@ -3421,15 +7801,12 @@ fn set_map_token(
 
     let dict = current.bind(py).downcast::<PyDict>()?;
     let list_obj: PyObject = match dict.get_item(key)? {
-        Some(value) => {
-            if value.is_instance_of::<PyList>() {
-                value.into()
-            } else {
-                if !write_options.overwrite_incompatible {
-                    return Ok(current);
-                }
-                PyList::empty_bound(py).into()
+        Some(value) if value.is_instance_of::<PyList>() => value.into(),
+        Some(existing) => {
+            if !write_options.can_overwrite(&existing) {
+                return Ok(current);
             }
+            PyList::empty_bound(py).into()
         }
         None => {
             if !write_options.create_missing {
@@ -3443,9 +7820,20 @@ fn set_map_token(
     if remaining.len() == 1 {
         for idx in 0..list.len() {
             let existing: PyObject = list.get_item(idx)?.into();
-            let resolved =
-                resolve_new_value(py, module, registry, Some(existing), new_value, root_data)?;
+            let Some(resolved) = write_leaf(
+                py,
+                module,
+                registry,
+                Some(existing),
+                new_value,
+                write_options,
+                root_data,
+            )?
+            else {
+                continue;
+            };
             list.set_item(idx, resolved)?;
+            trace.key(key).index(idx).record();
         }
         dict.set_item(key, list_obj)?;
         return Ok(current);
@@ -3461,7 +7849,7 @@ fn set_map_token(
     for idx in 0..list.len() {
         let mut item: PyObject = list.get_item(idx)?.into();
         if next_kind.is_some() && !is_dict_or_list(&item.bind(py)) {
-            if !write_options.overwrite_incompatible {
+            if !write_options.overwrite_scalar {
                 continue;
             }
             item = new_write_container(py);
@@ -3476,6 +7864,7 @@ fn set_map_token(
             new_value,
             write_options,
             root_data,
+            &trace.key(key).index(idx),
         )?;
         list.set_item(idx, updated)?;
     }
@@ -3491,8 +7880,9 @@ fn set_root_map_token(
     current: PyObject,
     remaining: &[ParsedToken],
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     let next_kind = remaining.get(1).map(|token| &token.kind);
     if !current.bind(py).is_instance_of::<PyList>() {
@@ -3503,9 +7893,20 @@ fn set_root_map_token(
     if remaining.len() == 1 {
         for idx in 0..list.len() {
             let existing: PyObject = list.get_item(idx)?.into();
-            let resolved =
-                resolve_new_value(py, module, registry, Some(existing), new_value, root_data)?;
+            let Some(resolved) = write_leaf(
+                py,
+                module,
+                registry,
+                Some(existing),
+                new_value,
+                write_options,
+                root_data,
+            )?
+            else {
+                continue;
+            };
             list.set_item(idx, resolved)?;
+            trace.index(idx).record();
         }
         return Ok(current);
     }
@@ -3513,7 +7914,7 @@ fn set_root_map_token(
     for idx in 0..list.len() {
         let mut item: PyObject = list.get_item(idx)?.into();
         if next_kind.is_some() && !is_dict_or_list(&item.bind(py)) {
-            if !write_options.overwrite_incompatible {
+            if !write_options.overwrite_scalar {
                 continue;
             }
             item = new_write_container(py);
@@ -3528,6 +7929,7 @@ fn set_root_map_token(
             new_value,
             write_options,
             root_data,
+            &trace.index(idx),
         )?;
         list.set_item(idx, updated)?;
     }
@@ -3543,8 +7945,9 @@ fn set_root_index_token(
     remaining: &[ParsedToken],
     index: isize,
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     let next_kind = remaining.get(1).map(|token| &token.kind);
     if !current.bind(py).is_instance_of::<PyList>() {
@@ -3578,16 +7981,27 @@ fn set_root_index_token(
     };
 
     if remaining.len() == 1 {
-        let existing = list.get_item(target_index)?.into();
-        let resolved =
-            resolve_new_value(py, module, registry, Some(existing), new_value, root_data)?;
+        let existing: PyObject = list.get_item(target_index)?.into();
+        let Some(resolved) = write_leaf(
+            py,
+            module,
+            registry,
+            Some(existing),
+            new_value,
+            write_options,
+            root_data,
+        )?
+        else {
+            return Ok(current);
+        };
         list.set_item(target_index, resolved)?;
+        trace.index(target_index).record();
         return Ok(current);
     }
 
     let mut item: PyObject = list.get_item(target_index)?.into();
     if next_kind.is_some() && !is_dict_or_list(&item.bind(py)) {
-        if !write_options.overwrite_incompatible {
+        if !write_options.overwrite_scalar {
             return Ok(current);
         }
         item = new_write_container(py);
@@ -3602,6 +8016,7 @@ fn set_root_index_token(
         new_value,
         write_options,
         root_data,
+        &trace.index(target_index),
     )?;
     list.set_item(target_index, updated)?;
     Ok(current)
@@ -3616,8 +8031,9 @@ fn set_root_slice_token(
     start: Option<isize>,
     end: Option<isize>,
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     let next_kind = remaining.get(1).map(|token| &token.kind);
     if !current.bind(py).is_instance_of::<PyList>() {
@@ -3628,10 +8044,21 @@ fn set_root_slice_token(
 
     if remaining.len() == 1 {
         for idx in indexes {
-            let existing = list.get_item(idx)?.into();
-            let resolved =
-                resolve_new_value(py, module, registry, Some(existing), new_value, root_data)?;
+            let existing: PyObject = list.get_item(idx)?.into();
+            let Some(resolved) = write_leaf(
+                py,
+                module,
+                registry,
+                Some(existing),
+                new_value,
+                write_options,
+                root_data,
+            )?
+            else {
+                continue;
+            };
             list.set_item(idx, resolved)?;
+            trace.index(idx).record();
         }
         return Ok(current);
     }
@@ -3639,7 +8066,7 @@ fn set_root_slice_token(
     for idx in indexes {
         let mut item: PyObject = list.get_item(idx)?.into();
         if next_kind.is_some() && !is_dict_or_list(&item.bind(py)) {
-            if !write_options.overwrite_incompatible {
+            if !write_options.overwrite_scalar {
                 continue;
             }
             item = new_write_container(py);
@@ -3653,6 +8080,7 @@ fn set_root_slice_token(
             new_value,
             write_options,
             root_data,
+            &trace.index(idx),
         )?;
         list.set_item(idx, updated)?;
     }
@@ -3666,18 +8094,17 @@ fn set_root_filter_token(
     registry: &Bound<'_, PyAny>,
     current: PyObject,
     remaining: &[ParsedToken],
-    field: &str,
-    operator: &str,
-    value: &str,
+    filter: &FilterExpr,
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     if !current.bind(py).is_instance_of::<PyList>() {
         return Ok(current);
     }
     let list = current.bind(py).downcast::<PyList>()?;
-    let matcher = compile_filter_matcher(py, module, registry, field, value)?;
+    let matcher = compile_filter_matcher(py, module, registry, filter, Some(root_data))?;
 
     let mut matches: Vec<bool> = Vec::with_capacity(list.len());
     for idx in 0..list.len() {
@@ -3686,44 +8113,48 @@ fn set_root_filter_token(
             py,
             module,
             registry,
-            operator,
             &matcher,
             &item,
             Some(root_data),
         )?);
     }
 
-    if !matches.iter().any(|matched| *matched) {
+    // Only a single `field == literal` clause describes an item that can be created.
+    if let (false, CompiledFilterMatcher::Clause(clause)) =
+        (matches.iter().any(|matched| *matched), &matcher)
+    {
         let field_uses_item_root = matches!(
-            matcher.field_resolver,
+            clause.field_resolver,
             FieldValueResolver::CurrentItem
                 | FieldValueResolver::CurrentItemBuiltinPipeline(_)
                 | FieldValueResolver::CurrentItemTransform(_)
+                | FieldValueResolver::CurrentItemExpr(_)
         );
         let field_path_filter_present = matches!(
-            matcher.field_resolver,
+            clause.field_resolver,
             FieldValueResolver::CurrentItemBuiltinPipeline(_)
                 | FieldValueResolver::CurrentItemTransform(_)
+                | FieldValueResolver::CurrentItemExpr(_)
                 | FieldValueResolver::PredicateFilter(_)
         );
         let value_path_filter_present = matches!(
-            matcher.value_matcher,
+            clause.value_matcher,
             ValueMatcher::BuiltinPipeline(_) | ValueMatcher::PredicateExpr(_)
         );
 
         if !field_uses_item_root
             && !field_path_filter_present
             && !value_path_filter_present
-            && operator == "=="
+            && clause.operator == "=="
             && write_options.create_missing
             && write_options.create_filter_match
         {
             let new_item = PyDict::new_bound(py);
-            let field_key = match &matcher.field_resolver {
+            let field_key = match &clause.field_resolver {
                 FieldValueResolver::Key(key) => key.as_str(),
-                _ => field,
+                _ => clause.raw_field.as_str(),
             };
-            new_item.set_item(field_key, value)?;
+            new_item.set_item(field_key, clause.raw_value.as_str())?;
             list.append(new_item.clone())?;
             matches.push(true);
         }
@@ -3734,10 +8165,21 @@ fn set_root_filter_token(
             if !matches.get(idx).copied().unwrap_or(false) {
                 continue;
             }
-            let existing = list.get_item(idx)?.into();
-            let resolved =
-                resolve_new_value(py, module, registry, Some(existing), new_value, root_data)?;
+            let existing: PyObject = list.get_item(idx)?.into();
+            let Some(resolved) = write_leaf(
+                py,
+                module,
+                registry,
+                Some(existing),
+                new_value,
+                write_options,
+                root_data,
+            )?
+            else {
+                continue;
+            };
             list.set_item(idx, resolved)?;
+            trace.index(idx).record();
         }
         return Ok(current);
     }
@@ -3756,6 +8198,7 @@ fn set_root_filter_token(
             new_value,
             write_options,
             root_data,
+            &trace.index(idx),
         )?;
         list.set_item(idx, updated)?;
     }
@@ -3770,27 +8213,35 @@ fn set_wildcard_token(
     current: PyObject,
     remaining: &[ParsedToken],
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     if current.bind(py).is_instance_of::<PyDict>() {
         let dict = current.bind(py).downcast::<PyDict>()?;
         let keys = dict_keys(dict);
 
         for key in keys {
+            let child_trace = trace.key_object(key.bind(py));
             let current_child = dict
                 .get_item(key.bind(py))?
                 .map(|value| value.into())
                 .unwrap_or_else(|| py.None());
             let updated = if remaining.len() == 1 {
-                resolve_new_value(
+                let Some(resolved) = write_leaf(
                     py,
                     module,
                     registry,
                     Some(current_child),
                     new_value,
+                    write_options,
                     root_data,
                 )?
+                else {
+                    continue;
+                };
+                child_trace.record();
+                resolved
             } else {
                 set_recurse(
                     py,
@@ -3801,6 +8252,7 @@ fn set_wildcard_token(
                     new_value,
                     write_options,
                     root_data,
+                    &child_trace,
                 )?
             };
             dict.set_item(key.bind(py), updated)?;
@@ -3812,15 +8264,22 @@ fn set_wildcard_token(
         let list = current.bind(py).downcast::<PyList>()?;
         for idx in 0..list.len() {
             let current_child: PyObject = list.get_item(idx)?.into();
+            let child_trace = trace.index(idx);
             let updated = if remaining.len() == 1 {
-                resolve_new_value(
+                let Some(resolved) = write_leaf(
                     py,
                     module,
                     registry,
                     Some(current_child),
                     new_value,
+                    write_options,
                     root_data,
                 )?
+                else {
+                    continue;
+                };
+                child_trace.record();
+                resolved
             } else {
                 set_recurse(
                     py,
@@ -3831,6 +8290,7 @@ fn set_wildcard_token(
                     new_value,
                     write_options,
                     root_data,
+                    &child_trace,
                 )?
             };
             list.set_item(idx, updated)?;
@@ -3847,13 +8307,17 @@ fn deep_set_walk(
     node: PyObject,
     remaining: &[ParsedToken],
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
+    depth: usize,
 ) -> PyResult<()> {
+    check_recursion_depth_limit(py, depth)?;
     if node.bind(py).is_instance_of::<PyDict>() {
         let dict = node.bind(py).downcast::<PyDict>()?;
         let keys = dict_keys(dict);
         for key in keys {
+            let child_trace = trace.key_object(key.bind(py));
             let child = match dict.get_item(key.bind(py))? {
                 Some(value) => value.into(),
                 None => continue,
@@ -3869,6 +8333,7 @@ fn deep_set_walk(
                     new_value,
                     write_options,
                     root_data,
+                    &child_trace,
                 )?;
                 dict.set_item(key.bind(py), updated)?;
             }
@@ -3884,6 +8349,8 @@ fn deep_set_walk(
                         new_value,
                         write_options,
                         root_data,
+                        &child_trace,
+                        depth + 1,
                     )?;
                 }
             }
@@ -3895,6 +8362,7 @@ fn deep_set_walk(
         let list = node.bind(py).downcast::<PyList>()?;
         for idx in 0..list.len() {
             let child: PyObject = list.get_item(idx)?.into();
+            let child_trace = trace.index(idx);
             if remaining.len() > 1 {
                 let updated = set_recurse(
                     py,
@@ -3905,6 +8373,7 @@ fn deep_set_walk(
                     new_value,
                     write_options,
                     root_data,
+                    &child_trace,
                 )?;
                 list.set_item(idx, updated)?;
             }
@@ -3920,6 +8389,8 @@ fn deep_set_walk(
                     new_value,
                     write_options,
                     root_data,
+                    &child_trace,
+                    depth + 1,
                 )?;
             }
         }
@@ -3928,6 +8399,61 @@ fn deep_set_walk(
     Ok(())
 }
 
+/// Shared body of `set` and `set_if`.
+fn set_path(
+    py: Python<'_>,
+    data: PyObject,
+    path: &str,
+    value: PyObject,
+    strict: bool,
+    dialect: &str,
+    params: Option<Bound<'_, PyDict>>,
+    report: bool,
+    write_options: WriteOptions<'_>,
+) -> PyResult<PyObject> {
+    let json_pointer = is_json_pointer_dialect(dialect)?;
+    let path = expand_path_params(py, path, params.as_ref(), json_pointer)?;
+    let path = path.as_str();
+    let module = py.import_bound("dictwalk.dictwalk")?;
+    let registry = load_registry(py)?;
+    let tokens = if json_pointer {
+        parse_json_pointer_write_path(py, path, &data)?
+    } else {
+        parse_write_path(py, &module, &registry, path)?
+    };
+
+    if strict && !tokens.is_empty() {
+        ensure_path_resolves(
+            py,
+            &module,
+            &registry,
+            &data,
+            path,
+            &tokens,
+            tokens.len() - 1,
+        )?;
+    }
+
+    let root_data = data.clone_ref(py);
+    let modified = RefCell::new(Vec::new());
+    let _ = set_recurse(
+        py,
+        &module,
+        &registry,
+        data.clone_ref(py),
+        &tokens,
+        &value,
+        write_options,
+        &root_data,
+        &WriteTrace::root(report.then_some(&modified)),
+    )?;
+
+    if report {
+        return Ok((data, modified.into_inner()).into_py(py));
+    }
+    Ok(data)
+}
+
 fn set_deep_wildcard_token(
     py: Python<'_>,
     module: &Bound<'_, PyModule>,
@@ -3935,8 +8461,9 @@ fn set_deep_wildcard_token(
     current: PyObject,
     remaining: &[ParsedToken],
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     if !is_dict_or_list(&current.bind(py)) {
         return Ok(current);
@@ -3945,7 +8472,10 @@ fn set_deep_wildcard_token(
     let apply_options = WriteOptions {
         create_missing: false,
         create_filter_match: write_options.create_filter_match,
-        overwrite_incompatible: write_options.overwrite_incompatible,
+        overwrite_scalar: write_options.overwrite_scalar,
+        overwrite_container: write_options.overwrite_container,
+        skip_unchanged: write_options.skip_unchanged,
+        condition: write_options.condition,
     };
     deep_set_walk(
         py,
@@ -3956,6 +8486,8 @@ fn set_deep_wildcard_token(
         new_value,
         apply_options,
         root_data,
+        trace,
+        0,
     )?;
     Ok(current)
 }
@@ -3969,8 +8501,9 @@ fn set_index_token(
     key: &str,
     index: isize,
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     let next_kind = remaining.get(1).map(|token| &token.kind);
     let current = coerce_current_to_dict_for_write(py, current, write_options);
@@ -3980,15 +8513,12 @@ fn set_index_token(
 
     let dict = current.bind(py).downcast::<PyDict>()?;
     let list_obj: PyObject = match dict.get_item(key)? {
-        Some(value) => {
-            if value.is_instance_of::<PyList>() {
-                value.into()
-            } else {
-                if !write_options.overwrite_incompatible {
-                    return Ok(current);
-                }
-                PyList::empty_bound(py).into()
+        Some(value) if value.is_instance_of::<PyList>() => value.into(),
+        Some(existing) => {
+            if !write_options.can_overwrite(&existing) {
+                return Ok(current);
             }
+            PyList::empty_bound(py).into()
         }
         None => {
             if !write_options.create_missing {
@@ -4027,17 +8557,28 @@ fn set_index_token(
     };
 
     if remaining.len() == 1 {
-        let existing = list.get_item(target_index)?.into();
-        let resolved =
-            resolve_new_value(py, module, registry, Some(existing), new_value, root_data)?;
+        let existing: PyObject = list.get_item(target_index)?.into();
+        let Some(resolved) = write_leaf(
+            py,
+            module,
+            registry,
+            Some(existing),
+            new_value,
+            write_options,
+            root_data,
+        )?
+        else {
+            return Ok(current);
+        };
         list.set_item(target_index, resolved)?;
+        trace.key(key).index(target_index).record();
         dict.set_item(key, list_obj)?;
         return Ok(current);
     }
 
     let mut item: PyObject = list.get_item(target_index)?.into();
     if next_kind.is_some() && !is_dict_or_list(&item.bind(py)) {
-        if !write_options.overwrite_incompatible {
+        if !write_options.overwrite_scalar {
             dict.set_item(key, list_obj)?;
             return Ok(current);
         }
@@ -4053,6 +8594,7 @@ fn set_index_token(
         new_value,
         write_options,
         root_data,
+        &trace.key(key).index(target_index),
     )?;
     list.set_item(target_index, updated)?;
     dict.set_item(key, list_obj)?;
@@ -4069,8 +8611,9 @@ fn set_slice_token(
     start: Option<isize>,
     end: Option<isize>,
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     let next_kind = remaining.get(1).map(|token| &token.kind);
     let current = coerce_current_to_dict_for_write(py, current, write_options);
@@ -4080,15 +8623,12 @@ fn set_slice_token(
 
     let dict = current.bind(py).downcast::<PyDict>()?;
     let list_obj: PyObject = match dict.get_item(key)? {
-        Some(value) => {
-            if value.is_instance_of::<PyList>() {
-                value.into()
-            } else {
-                if !write_options.overwrite_incompatible {
-                    return Ok(current);
-                }
-                PyList::empty_bound(py).into()
+        Some(value) if value.is_instance_of::<PyList>() => value.into(),
+        Some(existing) => {
+            if !write_options.can_overwrite(&existing) {
+                return Ok(current);
             }
+            PyList::empty_bound(py).into()
         }
         None => {
             if !write_options.create_missing {
@@ -4102,10 +8642,21 @@ fn set_slice_token(
 
     if remaining.len() == 1 {
         for idx in indexes {
-            let existing = list.get_item(idx)?.into();
-            let resolved =
-                resolve_new_value(py, module, registry, Some(existing), new_value, root_data)?;
+            let existing: PyObject = list.get_item(idx)?.into();
+            let Some(resolved) = write_leaf(
+                py,
+                module,
+                registry,
+                Some(existing),
+                new_value,
+                write_options,
+                root_data,
+            )?
+            else {
+                continue;
+            };
             list.set_item(idx, resolved)?;
+            trace.key(key).index(idx).record();
         }
         dict.set_item(key, list_obj)?;
         return Ok(current);
@@ -4114,7 +8665,7 @@ fn set_slice_token(
     for idx in indexes {
         let mut item: PyObject = list.get_item(idx)?.into();
         if next_kind.is_some() && !is_dict_or_list(&item.bind(py)) {
-            if !write_options.overwrite_incompatible {
+            if !write_options.overwrite_scalar {
                 continue;
             }
             item = new_write_container(py);
@@ -4128,6 +8679,7 @@ fn set_slice_token(
             new_value,
             write_options,
             root_data,
+            &trace.key(key).index(idx),
         )?;
         list.set_item(idx, updated)?;
     }
@@ -4143,12 +8695,11 @@ fn set_filter_token(
     current: PyObject,
     remaining: &[ParsedToken],
     list_key: &str,
-    field: &str,
-    operator: &str,
-    value: &str,
+    filter: &FilterExpr,
     new_value: &PyObject,
-    write_options: WriteOptions,
+    write_options: WriteOptions<'_>,
     root_data: &PyObject,
+    trace: &WriteTrace<'_>,
 ) -> PyResult<PyObject> {
     if !current.bind(py).is_instance_of::<PyDict>() {
         return Ok(current);
@@ -4156,15 +8707,12 @@ fn set_filter_token(
     let dict = current.bind(py).downcast::<PyDict>()?;
 
     let list_obj: PyObject = match dict.get_item(list_key)? {
-        Some(value_obj) => {
-            if value_obj.is_instance_of::<PyList>() {
-                value_obj.into()
-            } else {
-                if !write_options.overwrite_incompatible {
-                    return Ok(current);
-                }
-                PyList::empty_bound(py).into()
+        Some(value_obj) if value_obj.is_instance_of::<PyList>() => value_obj.into(),
+        Some(existing) => {
+            if !write_options.can_overwrite(&existing) {
+                return Ok(current);
             }
+            PyList::empty_bound(py).into()
         }
         None => {
             if !write_options.create_missing {
@@ -4174,7 +8722,7 @@ fn set_filter_token(
         }
     };
     let list = list_obj.bind(py).downcast::<PyList>()?;
-    let matcher = compile_filter_matcher(py, module, registry, field, value)?;
+    let matcher = compile_filter_matcher(py, module, registry, filter, Some(root_data))?;
 
     let mut matches: Vec<bool> = Vec::with_capacity(list.len());
     for idx in 0..list.len() {
@@ -4183,44 +8731,48 @@ fn set_filter_token(
             py,
             module,
             registry,
-            operator,
             &matcher,
             &item,
             Some(root_data),
         )?);
     }
 
-    if !matches.iter().any(|matched| *matched) {
+    // Only a single `field == literal` clause describes an item that can be created.
+    if let (false, CompiledFilterMatcher::Clause(clause)) =
+        (matches.iter().any(|matched| *matched), &matcher)
+    {
         let field_uses_item_root = matches!(
-            matcher.field_resolver,
+            clause.field_resolver,
             FieldValueResolver::CurrentItem
                 | FieldValueResolver::CurrentItemBuiltinPipeline(_)
                 | FieldValueResolver::CurrentItemTransform(_)
+                | FieldValueResolver::CurrentItemExpr(_)
         );
         let field_path_filter_present = matches!(
-            matcher.field_resolver,
+            clause.field_resolver,
             FieldValueResolver::CurrentItemBuiltinPipeline(_)
                 | FieldValueResolver::CurrentItemTransform(_)
+                | FieldValueResolver::CurrentItemExpr(_)
                 | FieldValueResolver::PredicateFilter(_)
         );
         let value_path_filter_present = matches!(
-            matcher.value_matcher,
+            clause.value_matcher,
             ValueMatcher::BuiltinPipeline(_) | ValueMatcher::PredicateExpr(_)
         );
 
         if !field_uses_item_root
             && !field_path_filter_present
             && !value_path_filter_present
-            && operator == "=="
+            && clause.operator == "=="
             && write_options.create_missing
             && write_options.create_filter_match
         {
             let new_item = PyDict::new_bound(py);
-            let field_key = match &matcher.field_resolver {
+            let field_key = match &clause.field_resolver {
                 FieldValueResolver::Key(key) => key.as_str(),
-                _ => field,
+                _ => clause.raw_field.as_str(),
             };
-            new_item.set_item(field_key, value)?;
+            new_item.set_item(field_key, clause.raw_value.as_str())?;
             list.append(new_item.clone())?;
             matches.push(true);
         }
@@ -4231,10 +8783,21 @@ fn set_filter_token(
             if !matches.get(idx).copied().unwrap_or(false) {
                 continue;
             }
-            let existing = list.get_item(idx)?.into();
-            let resolved =
-                resolve_new_value(py, module, registry, Some(existing), new_value, root_data)?;
+            let existing: PyObject = list.get_item(idx)?.into();
+            let Some(resolved) = write_leaf(
+                py,
+                module,
+                registry,
+                Some(existing),
+                new_value,
+                write_options,
+                root_data,
+            )?
+            else {
+                continue;
+            };
             list.set_item(idx, resolved)?;
+            trace.key(list_key).index(idx).record();
         }
         dict.set_item(list_key, list_obj)?;
         return Ok(current);
@@ -4254,6 +8817,7 @@ fn set_filter_token(
             new_value,
             write_options,
             root_data,
+            &trace.key(list_key).index(idx),
         )?;
         list.set_item(idx, updated)?;
     }
@@ -4281,33 +8845,31 @@ fn unset_recurse(
         TokenKind::RootSlice { start, end } => {
             unset_root_slice_token(py, module, registry, current, remaining, *start, *end)
         }
-        TokenKind::RootFilter {
-            field,
-            operator,
-            value,
-        } => unset_root_filter_token(
-            py, module, registry, current, remaining, field, operator, value,
-        ),
+        TokenKind::RootFilter { filter } => {
+            unset_root_filter_token(py, module, registry, current, remaining, filter)
+        }
         TokenKind::Get(key) => unset_get_token(py, module, registry, current, remaining, key),
         TokenKind::Map(key) => unset_map_token(py, module, registry, current, remaining, key),
         TokenKind::Wildcard => unset_wildcard_token(py, module, registry, current, remaining),
         TokenKind::DeepWildcard => {
             unset_deep_wildcard_token(py, module, registry, current, remaining)
         }
-        TokenKind::Index { key, index } => {
-            unset_index_token(py, module, registry, current, remaining, key, *index)
-        }
+        TokenKind::Index {
+            key,
+            index: IndexValue::Literal(index),
+        } => unset_index_token(py, module, registry, current, remaining, key, *index),
+        TokenKind::Index {
+            index: IndexValue::RootReference(reference),
+            ..
+        } => Err(PyValueError::new_err(format!(
+            "Index reference '{reference}' is not supported in unset paths."
+        ))),
         TokenKind::Slice { key, start, end } => {
             unset_slice_token(py, module, registry, current, remaining, key, *start, *end)
         }
-        TokenKind::Filter {
-            list_key,
-            field,
-            operator,
-            value,
-        } => unset_filter_token(
-            py, module, registry, current, remaining, list_key, field, operator, value,
-        ),
+        TokenKind::Filter { list_key, filter } => {
+            unset_filter_token(py, module, registry, current, remaining, list_key, filter)
+        }
         TokenKind::Root => Ok(current),
     }
 }
@@ -4355,13 +8917,8 @@ fn unset_map_token(
 
     let dict = current.bind(py).downcast::<PyDict>()?;
     let list_obj: PyObject = match dict.get_item(key)? {
-        Some(value) => {
-            if value.is_instance_of::<PyList>() {
-                value.into()
-            } else {
-                return Ok(current);
-            }
-        }
+        Some(value) if value.is_instance_of::<PyList>() => value.into(),
+        Some(_) => return Ok(current),
         None => return Ok(current),
     };
     let list = list_obj.bind(py).downcast::<PyList>()?;
@@ -4477,24 +9034,21 @@ fn unset_root_filter_token(
     registry: &Bound<'_, PyAny>,
     current: PyObject,
     remaining: &[ParsedToken],
-    field: &str,
-    operator: &str,
-    value: &str,
+    filter: &FilterExpr,
 ) -> PyResult<PyObject> {
     if !current.bind(py).is_instance_of::<PyList>() {
         return Ok(current);
     }
 
     let list = current.bind(py).downcast::<PyList>()?;
-    let matcher = compile_filter_matcher(py, module, registry, field, value)?;
+    let matcher = compile_filter_matcher(py, module, registry, filter, None)?;
 
     if remaining.len() == 1 {
         let filtered = PyList::empty_bound(py);
         for idx in 0..list.len() {
             let item = list.get_item(idx)?;
             let item_obj: PyObject = item.clone().into();
-            if !filter_matches_compiled(py, module, registry, operator, &matcher, &item_obj, None)?
-            {
+            if !filter_matches_compiled(py, module, registry, &matcher, &item_obj, None)? {
                 filtered.append(item)?;
             }
         }
@@ -4508,7 +9062,7 @@ fn unset_root_filter_token(
     for idx in 0..list.len() {
         let item = list.get_item(idx)?;
         let item_obj: PyObject = item.clone().into();
-        if !filter_matches_compiled(py, module, registry, operator, &matcher, &item_obj, None)? {
+        if !filter_matches_compiled(py, module, registry, &matcher, &item_obj, None)? {
             continue;
         }
         let child: PyObject = item.into();
@@ -4568,7 +9122,9 @@ fn deep_unset_walk(
     registry: &Bound<'_, PyAny>,
     node: PyObject,
     remaining: &[ParsedToken],
+    depth: usize,
 ) -> PyResult<()> {
+    check_recursion_depth_limit(py, depth)?;
     if node.bind(py).is_instance_of::<PyDict>() {
         let dict = node.bind(py).downcast::<PyDict>()?;
         let keys = dict_keys(dict);
@@ -4585,7 +9141,14 @@ fn deep_unset_walk(
 
             if let Some(next_child) = dict.get_item(key.bind(py))? {
                 if is_dict_or_list(&next_child) {
-                    deep_unset_walk(py, module, registry, next_child.into(), remaining)?;
+                    deep_unset_walk(
+                        py,
+                        module,
+                        registry,
+                        next_child.into(),
+                        remaining,
+                        depth + 1,
+                    )?;
                 }
             }
         }
@@ -4603,7 +9166,14 @@ fn deep_unset_walk(
 
             let next_child = list.get_item(idx)?;
             if is_dict_or_list(&next_child) {
-                deep_unset_walk(py, module, registry, next_child.into(), remaining)?;
+                deep_unset_walk(
+                    py,
+                    module,
+                    registry,
+                    next_child.into(),
+                    remaining,
+                    depth + 1,
+                )?;
             }
         }
     }
@@ -4622,7 +9192,7 @@ fn unset_deep_wildcard_token(
         return Ok(current);
     }
 
-    deep_unset_walk(py, module, registry, current.clone_ref(py), remaining)?;
+    deep_unset_walk(py, module, registry, current.clone_ref(py), remaining, 0)?;
     Ok(current)
 }
 
@@ -4641,13 +9211,8 @@ fn unset_index_token(
 
     let dict = current.bind(py).downcast::<PyDict>()?;
     let list_obj: PyObject = match dict.get_item(key)? {
-        Some(value) => {
-            if value.is_instance_of::<PyList>() {
-                value.into()
-            } else {
-                return Ok(current);
-            }
-        }
+        Some(value) if value.is_instance_of::<PyList>() => value.into(),
+        Some(_) => return Ok(current),
         None => return Ok(current),
     };
     let list = list_obj.bind(py).downcast::<PyList>()?;
@@ -4692,13 +9257,8 @@ fn unset_slice_token(
 
     let dict = current.bind(py).downcast::<PyDict>()?;
     let list_obj: PyObject = match dict.get_item(key)? {
-        Some(value) => {
-            if value.is_instance_of::<PyList>() {
-                value.into()
-            } else {
-                return Ok(current);
-            }
-        }
+        Some(value) if value.is_instance_of::<PyList>() => value.into(),
+        Some(_) => return Ok(current),
         None => return Ok(current),
     };
     let list = list_obj.bind(py).downcast::<PyList>()?;
@@ -4729,9 +9289,7 @@ fn unset_filter_token(
     current: PyObject,
     remaining: &[ParsedToken],
     list_key: &str,
-    field: &str,
-    operator: &str,
-    value: &str,
+    filter: &FilterExpr,
 ) -> PyResult<PyObject> {
     if !current.bind(py).is_instance_of::<PyDict>() {
         return Ok(current);
@@ -4739,25 +9297,19 @@ fn unset_filter_token(
 
     let dict = current.bind(py).downcast::<PyDict>()?;
     let list_obj: PyObject = match dict.get_item(list_key)? {
-        Some(value_obj) => {
-            if value_obj.is_instance_of::<PyList>() {
-                value_obj.into()
-            } else {
-                return Ok(current);
-            }
-        }
+        Some(value_obj) if value_obj.is_instance_of::<PyList>() => value_obj.into(),
+        Some(_) => return Ok(current),
         None => return Ok(current),
     };
     let list = list_obj.bind(py).downcast::<PyList>()?;
-    let matcher = compile_filter_matcher(py, module, registry, field, value)?;
+    let matcher = compile_filter_matcher(py, module, registry, filter, None)?;
 
     if remaining.len() == 1 {
         let filtered = PyList::empty_bound(py);
         for idx in 0..list.len() {
             let item = list.get_item(idx)?;
             let item_obj: PyObject = item.clone().into();
-            if !filter_matches_compiled(py, module, registry, operator, &matcher, &item_obj, None)?
-            {
+            if !filter_matches_compiled(py, module, registry, &matcher, &item_obj, None)? {
                 filtered.append(item)?;
             }
         }
@@ -4767,7 +9319,7 @@ fn unset_filter_token(
 
     for idx in 0..list.len() {
         let child: PyObject = list.get_item(idx)?.into();
-        if !filter_matches_compiled(py, module, registry, operator, &matcher, &child, None)? {
+        if !filter_matches_compiled(py, module, registry, &matcher, &child, None)? {
             continue;
         }
         let updated = unset_recurse(py, module, registry, child, &remaining[1..])?;
@@ -4778,6 +9330,476 @@ fn unset_filter_token(
     Ok(current)
 }
 
+/// Parses a read path for concrete resolution; `.` yields no tokens.
+fn parse_concrete_path(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    path: &str,
+) -> PyResult<(String, Vec<ParsedToken>)> {
+    let (base_path, output_transform) = split_path_and_transform(path);
+    if output_transform.is_some() {
+        return Err(make_parse_error(
+            py,
+            path,
+            None,
+            "Output transforms are not supported when resolving concrete paths.",
+        ));
+    }
+    if base_path == "." {
+        return Ok((base_path, Vec::new()));
+    }
+
+    let tokens = parse_path(py, module, registry, &base_path)?;
+    validate_read_path_root_token(py, &base_path, &tokens)?;
+    Ok((base_path, tokens))
+}
+
+/// Streaming counterpart of the `get` resolution loop: calls `visit` with
+/// every value `path` selects instead of collecting them, and with its
+/// location when `track_paths` is set. Returns `false` when the sink or a
+/// soft resolution error ended the walk early (raised instead with `strict`).
+fn visit_path(
+    py: Python<'_>,
+    data: &PyObject,
+    path: &str,
+    strict: bool,
+    track_paths: bool,
+    visit: &mut MatchSink<'_>,
+) -> PyResult<bool> {
+    let module = py.import_bound("dictwalk.dictwalk")?;
+    let registry = load_registry(py)?;
+    let (base_path, tokens) = parse_concrete_path(py, &module, &registry, path)?;
+    let walk = PathWalk {
+        module: &module,
+        registry: &registry,
+        root_data: data,
+        path: &base_path,
+        strict,
+        matchers: tokens.iter().map(|_| RefCell::new(None)).collect(),
+        tokens: &tokens,
+    };
+    walk.visit(
+        py,
+        0,
+        data,
+        Reach::Node,
+        track_paths.then(ConcretePath::root),
+        visit,
+    )
+}
+
+struct PathWalk<'a, 'py> {
+    module: &'a Bound<'py, PyModule>,
+    registry: &'a Bound<'py, PyAny>,
+    root_data: &'a PyObject,
+    path: &'a str,
+    strict: bool,
+    tokens: &'a [ParsedToken],
+    /// Filter predicates, compiled the first time their token is reached.
+    matchers: Vec<RefCell<Option<Rc<CompiledFilterMatcher>>>>,
+}
+
+impl PathWalk<'_, '_> {
+    /// Depth first: each value token `idx` selects goes through the remaining
+    /// tokens before the next one is looked at, so no token's matches are
+    /// ever collected.
+    fn visit(
+        &self,
+        py: Python<'_>,
+        idx: usize,
+        current: &PyObject,
+        reach: Reach,
+        at: Option<ConcretePath>,
+        sink: &mut MatchSink<'_>,
+    ) -> PyResult<bool> {
+        let Some(token) = self.tokens.get(idx) else {
+            return sink(current.clone_ref(py), reach, at);
+        };
+        let result = self.matcher(py, idx).and_then(|matcher| {
+            visit_token(
+                py,
+                self.module,
+                self.registry,
+                self.root_data,
+                &token.kind,
+                matcher.as_deref(),
+                current,
+                reach,
+                at.as_ref(),
+                &mut |value, reach, at| self.visit(py, idx + 1, &value, reach, at, sink),
+            )
+        });
+        match result {
+            Ok(more) => Ok(more),
+            Err(err) if !is_soft_resolution_error(py, &err) => Err(err),
+            Err(err) if self.strict => Err(make_resolution_error(
+                py,
+                self.path,
+                Some(&token.raw),
+                &err.to_string(),
+            )),
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn matcher(&self, py: Python<'_>, idx: usize) -> PyResult<Option<Rc<CompiledFilterMatcher>>> {
+        let mut cached = self.matchers[idx].borrow_mut();
+        if cached.is_none() {
+            let kind = &self.tokens[idx].kind;
+            *cached = compile_token_matcher(py, self.module, self.registry, kind, self.root_data)?
+                .map(Rc::new);
+        }
+        Ok(cached.clone())
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ReadOptions {
+    strict: bool,
+    copy: bool,
+    map_transform: bool,
+    json_pointer: bool,
+    returns: GetReturns,
+}
+
+const POSITIONAL_RETURNS_MESSAGE: &str =
+    "get(returns=...) requires the path to end in a filter or '*' token.";
+
+/// Shape of a `get` result for a path ending in a filter or `*` token.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum GetReturns {
+    Values,
+    Indices,
+    Items,
+}
+
+/// `get(..., flatten=...)`: `False`/`True` or a non-negative number of levels.
+fn parse_flatten_depth(flatten: &Bound<'_, PyAny>) -> PyResult<usize> {
+    if let Ok(flag) = flatten.downcast::<PyBool>() {
+        return Ok(usize::from(flag.is_true()));
+    }
+    match flatten.extract::<isize>() {
+        Ok(depth) if depth >= 0 => Ok(depth as usize),
+        _ => Err(PyValueError::new_err(format!(
+            "Unsupported flatten {}. Expected a bool or a non-negative int.",
+            flatten.repr()?
+        ))),
+    }
+}
+
+fn parse_get_returns(returns: &str) -> PyResult<GetReturns> {
+    match returns {
+        "values" => Ok(GetReturns::Values),
+        "indices" => Ok(GetReturns::Indices),
+        "items" => Ok(GetReturns::Items),
+        other => Err(PyValueError::new_err(format!(
+            "Unsupported returns '{other}'. Expected one of: values, indices, items."
+        ))),
+    }
+}
+
+/// A `get_typed` schema is a `$coerce` type name, a dict of field schemas, or a
+/// one-element list holding the schema of every list item.
+fn validate_type_schema(py: Python<'_>, schema: &Bound<'_, PyAny>, depth: usize) -> PyResult<()> {
+    check_recursion_depth_limit(py, depth)?;
+    if let Ok(type_name) = schema.extract::<String>() {
+        return coerce_value(py, &py.None(), &type_name).map(|_| ());
+    }
+    if let Ok(fields) = schema.downcast::<PyDict>() {
+        for (_, field_schema) in fields.iter() {
+            validate_type_schema(py, &field_schema, depth + 1)?;
+        }
+        return Ok(());
+    }
+    if let Ok(items) = schema.downcast::<PyList>() {
+        if items.len() == 1 {
+            return validate_type_schema(py, &items.get_item(0)?, depth + 1);
+        }
+    }
+    Err(PyTypeError::new_err(format!(
+        "get_typed schema entries must be a type name, a dict, or a one-element list, got {}.",
+        schema.repr()?
+    )))
+}
+
+/// Rebuilds `value` with every field named by `schema` coerced. Fields absent
+/// from the schema are copied unchanged and `None` stays `None`; a value that
+/// cannot be coerced becomes `None`, or raises when `raise` is set.
+fn apply_type_schema(
+    py: Python<'_>,
+    value: &PyObject,
+    schema: &Bound<'_, PyAny>,
+    path: &str,
+    field: &str,
+    raise: bool,
+) -> PyResult<PyObject> {
+    let value_bound = value.bind(py);
+    if value_bound.is_none() {
+        return Ok(py.None());
+    }
+
+    let expected = if let Ok(type_name) = schema.extract::<String>() {
+        if let Some(coerced) = coerce_value(py, value, &type_name)? {
+            return Ok(coerced);
+        }
+        type_name
+    } else if let Ok(fields) = schema.downcast::<PyDict>() {
+        if let Some(mapping) = as_read_mapping(&value_bound) {
+            let out = PyDict::new_bound(py);
+            for item in mapping.items()?.iter()? {
+                let (key, item_value) = item?.extract::<(PyObject, PyObject)>()?;
+                let updated = match fields.get_item(key.bind(py))? {
+                    Some(field_schema) => {
+                        let key_text = key.bind(py).str()?.to_string_lossy().to_string();
+                        apply_type_schema(
+                            py,
+                            &item_value,
+                            &field_schema,
+                            path,
+                            &concrete_key_path(field, &key_text),
+                            raise,
+                        )?
+                    }
+                    None => item_value,
+                };
+                out.set_item(key, updated)?;
+            }
+            return Ok(out.into());
+        }
+        "dict".to_string()
+    } else {
+        let item_schema = schema.get_item(0)?;
+        if let Some(items) = collect_sequence_items(py, value)? {
+            let out = PyList::empty_bound(py);
+            for (idx, item) in items.iter().enumerate() {
+                out.append(apply_type_schema(
+                    py,
+                    item,
+                    &item_schema,
+                    path,
+                    &concrete_index_path(field, idx),
+                    raise,
+                )?)?;
+            }
+            return Ok(out.into());
+        }
+        "list".to_string()
+    };
+
+    if !raise {
+        return Ok(py.None());
+    }
+    let field = if field.is_empty() { "." } else { field };
+    Err(make_resolution_error(
+        py,
+        path,
+        Some(field),
+        &format!(
+            "Cannot coerce {} at '{field}' to {expected}.",
+            value_bound.repr()?
+        ),
+    ))
+}
+
+/// The `get` resolution loop. The flag is `false` when a soft resolution
+/// failure made it fall back to `default`.
+fn resolve_get(
+    py: Python<'_>,
+    data: PyObject,
+    path: &str,
+    default: Option<PyObject>,
+    options: ReadOptions,
+) -> PyResult<(PyObject, bool)> {
+    let finish = |value: PyObject| -> PyResult<PyObject> {
+        if !options.copy {
+            return Ok(value);
+        }
+        py.import_bound("copy")?
+            .getattr("deepcopy")?
+            .call1((value,))
+            .map(|copied| copied.into())
+    };
+    let module = py.import_bound("dictwalk.dictwalk")?;
+    let registry = load_registry(py)?;
+    let (base_path, output_transform) = if options.json_pointer {
+        (path.to_string(), None)
+    } else {
+        split_path_and_transform(path)
+    };
+    let transform_output = |current: &PyObject, transform: &str| -> PyResult<PyObject> {
+        if options.map_transform {
+            apply_output_transform_to_elements(py, current, transform, &data)
+        } else {
+            apply_output_transform(py, &module, &registry, current, transform, &data)
+        }
+    };
+
+    let positional = options.returns != GetReturns::Values;
+    if positional && output_transform.is_some() {
+        return Err(PyValueError::new_err(
+            "get(returns=...) cannot be combined with an output transform.",
+        ));
+    }
+
+    if !options.json_pointer && base_path == "." {
+        if positional {
+            return Err(PyValueError::new_err(POSITIONAL_RETURNS_MESSAGE));
+        }
+        let mut current = data.clone_ref(py);
+        if let Some(transform) = output_transform {
+            current = transform_output(&current, &transform)?;
+        }
+        return Ok((finish(current)?, true));
+    }
+
+    let tokens = if options.json_pointer {
+        parse_json_pointer_path(py, &base_path, &data)?
+    } else {
+        let tokens = parse_path(py, &module, &registry, &base_path)?;
+        validate_read_path_root_token(py, &base_path, &tokens)?;
+        tokens
+    };
+    if positional
+        && !matches!(
+            tokens.last().map(|token| &token.kind),
+            Some(TokenKind::Filter { .. } | TokenKind::RootFilter { .. } | TokenKind::Wildcard)
+        )
+    {
+        return Err(PyValueError::new_err(POSITIONAL_RETURNS_MESSAGE));
+    }
+    let mut current = data.clone_ref(py);
+    let token_count = tokens.len();
+
+    for (idx, token) in tokens.into_iter().enumerate() {
+        if matches!(token.kind, TokenKind::Root) {
+            current = data.clone_ref(py);
+            continue;
+        }
+
+        let resolved = if positional && idx + 1 == token_count {
+            resolve_token_positions(py, &module, &registry, &current, &data, &token.kind).and_then(
+                |positions| {
+                    let out = PyList::empty_bound(py);
+                    for (position, value) in positions {
+                        if options.returns == GetReturns::Indices {
+                            out.append(position)?;
+                        } else {
+                            out.append(PyTuple::new_bound(py, [position, value]))?;
+                        }
+                    }
+                    Ok(out.into_any().unbind())
+                },
+            )
+        } else {
+            resolve_token(py, &module, &registry, &current, &data, &token.kind)
+        };
+
+        match resolved {
+            Ok(value) => current = value,
+            Err(err) => {
+                if is_soft_resolution_error(py, &err) {
+                    if options.strict {
+                        return Err(make_resolution_error(
+                            py,
+                            &base_path,
+                            Some(&token.raw),
+                            &err.to_string(),
+                        ));
+                    }
+                    return Ok((default.unwrap_or_else(|| py.None()), false));
+                }
+                return Err(err);
+            }
+        }
+    }
+
+    if let Some(transform) = output_transform {
+        current = transform_output(&current, &transform)?;
+    }
+
+    Ok((finish(current)?, true))
+}
+
+/// Walks already-parsed read `tokens` like `exists`: soft resolution errors
+/// mean "missing" unless `strict`, in which case they are raised.
+fn path_tokens_exist(
+    py: Python<'_>,
+    module: &Bound<'_, PyModule>,
+    registry: &Bound<'_, PyAny>,
+    data: &PyObject,
+    path: &str,
+    tokens: &[ParsedToken],
+    strict: bool,
+    nonempty_wildcards: bool,
+) -> PyResult<bool> {
+    let mut current = data.clone_ref(py);
+    let token_count = tokens.len();
+    let mut matched_any = true;
+    let mut final_probed = false;
+
+    for (idx, token) in tokens.iter().enumerate() {
+        if matches!(token.kind, TokenKind::Root) {
+            current = data.clone_ref(py);
+            continue;
+        }
+
+        // The final token's matches are never returned, so avoid materializing them.
+        let probed = if idx + 1 == token_count {
+            probe_final_token(py, module, registry, &current, data, &token.kind)
+        } else {
+            None
+        };
+        let resolved = match probed {
+            Some(result) => result.map(|found| {
+                matched_any = found;
+                final_probed = true;
+                current.clone_ref(py)
+            }),
+            None => resolve_token(py, module, registry, &current, data, &token.kind),
+        };
+
+        match resolved {
+            Ok(value) => current = value,
+            Err(err) => {
+                if is_soft_resolution_error(py, &err) {
+                    if strict {
+                        return Err(make_resolution_error(
+                            py,
+                            path,
+                            Some(&token.raw),
+                            &err.to_string(),
+                        ));
+                    }
+                    return Ok(false);
+                }
+                return Err(err);
+            }
+        }
+    }
+
+    if !nonempty_wildcards {
+        return Ok(true);
+    }
+    // Tokens after a projection keep projecting, so the final value is the
+    // list of everything the path selected.
+    let projects = tokens.iter().any(|token| {
+        matches!(
+            token.kind,
+            TokenKind::Wildcard
+                | TokenKind::DeepWildcard
+                | TokenKind::Filter { .. }
+                | TokenKind::RootFilter { .. }
+                | TokenKind::Map(_)
+                | TokenKind::RootMap
+        )
+    });
+    if projects && !final_probed {
+        matched_any = current.bind(py).len()? > 0;
+    }
+    Ok(matched_any)
+}
+
 #[pyclass(name = "DictWalk")]
 #[derive(Default)]
 struct RustDictWalk;
@@ -4790,7 +9812,7 @@ impl RustDictWalk {
         Self
     }
 
-    #[pyo3(signature = (data, path, default=None, *, strict=false))]
+    #[pyo3(signature = (data, path, default=None, *, strict=false, lenient_compare=false, copy=false, map_transform=false, now=None, dialect="dictwalk", params=None, returns="values", normalize_tuples=false, flatten=None, fallback=None))]
     fn get(
         &self,
         py: Python<'_>,
@@ -4798,103 +9820,302 @@ impl RustDictWalk {
         path: &str,
         default: Option<PyObject>,
         strict: bool,
+        lenient_compare: bool,
+        copy: bool,
+        map_transform: bool,
+        now: Option<PyObject>,
+        dialect: &str,
+        params: Option<Bound<'_, PyDict>>,
+        returns: &str,
+        normalize_tuples: bool,
+        flatten: Option<Bound<'_, PyAny>>,
+        fallback: Option<PyObject>,
     ) -> PyResult<PyObject> {
-        let module = py.import_bound("dictwalk.dictwalk")?;
-        let registry = load_registry(py)?;
-        let (base_path, output_transform) = split_path_and_transform(path);
-
-        if base_path == "." {
-            let mut current = data.clone_ref(py);
-            if let Some(transform) = output_transform {
-                current =
-                    apply_output_transform(py, &module, &registry, &current, &transform, &data)?;
+        let options = ReadOptions {
+            strict,
+            copy,
+            map_transform,
+            json_pointer: is_json_pointer_dialect(dialect)?,
+            returns: parse_get_returns(returns)?,
+        };
+        let path = expand_path_params(py, path, params.as_ref(), options.json_pointer)?;
+        let _lenient_compare = LenientCompareGuard::new(lenient_compare);
+        let _now = NowOverrideGuard::new(py, now)?;
+        let flatten_depth = match flatten {
+            Some(flatten) => parse_flatten_depth(&flatten)?,
+            None => 0,
+        };
+        let (mut value, found) = match fallback {
+            Some(fallback) => {
+                let primary = ReadOptions {
+                    strict: false,
+                    ..options
+                };
+                match resolve_get(py, data, &path, None, primary)? {
+                    (value, true) => (value, true),
+                    (_, false) => resolve_get(py, fallback, &path, default, options)?,
+                }
             }
-            return Ok(current);
+            None => resolve_get(py, data, &path, default, options)?,
+        };
+        if !found {
+            return Ok(value);
         }
+        for _ in 0..flatten_depth {
+            value = apply_builtin_filter(py, &value, &BuiltinFilter::Flatten)?;
+        }
+        if normalize_tuples {
+            return crate::normalize_tuples(py, &value, 0);
+        }
+        Ok(value)
+    }
 
-        let tokens = parse_path(py, &module, &registry, &base_path)?;
-        validate_read_path_root_token(py, &base_path, &tokens)?;
-        let mut current = data.clone_ref(py);
-
-        for token in tokens {
-            if matches!(token.kind, TokenKind::Root) {
-                current = data.clone_ref(py);
-                continue;
-            }
-
-            let resolved = resolve_token(py, &module, &registry, &current, &data, &token.kind);
+    #[pyo3(signature = (data, path, default=None, *, lenient_compare=false))]
+    fn get_or_default(
+        &self,
+        py: Python<'_>,
+        data: PyObject,
+        path: &str,
+        default: Option<PyObject>,
+        lenient_compare: bool,
+    ) -> PyResult<PyObject> {
+        let options = ReadOptions {
+            strict: false,
+            copy: false,
+            map_transform: false,
+            json_pointer: false,
+            returns: GetReturns::Values,
+        };
+        let _lenient_compare = LenientCompareGuard::new(lenient_compare);
+        let (value, found) = resolve_get(py, data, path, default, options)?;
+        Ok(PyTuple::new_bound(py, [value, found.to_object(py)]).into())
+    }
 
-            match resolved {
-                Ok(value) => current = value,
-                Err(err) => {
-                    if is_soft_resolution_error(py, &err) {
-                        if strict {
-                            return Err(make_resolution_error(
-                                py,
-                                &base_path,
-                                Some(&token.raw),
-                                &err.to_string(),
-                            ));
-                        }
-                        return Ok(default.unwrap_or_else(|| py.None()));
-                    }
-                    return Err(err);
-                }
+    #[pyo3(signature = (data, path, schema, default=None, *, on_error="null", strict=false, lenient_compare=false))]
+    fn get_typed(
+        &self,
+        py: Python<'_>,
+        data: PyObject,
+        path: &str,
+        schema: PyObject,
+        default: Option<PyObject>,
+        on_error: &str,
+        strict: bool,
+        lenient_compare: bool,
+    ) -> PyResult<PyObject> {
+        let raise = match on_error {
+            "null" => false,
+            "raise" => true,
+            other => {
+                return Err(PyValueError::new_err(format!(
+                    "Unsupported on_error '{other}'. Expected one of: null, raise."
+                )))
             }
+        };
+        validate_type_schema(py, schema.bind(py), 0)?;
+        let options = ReadOptions {
+            strict,
+            copy: false,
+            map_transform: false,
+            json_pointer: false,
+            returns: GetReturns::Values,
+        };
+        let _lenient_compare = LenientCompareGuard::new(lenient_compare);
+        let (value, found) = resolve_get(py, data, path, default, options)?;
+        if !found {
+            return Ok(value);
         }
+        apply_type_schema(py, &value, schema.bind(py), path, "", raise)
+    }
 
-        if let Some(transform) = output_transform {
-            current = apply_output_transform(py, &module, &registry, &current, &transform, &data)?;
+    #[pyo3(signature = (data, path, *, strict=false, lenient_compare=false))]
+    fn get_unique(
+        &self,
+        py: Python<'_>,
+        data: PyObject,
+        path: &str,
+        strict: bool,
+        lenient_compare: bool,
+    ) -> PyResult<PyObject> {
+        let options = ReadOptions {
+            strict,
+            copy: false,
+            map_transform: false,
+            json_pointer: false,
+            returns: GetReturns::Values,
+        };
+        let _lenient_compare = LenientCompareGuard::new(lenient_compare);
+        let (value, found) = resolve_get(py, data, path, None, options)?;
+        let value_bound = value.bind(py);
+        if !found || value_bound.is_none() {
+            return Ok(PyList::empty_bound(py).into());
         }
-
-        Ok(current)
+        if !is_list_or_tuple(value_bound) {
+            return Ok(PyList::new_bound(py, [value]).into());
+        }
+        unique_items(py, &value)
     }
 
-    #[pyo3(signature = (data, path, *, strict=false))]
+    #[pyo3(signature = (data, path, *, strict=false, lenient_compare=false, dialect="dictwalk", params=None, nonempty_wildcards=true))]
     fn exists(
         &self,
         py: Python<'_>,
         data: PyObject,
         path: &str,
         strict: bool,
+        lenient_compare: bool,
+        dialect: &str,
+        params: Option<Bound<'_, PyDict>>,
+        nonempty_wildcards: bool,
     ) -> PyResult<PyObject> {
+        let json_pointer = is_json_pointer_dialect(dialect)?;
+        let path = expand_path_params(py, path, params.as_ref(), json_pointer)?;
+        let path = path.as_str();
+        let _lenient_compare = LenientCompareGuard::new(lenient_compare);
         let module = py.import_bound("dictwalk.dictwalk")?;
         let registry = load_registry(py)?;
-        let tokens = parse_path(py, &module, &registry, path)?;
-        validate_read_path_root_token(py, path, &tokens)?;
-        let mut current = data.clone_ref(py);
+        let tokens = if json_pointer {
+            parse_json_pointer_path(py, path, &data)?
+        } else {
+            let tokens = parse_path(py, &module, &registry, path)?;
+            validate_read_path_root_token(py, path, &tokens)?;
+            tokens
+        };
+        path_tokens_exist(
+            py,
+            &module,
+            &registry,
+            &data,
+            path,
+            &tokens,
+            strict,
+            nonempty_wildcards,
+        )
+        .map(|found| found.to_object(py))
+    }
 
-        for token in tokens {
-            if matches!(token.kind, TokenKind::Root) {
-                current = data.clone_ref(py);
-                continue;
+    #[pyo3(signature = (data, path, type_name, *, strict=false, lenient_compare=false))]
+    fn exists_typed(
+        &self,
+        py: Python<'_>,
+        data: PyObject,
+        path: &str,
+        type_name: &str,
+        strict: bool,
+        lenient_compare: bool,
+    ) -> PyResult<bool> {
+        let options = ReadOptions {
+            strict,
+            copy: false,
+            map_transform: false,
+            json_pointer: false,
+            returns: GetReturns::Values,
+        };
+        let _lenient_compare = LenientCompareGuard::new(lenient_compare);
+        let (value, found) = resolve_get(py, data, path, None, options)?;
+        if !found {
+            return Ok(false);
+        }
+        Ok(matches_type_name(value.bind(py), type_name))
+    }
+
+    #[pyo3(signature = (data, path, *, strict=false, lenient_compare=false))]
+    fn first_match_with_path(
+        &self,
+        py: Python<'_>,
+        data: PyObject,
+        path: &str,
+        strict: bool,
+        lenient_compare: bool,
+    ) -> PyResult<PyObject> {
+        let _lenient_compare = LenientCompareGuard::new(lenient_compare);
+        let mut first = None;
+        visit_path(py, &data, path, strict, true, &mut |value, _, at| {
+            first = Some((at.and_then(|at| at.render()), value));
+            Ok(false)
+        })?;
+        Ok(match first {
+            Some((path, value)) => {
+                let path = path.map(|path| {
+                    if path.is_empty() {
+                        ".".to_string()
+                    } else {
+                        path
+                    }
+                });
+                PyTuple::new_bound(py, [path.to_object(py), value]).into()
             }
+            None => py.None(),
+        })
+    }
 
-            let resolved = resolve_token(py, &module, &registry, &current, &data, &token.kind);
+    #[pyo3(signature = (data, path, *, strict=false, lenient_compare=false))]
+    fn match_count(
+        &self,
+        py: Python<'_>,
+        data: PyObject,
+        path: &str,
+        strict: bool,
+        lenient_compare: bool,
+    ) -> PyResult<usize> {
+        let _lenient_compare = LenientCompareGuard::new(lenient_compare);
+        let mut count = 0;
+        let walked = visit_path(py, &data, path, strict, false, &mut |_, _, _| {
+            count += 1;
+            Ok(true)
+        });
+        match walked {
+            Ok(true) => Ok(count),
+            Ok(false) => Ok(0),
+            // An index past the end of a list is a miss, not a malformed path.
+            Err(err) if err.is_instance_of::<PyIndexError>(py) => {
+                if strict {
+                    Err(make_resolution_error(py, path, None, &err.to_string()))
+                } else {
+                    Ok(0)
+                }
+            }
+            Err(err) => Err(err),
+        }
+    }
 
-            match resolved {
-                Ok(value) => current = value,
+    #[pyo3(signature = (data, path, callback, *, strict=false, lenient_compare=false))]
+    fn for_each(
+        &self,
+        py: Python<'_>,
+        data: PyObject,
+        path: &str,
+        callback: Bound<'_, PyAny>,
+        strict: bool,
+        lenient_compare: bool,
+    ) -> PyResult<usize> {
+        if !callback.is_callable() {
+            return Err(PyTypeError::new_err(format!(
+                "for_each callback must be callable, got {}.",
+                get_type_name(&callback)
+            )));
+        }
+        let _lenient_compare = LenientCompareGuard::new(lenient_compare);
+        let mut visited = 0;
+        let mut failure = None;
+        visit_path(py, &data, path, strict, false, &mut |value, _, _| {
+            visited += 1;
+            // Kept apart so a `KeyError` from the callback is not mistaken for a miss.
+            match callback.call1((value,)) {
+                Ok(_) => Ok(true),
                 Err(err) => {
-                    if is_soft_resolution_error(py, &err) {
-                        if strict {
-                            return Err(make_resolution_error(
-                                py,
-                                path,
-                                Some(&token.raw),
-                                &err.to_string(),
-                            ));
-                        }
-                        return Ok(false.to_object(py));
-                    }
-                    return Err(err);
+                    failure = Some(err);
+                    Ok(false)
                 }
             }
+        })?;
+        match failure {
+            Some(err) => Err(err),
+            None => Ok(visited),
         }
-
-        Ok(true.to_object(py))
     }
 
-    #[pyo3(signature = (data, path, value, *, strict=false, create_missing=true, create_filter_match=true, overwrite_incompatible=true))]
+    #[pyo3(signature = (data, path, value, *, strict=false, create_missing=true, create_filter_match=true, overwrite_incompatible=true, overwrite_scalar=None, overwrite_container=None, dialect="dictwalk", params=None, report=false, skip_unchanged=false))]
     fn set(
         &self,
         py: Python<'_>,
@@ -4905,76 +10126,169 @@ impl RustDictWalk {
         create_missing: bool,
         create_filter_match: bool,
         overwrite_incompatible: bool,
+        overwrite_scalar: Option<bool>,
+        overwrite_container: Option<bool>,
+        dialect: &str,
+        params: Option<Bound<'_, PyDict>>,
+        report: bool,
+        skip_unchanged: bool,
     ) -> PyResult<PyObject> {
-        let module = py.import_bound("dictwalk.dictwalk")?;
-        let registry = load_registry(py)?;
-        let tokens = parse_path(py, &module, &registry, path)?;
-
-        validate_write_path_root_selector(py, path, &tokens)?;
+        let write_options = WriteOptions {
+            create_missing,
+            create_filter_match,
+            overwrite_scalar: overwrite_scalar.unwrap_or(overwrite_incompatible),
+            overwrite_container: overwrite_container.unwrap_or(overwrite_incompatible),
+            skip_unchanged,
+            condition: None,
+        };
+        set_path(
+            py,
+            data,
+            path,
+            value,
+            strict,
+            dialect,
+            params,
+            report,
+            write_options,
+        )
+    }
 
-        if path_uses_bare_root_token(&tokens) {
-            return Err(make_parse_error(
-                py,
-                path,
-                Some("$$root"),
-                "The '$$root' token is only supported in read paths.",
-            ));
-        }
+    #[pyo3(signature = (data, path, value, condition, *, strict=false, create_missing=true, create_filter_match=true, overwrite_incompatible=true, overwrite_scalar=None, overwrite_container=None, dialect="dictwalk", params=None, report=false, skip_unchanged=false))]
+    fn set_if(
+        &self,
+        py: Python<'_>,
+        data: PyObject,
+        path: &str,
+        value: PyObject,
+        condition: &str,
+        strict: bool,
+        create_missing: bool,
+        create_filter_match: bool,
+        overwrite_incompatible: bool,
+        overwrite_scalar: Option<bool>,
+        overwrite_container: Option<bool>,
+        dialect: &str,
+        params: Option<Bound<'_, PyDict>>,
+        report: bool,
+        skip_unchanged: bool,
+    ) -> PyResult<PyObject> {
+        let condition = condition.trim();
+        let predicate = compile_builtin_or_boolean_predicate(py, condition, Some(&data))
+            .map_err(|message| make_parse_error(py, condition, Some(condition), &message))?
+            .ok_or_else(|| {
+                make_parse_error(
+                    py,
+                    condition,
+                    Some(condition),
+                    "set_if condition must be a filter or predicate expression",
+                )
+            })?;
+        let write_options = WriteOptions {
+            create_missing,
+            create_filter_match,
+            overwrite_scalar: overwrite_scalar.unwrap_or(overwrite_incompatible),
+            overwrite_container: overwrite_container.unwrap_or(overwrite_incompatible),
+            skip_unchanged,
+            condition: Some(&predicate),
+        };
+        set_path(
+            py,
+            data,
+            path,
+            value,
+            strict,
+            dialect,
+            params,
+            report,
+            write_options,
+        )
+    }
 
-        if strict && !tokens.is_empty() {
-            ensure_path_resolves(
-                py,
-                &module,
-                &registry,
-                &data,
-                path,
-                &tokens,
-                tokens.len() - 1,
-            )?;
+    #[pyo3(signature = (data, paths, value, *, strict=false, create_missing=true, create_filter_match=true, overwrite_incompatible=true, overwrite_scalar=None, overwrite_container=None))]
+    fn set_all(
+        &self,
+        py: Python<'_>,
+        data: PyObject,
+        paths: Vec<String>,
+        value: PyObject,
+        strict: bool,
+        create_missing: bool,
+        create_filter_match: bool,
+        overwrite_incompatible: bool,
+        overwrite_scalar: Option<bool>,
+        overwrite_container: Option<bool>,
+    ) -> PyResult<PyObject> {
+        let module = py.import_bound("dictwalk.dictwalk")?;
+        let registry = load_registry(py)?;
+        let parsed_paths = paths
+            .iter()
+            .map(|path| Ok((path, parse_write_path(py, &module, &registry, path)?)))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        // Validate every path before writing so a strict failure leaves `data` untouched.
+        if strict {
+            for (path, tokens) in &parsed_paths {
+                if !tokens.is_empty() {
+                    ensure_path_resolves(
+                        py,
+                        &module,
+                        &registry,
+                        &data,
+                        path,
+                        tokens,
+                        tokens.len() - 1,
+                    )?;
+                }
+            }
         }
 
         let write_options = WriteOptions {
             create_missing,
             create_filter_match,
-            overwrite_incompatible,
+            overwrite_scalar: overwrite_scalar.unwrap_or(overwrite_incompatible),
+            overwrite_container: overwrite_container.unwrap_or(overwrite_incompatible),
+            skip_unchanged: false,
+            condition: None,
         };
         let root_data = data.clone_ref(py);
-        let _ = set_recurse(
-            py,
-            &module,
-            &registry,
-            data.clone_ref(py),
-            &tokens,
-            &value,
-            write_options,
-            &root_data,
-        )?;
+        for (_, tokens) in &parsed_paths {
+            let _ = set_recurse(
+                py,
+                &module,
+                &registry,
+                data.clone_ref(py),
+                tokens,
+                &value,
+                write_options,
+                &root_data,
+                &WriteTrace::root(None),
+            )?;
+        }
 
         Ok(data)
     }
 
-    #[pyo3(signature = (data, path, *, strict=false))]
+    #[pyo3(signature = (data, path, *, strict=false, dialect="dictwalk", params=None))]
     fn unset(
         &self,
         py: Python<'_>,
         data: PyObject,
         path: &str,
         strict: bool,
+        dialect: &str,
+        params: Option<Bound<'_, PyDict>>,
     ) -> PyResult<PyObject> {
+        let json_pointer = is_json_pointer_dialect(dialect)?;
+        let path = expand_path_params(py, path, params.as_ref(), json_pointer)?;
+        let path = path.as_str();
         let module = py.import_bound("dictwalk.dictwalk")?;
         let registry = load_registry(py)?;
-        let tokens = parse_path(py, &module, &registry, path)?;
-
-        validate_write_path_root_selector(py, path, &tokens)?;
-
-        if path_uses_bare_root_token(&tokens) {
-            return Err(make_parse_error(
-                py,
-                path,
-                Some("$$root"),
-                "The '$$root' token is only supported in read paths.",
-            ));
-        }
+        let tokens = if json_pointer {
+            parse_json_pointer_write_path(py, path, &data)?
+        } else {
+            parse_unset_path(py, &module, &registry, path)?
+        };
 
         if strict && !tokens.is_empty() {
             ensure_path_resolves(py, &module, &registry, &data, path, &tokens, tokens.len())?;
@@ -5026,15 +10340,233 @@ impl RustDictWalk {
     }
 }
 
+/// Required-path checker: paths are parsed once at construction and reused
+/// for every document passed to `check`/`missing`.
+#[pyclass(name = "Validator")]
+struct RustValidator {
+    paths: Vec<(String, Vec<ParsedToken>)>,
+    lenient_compare: bool,
+    nonempty_wildcards: bool,
+}
+
+impl RustValidator {
+    fn check_paths(&self, py: Python<'_>, data: &PyObject) -> PyResult<Vec<(&str, bool)>> {
+        let _lenient_compare = LenientCompareGuard::new(self.lenient_compare);
+        let module = py.import_bound("dictwalk.dictwalk")?;
+        let registry = load_registry(py)?;
+        self.paths
+            .iter()
+            .map(|(path, tokens)| {
+                path_tokens_exist(
+                    py,
+                    &module,
+                    &registry,
+                    data,
+                    path,
+                    tokens,
+                    false,
+                    self.nonempty_wildcards,
+                )
+                .map(|found| (path.as_str(), found))
+            })
+            .collect()
+    }
+}
+
+#[pymethods]
+impl RustValidator {
+    #[new]
+    #[pyo3(signature = (paths, *, lenient_compare=false, nonempty_wildcards=true))]
+    fn new(
+        py: Python<'_>,
+        paths: Vec<String>,
+        lenient_compare: bool,
+        nonempty_wildcards: bool,
+    ) -> PyResult<Self> {
+        let module = py.import_bound("dictwalk.dictwalk")?;
+        let registry = load_registry(py)?;
+        let mut parsed = Vec::with_capacity(paths.len());
+        for path in paths {
+            let tokens = parse_path(py, &module, &registry, &path)?;
+            validate_read_path_root_token(py, &path, &tokens)?;
+            parsed.push((path, tokens));
+        }
+        Ok(Self {
+            paths: parsed,
+            lenient_compare,
+            nonempty_wildcards,
+        })
+    }
+
+    #[getter]
+    fn paths(&self) -> Vec<String> {
+        self.paths.iter().map(|(path, _)| path.clone()).collect()
+    }
+
+    fn check(&self, py: Python<'_>, data: PyObject) -> PyResult<PyObject> {
+        let out = PyDict::new_bound(py);
+        for (path, found) in self.check_paths(py, &data)? {
+            out.set_item(path, found)?;
+        }
+        Ok(out.into())
+    }
+
+    fn missing(&self, py: Python<'_>, data: PyObject) -> PyResult<Vec<String>> {
+        Ok(self
+            .check_paths(py, &data)?
+            .into_iter()
+            .filter(|(_, found)| !found)
+            .map(|(path, _)| path.to_string())
+            .collect())
+    }
+}
+
 #[pyfunction]
 fn backend_name() -> &'static str {
     "rust"
 }
 
+#[pyfunction]
+#[pyo3(signature = (*, max_path_tokens=None, max_result_size=None, max_recursion_depth=None))]
+fn set_limits(
+    max_path_tokens: Option<usize>,
+    max_result_size: Option<usize>,
+    max_recursion_depth: Option<usize>,
+) -> PyResult<()> {
+    let updates = [
+        ("max_path_tokens", max_path_tokens, &MAX_PATH_TOKENS),
+        ("max_result_size", max_result_size, &MAX_RESULT_SIZE),
+        (
+            "max_recursion_depth",
+            max_recursion_depth,
+            &MAX_RECURSION_DEPTH,
+        ),
+    ];
+    if let Some((name, _, _)) = updates.iter().find(|(_, value, _)| *value == Some(0)) {
+        return Err(PyValueError::new_err(format!(
+            "{name} must be greater than 0."
+        )));
+    }
+    for (_, value, limit) in updates {
+        if let Some(value) = value {
+            limit.store(value, AtomicOrdering::Relaxed);
+        }
+    }
+    Ok(())
+}
+
+#[pyfunction]
+fn get_limits(py: Python<'_>) -> PyResult<PyObject> {
+    let out = PyDict::new_bound(py);
+    out.set_item(
+        "max_path_tokens",
+        MAX_PATH_TOKENS.load(AtomicOrdering::Relaxed),
+    )?;
+    out.set_item(
+        "max_result_size",
+        MAX_RESULT_SIZE.load(AtomicOrdering::Relaxed),
+    )?;
+    out.set_item(
+        "max_recursion_depth",
+        MAX_RECURSION_DEPTH.load(AtomicOrdering::Relaxed),
+    )?;
+    Ok(out.into())
+}
+
+#[pyfunction]
+#[pyo3(signature = (*, none_safe_strings=None))]
+fn set_options(none_safe_strings: Option<bool>) {
+    if let Some(enabled) = none_safe_strings {
+        NONE_SAFE_STRINGS.store(enabled, AtomicOrdering::Relaxed);
+    }
+}
+
+#[pyfunction]
+fn get_options(py: Python<'_>) -> PyResult<PyObject> {
+    let out = PyDict::new_bound(py);
+    out.set_item(
+        "none_safe_strings",
+        NONE_SAFE_STRINGS.load(AtomicOrdering::Relaxed),
+    )?;
+    Ok(out.into())
+}
+
+fn registered_soft_error_types(py: Python<'_>) -> Vec<Py<PyType>> {
+    let registered = SOFT_ERROR_TYPES
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    registered
+        .iter()
+        .map(|exc_type| exc_type.clone_ref(py))
+        .collect()
+}
+
+#[pyfunction]
+fn add_soft_error(py: Python<'_>, exc_type: Bound<'_, PyType>) -> PyResult<()> {
+    if !exc_type.is_subclass(&py.get_type_bound::<pyo3::exceptions::PyException>())? {
+        return Err(PyTypeError::new_err(format!(
+            "add_soft_error() expects an Exception subclass, got {}.",
+            exc_type.repr()?
+        )));
+    }
+    let mut registered = SOFT_ERROR_TYPES
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    if !registered.iter().any(|known| known.bind(py).is(&exc_type)) {
+        registered.push(exc_type.unbind());
+    }
+    Ok(())
+}
+
+#[pyfunction]
+fn remove_soft_error(py: Python<'_>, exc_type: Bound<'_, PyType>) -> bool {
+    let mut registered = SOFT_ERROR_TYPES
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    let before = registered.len();
+    registered.retain(|known| !known.bind(py).is(&exc_type));
+    registered.len() != before
+}
+
+#[pyfunction]
+fn soft_errors(py: Python<'_>) -> PyObject {
+    PyTuple::new_bound(py, registered_soft_error_types(py)).into()
+}
+
+#[pyfunction]
+fn capabilities(py: Python<'_>) -> PyResult<PyObject> {
+    let operators = PyDict::new_bound(py);
+    operators.set_item("comparison", COMPARISON_OPERATORS.to_vec())?;
+    operators.set_item("logical", LOGICAL_OPERATORS.to_vec())?;
+
+    let out = PyDict::new_bound(py);
+    out.set_item("backend", backend_name())?;
+    out.set_item("dialects", PATH_DIALECTS.to_vec())?;
+    out.set_item("operators", operators)?;
+    out.set_item("builtins", BUILTIN_FILTER_NAMES.to_vec())?;
+    out.set_item("builtin_count", BUILTIN_FILTER_NAMES.len())?;
+    // The Rust backend has no custom-filter registry; `register_path_filter`
+    // always raises, so there are never registered names to report.
+    out.set_item("custom_filters", false)?;
+    out.set_item("custom_filter_names", PyList::empty_bound(py))?;
+    out.set_item("limits", get_limits(py)?)?;
+    out.set_item("options", get_options(py)?)?;
+    Ok(out.into())
+}
+
 #[pymodule]
 fn _dictwalk_rs(py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<RustDictWalk>()?;
+    module.add_class::<RustValidator>()?;
     module.add_function(wrap_pyfunction!(backend_name, module)?)?;
+    module.add_function(wrap_pyfunction!(set_limits, module)?)?;
+    module.add_function(wrap_pyfunction!(get_limits, module)?)?;
+    module.add_function(wrap_pyfunction!(set_options, module)?)?;
+    module.add_function(wrap_pyfunction!(get_options, module)?)?;
+    module.add_function(wrap_pyfunction!(capabilities, module)?)?;
+    module.add_function(wrap_pyfunction!(add_soft_error, module)?)?;
+    module.add_function(wrap_pyfunction!(remove_soft_error, module)?)?;
+    module.add_function(wrap_pyfunction!(soft_errors, module)?)?;
     let dictwalk = Py::new(py, RustDictWalk::new())?;
     module.add("dictwalk", dictwalk)?;
     Ok(())
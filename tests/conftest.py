@@ -40,6 +40,15 @@ def backend_name() -> str:
     return "rust"
 
 
+@pytest.fixture
+def restore_limits() -> Iterator[None]:
+    from dictwalk import get_limits, set_limits
+
+    original = get_limits()
+    yield
+    set_limits(**original)
+
+
 @pytest.fixture(autouse=True)
 def _inject_backend_dictwalk(
     request: pytest.FixtureRequest, backend: Any
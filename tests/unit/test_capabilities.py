@@ -0,0 +1,31 @@
+import pytest
+
+from dictwalk import capabilities, dictwalk, get_limits, set_limits
+
+
+def test_capabilities_describes_backend_features():
+    caps = capabilities()
+
+    assert caps["backend"] == "rust"
+    assert caps["dialects"] == ["dictwalk", "jsonpointer"]
+    assert "==" in caps["operators"]["comparison"]
+    assert caps["operators"]["logical"] == ["&&", "||", "!", "??", "and", "or", "not"]
+    assert caps["custom_filters"] is False
+    assert caps["custom_filter_names"] == []
+
+
+def test_capabilities_lists_builtins_without_prefix():
+    caps = capabilities()
+
+    assert caps["builtin_count"] == len(caps["builtins"])
+    assert caps["builtins"] == sorted(set(caps["builtins"]))
+    assert {"inc", "round_sig", "to_ordered", "within"} <= set(caps["builtins"])
+    for name in ["len", "sum", "tally", "unique"]:
+        dictwalk.run_filter_function(f"${name}", [1, 2])
+
+
+def test_capabilities_reflects_runtime_limits(restore_limits):
+    set_limits(max_path_tokens=42)
+
+    assert capabilities()["limits"]["max_path_tokens"] == 42
+    assert capabilities()["limits"] == get_limits()
@@ -1,7 +1,17 @@
+from collections.abc import Mapping
+
 import pytest
-from dictwalk import dictwalk
+from dictwalk import (
+    add_soft_error,
+    dictwalk,
+    get_limits,
+    remove_soft_error,
+    set_limits,
+    soft_errors,
+)
 from dictwalk.errors import (
     DictWalkError,
+    DictWalkLimitError,
     DictWalkResolutionError,
     DictWalkParseError,
 )
@@ -100,3 +110,146 @@ def test_register_path_filter_raises_when_custom_filter_registration_is_attempte
 def test_get_path_filter_raises_when_accessing_filter_objects():
     with pytest.raises(DictWalkError):
         dictwalk.get_path_filter("double")
+
+
+def test_limits_have_generous_defaults():
+    limits = get_limits()
+
+    assert limits["max_path_tokens"] >= 1000
+    assert limits["max_result_size"] >= 1_000_000
+    assert limits["max_recursion_depth"] >= 1000
+
+
+def test_limits_raise_for_too_many_path_tokens(restore_limits):
+    set_limits(max_path_tokens=3)
+
+    assert dictwalk.get({"a": {"b": {"c": 1}}}, "a.b.c") == 1
+    with pytest.raises(DictWalkLimitError):
+        dictwalk.get({}, "a.b.c.d")
+
+
+def test_limits_raise_for_large_deep_wildcard_results(restore_limits):
+    set_limits(max_result_size=5)
+    data = {"a": [{"b": 1}, {"b": 2}, {"b": 3}]}
+
+    with pytest.raises(DictWalkLimitError):
+        dictwalk.get(data, "**.**")
+
+
+def test_limits_raise_for_deep_recursion(restore_limits):
+    set_limits(max_recursion_depth=2)
+    data = {"a": {"b": {"c": {"d": 1}}}}
+
+    with pytest.raises(DictWalkLimitError):
+        dictwalk.get(data, "**")
+    with pytest.raises(DictWalkLimitError):
+        dictwalk.set(data, "**.d", 2)
+    with pytest.raises(DictWalkLimitError):
+        dictwalk.unset(data, "**.d")
+
+
+def test_limits_bound_every_recursive_walk_on_cyclic_input():
+    cyclic_dict = {"b": 1}
+    cyclic_dict["a"] = cyclic_dict
+    cyclic_list = [None]
+    cyclic_list.append(cyclic_list)
+    cyclic_schema = {"x": "int"}
+    cyclic_schema["y"] = cyclic_schema
+
+    builtins = [
+        ("$map_recursive($strip)", cyclic_dict),
+        ("$sort_keys_recursive", cyclic_dict),
+        ("$prune_nulls", cyclic_dict),
+        ("$prune_nulls(True)", cyclic_dict),
+        ("$flatten_keys", cyclic_dict),
+        ("$json_path('$..b')", cyclic_dict),
+        ("$flatten_deep", cyclic_list),
+        ("$coalesce_flat", cyclic_list),
+    ]
+    for name, value in builtins:
+        with pytest.raises(DictWalkLimitError):
+            dictwalk.run_filter_function(name, value)
+
+    calls = [
+        lambda: dictwalk.get(cyclic_dict, "**"),
+        lambda: dictwalk.get({"x": cyclic_list}, "x", normalize_tuples=True),
+        lambda: dictwalk.get_typed({"a": {}}, "a", cyclic_schema),
+        lambda: dictwalk.exists(cyclic_dict, "**.zz"),
+        lambda: dictwalk.match_count(cyclic_dict, "**"),
+        lambda: dictwalk.for_each(cyclic_dict, "**", lambda value: None),
+        lambda: dictwalk.first_match_with_path(cyclic_dict, "**.zz"),
+        lambda: dictwalk.set(cyclic_dict, "**.zz", 1),
+        lambda: dictwalk.unset(cyclic_dict, "**.zz"),
+    ]
+    for call in calls:
+        with pytest.raises(DictWalkLimitError):
+            call()
+
+
+def test_exists_with_final_deep_wildcard_does_not_collect_descendants(restore_limits):
+    set_limits(max_result_size=1, max_recursion_depth=1)
+    data = {"a": {"b": {"c": {"d": 1}}}}
+
+    assert dictwalk.exists(data, "a.**") is True
+    assert dictwalk.exists(data, "a.b.*") is True
+    assert dictwalk.exists(data, "a.b.c.d.**") is False
+
+
+def test_limits_reject_non_positive_values():
+    with pytest.raises(ValueError):
+        set_limits(max_path_tokens=0)
+
+
+class LookupFailed(Exception):
+    pass
+
+
+class FailingMapping(Mapping):
+    def __getitem__(self, key):
+        raise LookupFailed(key)
+
+    def __iter__(self):
+        return iter(["a"])
+
+    def __len__(self):
+        return 1
+
+
+@pytest.fixture
+def restore_soft_errors():
+    yield
+    for exc_type in soft_errors():
+        remove_soft_error(exc_type)
+
+
+def test_unregistered_errors_propagate_in_non_strict_mode():
+    with pytest.raises(LookupFailed):
+        dictwalk.get({"m": FailingMapping()}, "m.a", "fallback")
+
+
+def test_add_soft_error_treats_registered_types_as_misses(restore_soft_errors):
+    data = {"m": FailingMapping()}
+    add_soft_error(LookupFailed)
+    add_soft_error(LookupFailed)
+
+    assert soft_errors() == (LookupFailed,)
+    assert dictwalk.get(data, "m.a", "fallback") == "fallback"
+    assert dictwalk.exists(data, "m.a") is False
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.get(data, "m.a", strict=True)
+
+
+def test_remove_soft_error_restores_propagation(restore_soft_errors):
+    add_soft_error(LookupFailed)
+
+    assert remove_soft_error(LookupFailed) is True
+    assert remove_soft_error(LookupFailed) is False
+    with pytest.raises(LookupFailed):
+        dictwalk.get({"m": FailingMapping()}, "m.a")
+
+
+def test_add_soft_error_rejects_non_exception_types():
+    with pytest.raises(TypeError):
+        add_soft_error(int)
+    with pytest.raises(TypeError):
+        add_soft_error(KeyboardInterrupt)
@@ -1,3 +1,6 @@
+from decimal import Decimal
+from types import MappingProxyType
+
 import pytest
 from dictwalk import dictwalk
 from dictwalk.errors import DictWalkParseError, DictWalkResolutionError
@@ -116,6 +119,38 @@ def test_get__list_map_values_with_output_transform():
     assert dictwalk.get(data, path, default=default) == expected
 
 
+def test_get__output_transform_coalesce_paths_picks_first_resolvable_root_path():
+    data = {"a": 1, "fallback": {"name": "x"}, "empty": None}
+    path = "a|$coalesce_paths($$root.missing, $$root.empty, $$root.fallback.name)"
+
+    assert dictwalk.get(data, path) == "x"
+
+
+def test_get__output_transform_coalesce_paths_returns_none_when_nothing_resolves():
+    data = {"a": 1}
+
+    assert dictwalk.get(data, "a|$coalesce_paths($$root.x, $$root.y)") is None
+
+
+def test_get__output_transform_deep_get_descends_into_current_value():
+    data = {"records": [{"user": {"city": "Cork"}}, {"user": {}}]}
+
+    assert dictwalk.get(data, "records|$deep_get('user.city')[]") == ["Cork", None]
+
+
+def test_get__output_transform_runs_on_whole_list_by_default():
+    data = {"users": {"a": {"name": "x"}, "b": {"name": "y"}}}
+
+    assert dictwalk.get(data, "users.*.name|$len") == 2
+
+
+def test_get__map_transform_applies_output_transform_to_each_element():
+    data = {"users": {"a": {"name": "ann"}, "b": {"name": "bo"}}}
+
+    assert dictwalk.get(data, "users.*.name|$upper|$len", map_transform=True) == [3, 2]
+    assert dictwalk.get(data, "users.a.name|$upper", map_transform=True) == "ANN"
+
+
 def test_get__filter_and_map():
     data = {"a": {"b": [{"id": "1", "c": 10}, {"id": "2", "c": 20}]}}
     path = "a.b[?.id==1].c[]"
@@ -246,6 +281,54 @@ def test_get__filter_and_map_using_boolean_not():
     assert dictwalk.get(data, path, default=default) == expected
 
 
+def test_get__filter_and_map_using_word_boolean_operators():
+    data = {
+        "a": {
+            "b": [
+                {"id": 1, "c": 10},
+                {"id": 2, "c": 20},
+                {"id": 3, "c": 30},
+                {"id": 4, "c": 40},
+            ]
+        }
+    }
+
+    assert dictwalk.get(data, "a.b[?.id==$gt(1) and $lt(4)].c[]") == [20, 30]
+    assert dictwalk.get(data, "a.b[?.id==$lt(2) or $gt(3)].c[]") == [10, 40]
+    assert dictwalk.get(data, "a.b[?.id==not $even].c[]") == [10, 30]
+    assert dictwalk.get(data, "a.b[?.id==($lt(2) || $gt(3)) and not $odd].c[]") == [
+        40
+    ]
+    assert dictwalk.get(data, "a.b[?.id>1 and .c<40 || .id==1].c[]") == [10, 20, 30]
+
+
+def test_get__word_boolean_operators_leave_fields_and_literals_alone():
+    data = {
+        "items": [
+            {"order": 1, "android": True, "note": "salt and pepper"},
+            {"order": 2, "android": False, "note": "plain"},
+        ]
+    }
+
+    assert dictwalk.get(data, "items[?.order==1 and .android==True].note[]") == [
+        "salt and pepper"
+    ]
+    assert dictwalk.get(data, "items[?.note==salt and pepper].order[]") == [1]
+    assert dictwalk.get(data, "items[?.note=='plain' or .order==1].order[]") == [1, 2]
+
+
+def test_get__word_operator_before_negated_field_raises_like_symbols():
+    data = {"u": [{"a": 0}, {"a": 1}]}
+
+    for path in [
+        "u[?.a==0 && !.a==1]",
+        "u[?.a==0 and not .a==1]",
+        "u[?.a==0 and not (.a==1)]",
+    ]:
+        with pytest.raises(DictWalkParseError, match="Invalid path filter token '0'"):
+            dictwalk.get(data, path)
+
+
 def test_get__filter_and_map_using_boolean_grouping():
     data = {
         "a": {
@@ -264,6 +347,160 @@ def test_get__filter_and_map_using_boolean_grouping():
     assert dictwalk.get(data, path, default=default) == expected
 
 
+def test_get__filter_field_transform_with_null_coalescing():
+    data = {
+        "a": {
+            "b": [
+                {"x": 0, "y": 5, "c": 10},
+                {"y": 0, "c": 20},
+                {"c": 30},
+            ]
+        }
+    }
+
+    assert dictwalk.get(data, "a.b[?.|$deep_get('x') ?? $deep_get('y')==0].c[]") == [10, 20]
+    assert dictwalk.get(data, "a.b[?.|$deep_get('x') ?? $deep_get('y')].c[]") == []
+    assert dictwalk.get(data, "a.b[?.|$deep_get('x') ?? $deep_get('c')>20].c[]") == [30]
+
+
+def test_get__filter_with_negated_sign_predicate():
+    data = {
+        "accounts": [{"id": 1, "bal": -5}, {"id": 2, "bal": 0}, {"id": 3, "bal": 7}]
+    }
+
+    assert dictwalk.get(data, "accounts[?.bal==!$negative].id[]") == [2, 3]
+    assert dictwalk.get(data, "accounts[?.bal==$nonzero].id[]") == [1, 3]
+
+
+def test_get__filter_predicate_with_null_coalescing():
+    data = {"a": {"b": [{"id": 1, "c": 10}, {"id": 2, "c": 20}]}}
+    path = "a.b[?.id==$deep_get('missing') ?? $even].c[]"
+
+    assert dictwalk.get(data, path) == [20]
+
+
+def test_get__filter_by_relative_error_against_root_reference():
+    data = {"target": 100, "readings": [99.5, 103, 100.9, 80]}
+    path = "readings[?.|$rel_error($$root.target)|$lte(0.01)]"
+
+    assert dictwalk.get(data, path) == [99.5, 100.9]
+
+
+def test_get__filter_predicate_value_with_root_reference():
+    data = {"min": 2, "items": [{"s": 1, "c": 10}, {"s": 3, "c": 30}]}
+
+    assert dictwalk.get(data, "items[?.s==$gt($$root.min)].c[]") == [30]
+
+
+def test_get__filter_and_map_using_chained_field_comparisons():
+    data = {
+        "a": {
+            "b": [
+                {"age": 10, "c": 10},
+                {"age": 30, "c": 20},
+                {"age": 70, "c": 30},
+            ]
+        }
+    }
+    path = "a.b[?.age>=18 && .age<65].c[]"
+    default = None
+    expected = [20]
+
+    assert dictwalk.get(data, path, default=default) == expected
+
+
+def test_get__filter_and_map_using_chained_field_comparisons_with_or():
+    data = {
+        "a": {
+            "b": [
+                {"id": 1, "kind": "x", "c": 10},
+                {"id": 2, "kind": "y", "c": 20},
+                {"id": 3, "kind": "z", "c": 30},
+            ]
+        }
+    }
+    path = "a.b[?.kind==x || .id>2].c[]"
+    default = None
+    expected = [10, 30]
+
+    assert dictwalk.get(data, path, default=default) == expected
+
+
+def test_get__chained_field_comparisons_bind_and_tighter_than_or():
+    data = {
+        "a": {
+            "b": [
+                {"id": 1, "kind": "x", "c": 10},
+                {"id": 2, "kind": "y", "c": 20},
+                {"id": 3, "kind": "y", "c": 30},
+            ]
+        }
+    }
+    path = "a.b[?.id==1 || .kind==y && .id>2].c[]"
+    default = None
+    expected = [10, 30]
+
+    assert dictwalk.get(data, path, default=default) == expected
+
+
+def test_get__chained_field_comparisons_mix_with_value_predicates():
+    data = {
+        "a": {
+            "b": [
+                {"id": 1, "kind": "x", "c": 10},
+                {"id": 2, "kind": "x", "c": 20},
+                {"id": 3, "kind": "y", "c": 30},
+                {"id": 4, "kind": "x", "c": 40},
+            ]
+        }
+    }
+    path = "a.b[?.id==$gt(1)&&$lt(4) && .kind==x].c[]"
+    default = None
+    expected = [20]
+
+    assert dictwalk.get(data, path, default=default) == expected
+
+
+def test_get__chained_field_comparisons_with_parenthesized_grouping():
+    data = {
+        "a": {
+            "b": [
+                {"id": 1, "score": 0, "c": 10},
+                {"id": 2, "score": 5, "c": 20},
+                {"id": 3, "score": 5, "c": 30},
+                {"id": 1, "score": 7, "c": 40},
+            ]
+        }
+    }
+    path = "a.b[?(.id==1 || .id==2) && .score>0].c[]"
+    default = None
+    expected = [20, 40]
+
+    assert dictwalk.get(data, path, default=default) == expected
+
+
+def test_get__chained_field_comparisons_with_nested_grouping_and_value_predicates():
+    data = {
+        "a": {
+            "b": [
+                {"id": 1, "score": 0, "c": 10},
+                {"id": 2, "score": 5, "c": 20},
+                {"id": 4, "score": 5, "c": 30},
+            ]
+        }
+    }
+    path = "a.b[?((.id==($lt(2)||$gt(3))) || .score==0) && .c>10].c[]"
+    default = None
+    expected = [30]
+
+    assert dictwalk.get(data, path, default=default) == expected
+
+
+def test_get__raises_parse_error_for_unbalanced_filter_grouping():
+    with pytest.raises(DictWalkParseError):
+        dictwalk.get({"a": []}, "a[?(.id==1 || .id==2]")
+
+
 def test_get__get_path_value_with_list_index():
     data = {"a": {"b": [{"c": 10}, {"c": 20}, {"c": 30}]}}
     path = "a.b[0].c"
@@ -733,7 +970,32 @@ def test_get__filter_path_exists_even_when_operator_filter_has_no_matches():
     path = "a.b[?.id<1]"
     expected = True
 
-    assert dictwalk.exists(data, path) is expected
+    assert dictwalk.exists(data, path, nonempty_wildcards=False) is expected
+
+
+def test_get__path_exists_requires_a_match_for_final_projection():
+    data = {"a": {"b": [{"id": 1}, {"id": 2}], "empty": {}, "none": []}}
+
+    assert dictwalk.exists(data, "a.b[?.id<1]") is False
+    assert dictwalk.exists(data, "a.b[?.id==2]") is True
+    assert dictwalk.exists(data, "a.empty.*") is False
+    assert dictwalk.exists(data, "a.empty.**") is False
+    assert dictwalk.exists(data, "a.b.*") is True
+    assert dictwalk.exists(data, "a.none.id[]") is False
+    assert dictwalk.exists(data, "a.b.id[]") is True
+    assert dictwalk.exists([], ".[]") is False
+    assert dictwalk.exists([], "$$root[?.id==1]") is False
+    assert dictwalk.exists(data, "a.none") is True
+
+
+def test_get__path_exists_requires_a_match_after_an_earlier_projection():
+    data = {"u": {"a": {}}, "users": [{"active": False, "name": "x"}]}
+
+    assert dictwalk.exists(data, "u.**.zz") is False
+    assert dictwalk.exists(data, "users[?.active==True].name") is False
+    assert dictwalk.exists(data, "users[?.active==False].name") is True
+    assert dictwalk.exists(data, "users.*.name") is True
+    assert dictwalk.exists(data, "u.**.zz", nonempty_wildcards=False) is True
 
 
 def test_get__filter_path_exists_using_boolean_and():
@@ -870,3 +1132,632 @@ def test_get__nested_predicate_with_mapped_output():
     default = None
     expected = [[10]]
     assert dictwalk.get(data, path, default=default) == expected
+
+
+def test_get__first_match_with_path_returns_filter_match_position():
+    data = {"a": {"users": [{"id": 1}, {"id": 2}, {"id": 2, "x": 1}]}}
+    path = "a.users[?.id==2]"
+    expected = ("a.users[1]", {"id": 2})
+
+    assert dictwalk.first_match_with_path(data, path) == expected
+
+
+def test_get__first_match_with_path_through_wildcards_and_filter_map():
+    data = {"a": {"g1": {"items": [{"v": 1}]}, "g2": {"items": [{"v": 2}]}}}
+
+    assert dictwalk.first_match_with_path(data, "a.*.items") == (
+        "a.g1.items",
+        [{"v": 1}],
+    )
+    assert dictwalk.first_match_with_path(data, "a.g2.items[?.v>0].v[]") == (
+        "a.g2.items[0].v",
+        2,
+    )
+    assert dictwalk.first_match_with_path(data, "a.**.v") == ("a.g1.items[0].v", 1)
+
+
+def test_get__first_match_with_path_for_index_slice_and_root_selectors():
+    data = [{"b": [10, 20, 30]}, {"b": [40]}]
+
+    assert dictwalk.first_match_with_path(data, ".[-1].b[0]") == (".[1].b[0]", 40)
+    assert dictwalk.first_match_with_path(data, ".[0].b[1:]") == (".[0].b[1]", 20)
+    assert dictwalk.first_match_with_path(data, ".") == (".", data)
+
+
+def test_get__first_match_with_path_can_write_back_to_match():
+    data = {"a": {"users": [{"id": 1}, {"id": 2}, {"id": 2}]}}
+
+    path, _ = dictwalk.first_match_with_path(data, "a.users[?.id==2]")
+    dictwalk.set(data, f"{path}.seen", True)
+
+    assert data == {"a": {"users": [{"id": 1}, {"id": 2, "seen": True}, {"id": 2}]}}
+
+
+def test_get__first_match_with_path_falls_back_to_json_pointer_for_unsafe_keys():
+    data = {"a": {"x.y": {"z": 1}}}
+
+    path, value = dictwalk.first_match_with_path(data, "a.*.z")
+    assert (path, value) == ("/a/x.y/z", 1)
+    assert dictwalk.get(data, path, dialect="jsonpointer") == 1
+    nested = {"b/c~[0]": {"k": [{"z": 2}]}}
+    path, _ = dictwalk.first_match_with_path(nested, "**.z")
+    assert path == "/b~1c~0[0]/k/0/z"
+    assert dictwalk.get(nested, path, dialect="jsonpointer") == 2
+    assert dictwalk.first_match_with_path({"a": {"1": 5}}, "a.*") == ("a.1", 5)
+
+
+def test_get__first_match_with_path_is_none_below_non_string_keys():
+    data = {"a": {1: {"b": 2}}}
+
+    assert dictwalk.first_match_with_path(data, "a.*.b") == (None, 2)
+
+
+def test_get__first_match_with_path_returns_none_without_matches():
+    data = {"a": {"users": [{"id": 1}]}}
+
+    assert dictwalk.first_match_with_path(data, "a.users[?.id==2]") is None
+    assert dictwalk.first_match_with_path(data, "a.missing") is None
+
+
+def test_get__first_match_with_path_strict_raises_resolution_error():
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.first_match_with_path({"a": {}}, "a.missing", strict=True)
+
+
+def test_get__first_match_with_path_rejects_output_transforms():
+    with pytest.raises(DictWalkParseError):
+        dictwalk.first_match_with_path({"a": 1}, "a|$inc")
+
+
+def test_get__match_count_for_multi_value_paths():
+    data = {"a": {"users": [{"id": 1}, {"id": 2}, {"id": 3}], "meta": {"x": 1, "y": 2}}}
+
+    assert dictwalk.match_count(data, "a.users[?.id>1]") == 2
+    assert dictwalk.match_count(data, "a.meta.*") == 2
+    assert dictwalk.match_count(data, "a.users[:2]") == 2
+    assert dictwalk.match_count(data, "a.users[?.id>5]") == 0
+
+
+def test_get__match_count_for_scalar_paths():
+    data = {"a": {"b": None}}
+
+    assert dictwalk.match_count(data, "a.b") == 1
+    assert dictwalk.match_count(data, "a.c") == 0
+
+
+def test_get__match_count_out_of_range_index_is_a_miss():
+    data = {"a": [{"b": 1}]}
+
+    assert dictwalk.match_count(data, "a[5]") == 0
+    assert dictwalk.match_count(data, "a[-2].b") == 0
+    assert dictwalk.match_count(data, "a[0].b") == 1
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.match_count(data, "a[5]", strict=True)
+
+
+def test_get__match_count_strict_raises_resolution_error():
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.match_count({"a": {}}, "a.c", strict=True)
+
+
+def test_get__index_from_root_reference():
+    data = {"target_index": -1, "items": [{"v": 1}, {"v": 2}]}
+    path = "items[$$root.target_index].v"
+
+    assert dictwalk.get(data, path) == 2
+
+
+class _ExplodingCompare:
+    def __eq__(self, other):
+        raise ValueError("cannot compare")
+
+    def __gt__(self, other):
+        raise ValueError("cannot compare")
+
+    __hash__ = None
+
+
+def test_get__filter_comparison_errors_propagate_by_default():
+    data = {"items": [{"v": _ExplodingCompare()}, {"v": 2}]}
+
+    with pytest.raises(ValueError):
+        dictwalk.get(data, "items[?.v>1]")
+
+
+def test_get__lenient_compare_treats_comparison_errors_as_non_matches():
+    data = {"items": [{"v": _ExplodingCompare()}, {"v": 2}]}
+
+    assert dictwalk.get(data, "items[?.v>1]", lenient_compare=True) == [{"v": 2}]
+    assert dictwalk.get(data, "items[?.v==2]", lenient_compare=True) == [{"v": 2}]
+    assert dictwalk.match_count(data, "items[?.v>1]", lenient_compare=True) == 1
+    assert dictwalk.exists(data, "items[?.v>1]", lenient_compare=True) is True
+
+
+class _InterruptingCompare:
+    def __gt__(self, other):
+        raise KeyboardInterrupt
+
+    def __eq__(self, other):
+        raise ZeroDivisionError("not a comparison failure")
+
+    __hash__ = None
+
+
+def test_get__lenient_compare_only_swallows_type_and_value_errors():
+    data = {"items": [{"v": _InterruptingCompare()}]}
+
+    with pytest.raises(KeyboardInterrupt):
+        dictwalk.get(data, "items[?.v>1]", lenient_compare=True)
+    with pytest.raises(ZeroDivisionError):
+        dictwalk.get(data, "items[?.v==2]", lenient_compare=True)
+
+
+def test_get__returns_references_into_data_by_default():
+    data = {"a": {"b": [1, 2]}}
+
+    dictwalk.get(data, "a.b").append(3)
+
+    assert data == {"a": {"b": [1, 2, 3]}}
+
+
+def test_get__copy_returns_deep_copy_of_resolved_value():
+    data = {"a": {"b": [{"c": 1}]}}
+
+    result = dictwalk.get(data, "a", copy=True)
+    result["b"][0]["c"] = 99
+
+    assert result == {"b": [{"c": 99}]}
+    assert data == {"a": {"b": [{"c": 1}]}}
+    assert dictwalk.get(data, ".", copy=True) is not data
+
+
+def test_get__filter_quoted_values_may_contain_brackets_and_operators():
+    data = {
+        "items": [
+            {"note": "a]b", "v": 1},
+            {"note": "a[b", "v": 2},
+            {"note": "x>=y", "v": 3},
+            {"note": "p&&.q", "v": 4},
+            {"note": "it's", "v": 5},
+            {"note": "a.b|$c", "v": 6},
+        ]
+    }
+
+    assert dictwalk.get(data, "items[?.note=='a]b'].v[]") == [1]
+    assert dictwalk.get(data, "items[?.note=='a[b'].v[]") == [2]
+    assert dictwalk.get(data, "items[?.note=='x>=y'].v[]") == [3]
+    assert dictwalk.get(data, "items[?.note=='p&&.q'].v[]") == [4]
+    assert dictwalk.get(data, r"items[?.note=='it\'s'].v[]") == [5]
+    assert dictwalk.get(data, "items[?.note==\"a.b|$c\"].v[]|$sum") == 6
+
+
+def test_get__path_and_clause_splitters_agree_on_unquoted_escapes():
+    data = {"items": [{"k": "a'b", "v": 1}, {"k": "a\\'b", "v": 2}]}
+
+    assert dictwalk.get(data, r"items[?.k==a\'b].v") == [2]
+    assert dictwalk.get(data, r"items[?.k==a\'b].v|$sum") == 2
+
+
+def test_exists__final_filter_stops_at_first_match():
+    data = {"items": [{"v": 2}, {"v": _ExplodingCompare()}]}
+
+    assert dictwalk.exists(data, "items[?.v>1]") is True
+    with pytest.raises(ValueError):
+        dictwalk.get(data, "items[?.v>1]")
+
+
+def test_get__now_fixes_current_time_for_age_builtins():
+    data = {"created": "2024-01-01T00:00:00Z"}
+    now = "2024-01-03T12:00:00Z"
+
+    assert dictwalk.get(data, "created|$age_seconds", now=now) == 216_000.0
+    assert dictwalk.get(data, "created|$age_days", now=now) == 2.5
+
+
+def test_get__now_rejects_values_that_are_not_datetimes():
+    with pytest.raises(TypeError):
+        dictwalk.get({"a": 1}, "a", now=[2024])
+
+
+
+def test_get_unique__dedupes_wildcard_results_in_order():
+    data = {"a": {"x": {"tag": "red"}, "y": {"tag": "blue"}, "z": {"tag": "red"}}}
+
+    assert dictwalk.get_unique(data, "a.*.tag") == ["red", "blue"]
+
+
+def test_get_unique__handles_unhashable_values():
+    data = {"items": [{"v": {"k": 1}}, {"v": [1]}, {"v": {"k": 1}}, {"v": [1]}]}
+
+    assert dictwalk.get_unique(data, "items.v[]") == [{"k": 1}, [1]]
+
+
+def test_get_unique__unresolved_and_scalar_paths():
+    assert dictwalk.get_unique({"a": 1}, "b") == []
+    assert dictwalk.get_unique({"a": 1}, "a") == [1]
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.get_unique({"a": 1}, "b", strict=True)
+
+
+def test_get__jsonpointer_dialect():
+    data = {"a": [{"b.c": 1}, {"x/y": {"m~n": 2}}], "grid": [[1, 2], [3, 4]]}
+
+    assert dictwalk.get(data, "/a/0/b.c", dialect="jsonpointer") == 1
+    assert dictwalk.get(data, "/a/1/x~1y/m~0n", dialect="jsonpointer") == 2
+    assert dictwalk.get(data, "/grid/1/0", dialect="jsonpointer") == 3
+    assert dictwalk.get(data, "", dialect="jsonpointer") is data
+    assert dictwalk.get([{"id": 7}], "/0/id", dialect="jsonpointer") == 7
+
+
+def test_get__jsonpointer_dialect_misses():
+    data = {"a": [{"b": 1}]}
+
+    assert dictwalk.get(data, "/z/b", "n/a", dialect="jsonpointer") == "n/a"
+    assert dictwalk.exists(data, "/a/0/b", dialect="jsonpointer") is True
+    assert dictwalk.exists(data, "/a/0/z", dialect="jsonpointer") is False
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.get(data, "/a/0/z", strict=True, dialect="jsonpointer")
+    with pytest.raises(IndexError):
+        dictwalk.get(data, "/a/5/b", dialect="jsonpointer")
+
+
+def test_get__jsonpointer_dialect_numeric_segments_name_mapping_members():
+    data = {"a": {"0": "z", "1": [10, 11]}, "b": [{"2": "w"}]}
+
+    assert dictwalk.get(data, "/a/0", dialect="jsonpointer") == "z"
+    assert dictwalk.get(data, "/a/1/1", dialect="jsonpointer") == 11
+    assert dictwalk.get(data, "/b/0/2", dialect="jsonpointer") == "w"
+    assert dictwalk.exists(data, "/a/0", dialect="jsonpointer") is True
+    assert dictwalk.run_filter_function("$json_pointer('/a/0')", data) == "z"
+
+
+def test_get__jsonpointer_dialect_invalid_input():
+    with pytest.raises(DictWalkParseError):
+        dictwalk.get({"a": 1}, "a", dialect="jsonpointer")
+    with pytest.raises(DictWalkParseError):
+        dictwalk.get({"a": 1}, "/a~9", dialect="jsonpointer")
+    with pytest.raises(ValueError):
+        dictwalk.get({"a": 1}, "a", dialect="xpath")
+
+
+def test_get__returns_indices_and_items_for_filter():
+    data = {
+        "users": [
+            {"n": "a", "on": True},
+            {"n": "b", "on": False},
+            {"n": "c", "on": True},
+        ]
+    }
+
+    assert dictwalk.get(data, "users[?.on==True]", returns="indices") == [0, 2]
+    assert dictwalk.get(data, "users[?.on==True]", returns="items") == [
+        (0, {"n": "a", "on": True}),
+        (2, {"n": "c", "on": True}),
+    ]
+    assert dictwalk.get(data, "users[?.on==True]", returns="values") == [
+        {"n": "a", "on": True},
+        {"n": "c", "on": True},
+    ]
+    assert dictwalk.get(data["users"], "$$root[?.on==False]", returns="indices") == [1]
+
+
+def test_get__returns_positions_for_wildcard():
+    data = {"a": {"x": 1, "y": 2}, "b": [5, 6]}
+
+    assert dictwalk.get(data, "a.*", returns="indices") == ["x", "y"]
+    assert dictwalk.get(data, "b.*", returns="items") == [(0, 5), (1, 6)]
+
+
+def test_get__returns_rejects_unsupported_paths():
+    data = {"a": [{"id": 1}]}
+
+    with pytest.raises(ValueError, match="filter or"):
+        dictwalk.get(data, "a[0]", returns="indices")
+    with pytest.raises(ValueError, match="output transform"):
+        dictwalk.get(data, "a[?.id==1]|$len", returns="indices")
+    with pytest.raises(ValueError, match="Unsupported returns"):
+        dictwalk.get(data, "a[?.id==1]", returns="keys")
+    assert dictwalk.get(data, "missing.*", "n/a", returns="indices") == "n/a"
+
+
+def test_get__normalize_tuples_converts_nested_tuples_to_lists():
+    data = {"a": {"pairs": ((1, 2), [3, (4, 5)]), "meta": {"t": (6,)}}}
+
+    result = dictwalk.get(data, "a", normalize_tuples=True)
+
+    assert result == {"pairs": [[1, 2], [3, [4, 5]]], "meta": {"t": [6]}}
+    assert type(result["pairs"][0]) is list
+    assert type(result["meta"]["t"]) is list
+    assert data["a"]["pairs"] == ((1, 2), [3, (4, 5)])
+    assert dictwalk.get(
+        {"a": [1, 2]}, "a.*", returns="items", normalize_tuples=True
+    ) == [[0, 1], [1, 2]]
+
+
+def test_get__flatten_merges_nested_list_results():
+    data = {"users": [{"tags": ["a", "b"]}, {"tags": [["c"], "d"]}, {"tags": []}]}
+
+    assert dictwalk.get(data, "users.*.tags") == [["a", "b"], [["c"], "d"], []]
+    assert dictwalk.get(data, "users.*.tags", flatten=True) == ["a", "b", ["c"], "d"]
+    assert dictwalk.get(data, "users.*.tags", flatten=2) == ["a", "b", "c", "d"]
+    assert dictwalk.get(data, "users.*.tags", flatten=0) == [
+        ["a", "b"],
+        [["c"], "d"],
+        [],
+    ]
+    assert dictwalk.get(data, "users[0].tags[0]", flatten=True) == "a"
+
+
+def test_get__flatten_skips_default_and_rejects_invalid_depth():
+    default = [[1]]
+
+    assert dictwalk.get({}, "missing", default, flatten=True) is default
+    with pytest.raises(ValueError):
+        dictwalk.get({"a": [[1]]}, "a", flatten=-1)
+    with pytest.raises(ValueError):
+        dictwalk.get({"a": [[1]]}, "a", flatten="deep")
+
+
+def test_get__fallback_document_is_consulted_on_miss():
+    user = {"ui": {"theme": "dark", "font": None}, "tags": []}
+    defaults = {"ui": {"theme": "light", "size": 12, "font": "mono"}, "tags": ["x"]}
+
+    assert dictwalk.get(user, "ui.theme", fallback=defaults) == "dark"
+    assert dictwalk.get(user, "ui.size", fallback=defaults) == 12
+    assert dictwalk.get(user, "ui.font", fallback=defaults) is None
+    assert dictwalk.get(user, "tags", fallback=defaults) == []
+    assert dictwalk.get(user, "ui.missing", "d", fallback=defaults) == "d"
+    assert dictwalk.get(user, "ui.size|$inc", fallback=defaults) == 13
+
+
+def test_get__fallback_with_strict_raises_only_when_both_miss():
+    user = {"ui": "flat"}
+    defaults = {"ui": {"size": 12}}
+
+    assert dictwalk.get(user, "ui.size", strict=True, fallback=defaults) == 12
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.get(user, "ui.missing", strict=True, fallback=defaults)
+
+
+def test_get__filter_accepts_json_null_true_false_literals():
+    data = {
+        "items": [
+            {"id": 1, "deleted_at": None, "active": True},
+            {"id": 2, "deleted_at": "2024-01-01", "active": False},
+            {"id": 3, "deleted_at": "null", "active": "true"},
+        ]
+    }
+
+    assert dictwalk.get(data, "items[?.deleted_at==null].id[]") == [1, 3]
+    assert dictwalk.get(data, "items[?.deleted_at==None].id[]") == [1]
+    assert dictwalk.get(data, "items[?.deleted_at=='null'].id[]") == [3]
+    assert dictwalk.get(data, "items[?.deleted_at!=null].id[]") == [2]
+    assert dictwalk.get(data, "items[?.active==true].id[]") == [1, 3]
+    assert dictwalk.get(data, "items[?.active==True].id[]") == [1]
+    assert dictwalk.get(data, "items[?.active==false && .id>1].id[]") == [2]
+
+
+def test_get__filter_with_weekend_predicate():
+    data = {
+        "events": [
+            {"id": 1, "date": "2024-01-06"},
+            {"id": 2, "date": "2024-01-08"},
+            {"id": 3, "date": "2024-01-07T12:00:00Z"},
+        ]
+    }
+
+    assert dictwalk.get(data, "events[?.date==$is_weekend].id[]") == [1, 3]
+    assert dictwalk.get(data, "events[?.date==$is_weekday].id[]") == [2]
+
+
+def test_get__normalize_tuples_is_off_by_default_and_skips_default():
+    data = {"a": (1, 2)}
+    default = (0,)
+
+    assert dictwalk.get(data, "a") is data["a"]
+    assert dictwalk.get(data, "b", default, normalize_tuples=True) is default
+
+
+def test_get_typed__coerces_nested_schema():
+    data = {
+        "user": {"id": "12", "tags": ["1", "x"], "meta": {"on": "yes"}, "name": "Ada"},
+        "rows": [{"n": "1"}, {"n": None}],
+    }
+    schema = {"id": "int", "tags": ["int"], "meta": {"on": "bool"}}
+
+    assert dictwalk.get_typed(data, "user", schema) == {
+        "id": 12,
+        "tags": [1, None],
+        "meta": {"on": True},
+        "name": "Ada",
+    }
+    assert dictwalk.get_typed(data, "rows", [{"n": "int"}]) == [{"n": 1}, {"n": None}]
+    assert dictwalk.get_typed(data, "user.id", "decimal") == Decimal("12")
+    assert dictwalk.get_typed(data, "missing", "int", "n/a") == "n/a"
+    assert data["user"]["id"] == "12"
+
+
+def test_get_typed__raises_when_requested():
+    data = {"user": {"id": "12", "tags": ["1", "x"]}}
+
+    with pytest.raises(DictWalkResolutionError, match="tags\\[1\\]"):
+        dictwalk.get_typed(data, "user", {"tags": ["int"]}, on_error="raise")
+    assert dictwalk.get_typed(data, "user.tags", {"a": "int"}) is None
+    with pytest.raises(ValueError, match="Unsupported coercion type 'uuid'"):
+        dictwalk.get_typed(data, "user", {"id": "uuid"})
+    with pytest.raises(ValueError, match="Unsupported on_error"):
+        dictwalk.get_typed(data, "user", "int", on_error="ignore")
+
+
+def test_get__path_params():
+    data = {
+        "users": [{"id": "a.b", "name": "x"}, {"id": "it's", "name": "y"}],
+        "groups": {"admins": {"size": 2}},
+        "hosts": {"db/1": {"port": 5432}},
+    }
+
+    assert dictwalk.get(data, "users[{idx}].name", params={"idx": 1}) == "y"
+    assert dictwalk.get(data, "groups.{group}.size", params={"group": "admins"}) == 2
+    assert dictwalk.get(
+        data, "users[?.id=='{id}'].name[]", params={"id": "a.b"}
+    ) == ["x"]
+    assert dictwalk.get(
+        data, 'users[?.id=="{id}"].name[]', params={"id": "it's"}
+    ) == ["y"]
+    assert (
+        dictwalk.get(
+            data, "/hosts/{host}/port", params={"host": "db/1"}, dialect="jsonpointer"
+        )
+        == 5432
+    )
+    assert dictwalk.exists(data, "groups.{group}", params={"group": "admins"}) is True
+
+
+def test_get__path_params_reject_unsafe_values():
+    data = {"groups": {"admins": {"size": 2}}}
+
+    with pytest.raises(DictWalkParseError, match="contains path syntax"):
+        dictwalk.get(data, "groups.{group}.size", params={"group": "admins.size"})
+    with pytest.raises(DictWalkParseError, match="contains path syntax"):
+        dictwalk.exists(data, "groups.{group}", params={"group": "*"})
+    with pytest.raises(DictWalkParseError, match="Missing path parameter 'group'"):
+        dictwalk.get(data, "groups.{group}", params={})
+    with pytest.raises(DictWalkParseError, match="Unclosed"):
+        dictwalk.get(data, "groups.{group", params={"group": "admins"})
+
+
+def test_exists_typed__matches_type_names_and_categories():
+    data = {"a": {"port": 8080, "ratio": 0.5, "on": True, "tags": ["x"], "n": None}}
+
+    assert dictwalk.exists_typed(data, "a.port", "int") is True
+    assert dictwalk.exists_typed(data, "a.port", "Number") is True
+    assert dictwalk.exists_typed(data, "a.ratio", "number") is True
+    assert dictwalk.exists_typed(data, "a.on", "number") is False
+    assert dictwalk.exists_typed(data, "a.tags", "array") is True
+    assert dictwalk.exists_typed(data, "a", "object") is True
+    assert dictwalk.exists_typed(data, "a.n", "null") is True
+    assert dictwalk.exists_typed(data, "a.port", "string") is False
+
+
+def test_exists_typed__missing_path_is_false():
+    assert dictwalk.exists_typed({"a": 1}, "b", "null") is False
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.exists_typed({"a": 1}, "b", "int", strict=True)
+
+
+def test_get__reads_through_mapping_proxies():
+    users = [MappingProxyType({"id": 1, "name": "Ada"}), {"id": 2, "name": "Lin"}]
+    data = MappingProxyType({"a": MappingProxyType({"users": users, "n": 0})})
+
+    assert dictwalk.get(data, "a.n") == 0
+    assert dictwalk.get(data, "a.users[0].name") == "Ada"
+    assert dictwalk.get(data, "a.users.name[]") == ["Ada", "Lin"]
+    assert dictwalk.get(data, "a.users[?.id==1].name[]") == ["Ada"]
+    assert dictwalk.get(data, "a.*") == [users, 0]
+    assert "Lin" in dictwalk.get(data, "**")
+    assert dictwalk.get(data, "a.missing", "d") == "d"
+    assert dictwalk.exists(data, "a.users[?.id==2]") is True
+
+
+def test_get_or_default__reports_whether_value_was_found():
+    data = {"a": {"b": None}, "items": [{"v": 1}]}
+
+    assert dictwalk.get_or_default(data, "a.b", "n/a") == (None, True)
+    assert dictwalk.get_or_default(data, "a.c", "n/a") == ("n/a", False)
+    assert dictwalk.get_or_default(data, "items.v[]|$sum") == (1, True)
+    assert dictwalk.get_or_default(data, "a.b.c") == (None, False)
+
+
+def test_get__output_transform_root_references_in_any_position():
+    data = {"total": 10, "count": 4, "min": 2, "scores": [1, 2, 3]}
+
+    assert dictwalk.get(data, "total|$div($$root.count)") == 2.5
+    assert dictwalk.get(data, "total|$double|$sub($$root.min)|$div($$root.count)") == 4.5
+    assert dictwalk.get(data, "total|$clamp($$root.min, $$root.count)") == 4
+    assert dictwalk.get(data, "scores|$add($$root.min)[]|$max") == 5
+
+
+def test_get__output_transform_root_references_in_nested_arguments():
+    data = {"total": 10, "count": 4, "min": 2, "scores": [1, 2, 3]}
+
+    assert dictwalk.get(data, "scores|$partition($gt($$root.min))") == [[3], [1, 2]]
+    assert dictwalk.get(data, "total|$apply_if($gt($$root.min), $double)") == 20
+    assert (
+        dictwalk.get(data, "total|$apply_if($gt(1), $add($$root.count))|$string")
+        == "14"
+    )
+    assert dictwalk.get(
+        data,
+        "scores|$apply_if($gt($$root.min)&&$lt($$root.count), $mul($$root.count))",
+        map_transform=True,
+    ) == [1, 2, 12]
+
+
+def test_get__zip_with_root_referenced_list():
+    data = {"names": ["Ada", "Lin", "Mia"], "ages": [36, 41]}
+
+    assert dictwalk.get(data, "names|$zip($$root.ages)") == [["Ada", 36], ["Lin", 41]]
+
+
+def test_for_each__visits_the_same_values_get_returns():
+    data = {
+        "a": {"users": [{"id": 1, "name": "x"}, {"id": 2, "name": "y"}]},
+        "b": [{"id": 3}, {"meta": {"id": 4}}],
+    }
+
+    for path in ["**.id", "a.users.*.name", "a.users[?.id>1]", "**", "b[:1]"]:
+        seen = []
+        assert dictwalk.for_each(data, path, seen.append) == len(seen)
+        assert seen == dictwalk.get(data, path)
+
+    seen = []
+    assert dictwalk.for_each(data, "a.users", seen.append) == 1
+    assert seen == [data["a"]["users"]]
+    assert dictwalk.for_each(data, "missing.*", seen.append) == 0
+
+
+def test_for_each__calls_back_during_traversal():
+    data = {"items": [{"id": n} for n in range(5)]}
+    seen = []
+
+    def stop_at_two(value):
+        seen.append(value)
+        if value == 2:
+            raise RuntimeError("stop")
+
+    with pytest.raises(RuntimeError, match="stop"):
+        dictwalk.for_each(data, "**.id", stop_at_two)
+    assert seen == [0, 1, 2]
+
+
+def test_for_each__streams_wildcard_and_filter_matches():
+    data = {"users": [{"id": n, "on": True} for n in range(3)]}
+
+    for path in ["users.*.id", "users.id[]", "users[?.on==True].id"]:
+        seen = []
+
+        def stop_at_one(value):
+            seen.append(value)
+            if value == 1:
+                raise RuntimeError("stop")
+
+        with pytest.raises(RuntimeError, match="stop"):
+            dictwalk.for_each(data, path, stop_at_one)
+        assert seen == [0, 1]
+
+
+def test_for_each__propagates_key_error_from_callback():
+    def fail(value):
+        raise KeyError(value)
+
+    with pytest.raises(KeyError):
+        dictwalk.for_each({"a": [1, 2]}, "a.*", fail)
+
+
+def test_for_each__strict_and_argument_errors():
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.for_each({"a": 1}, "a.*", print, strict=True)
+    with pytest.raises(TypeError):
+        dictwalk.for_each({"a": 1}, "a", None)
+    with pytest.raises(DictWalkParseError):
+        dictwalk.for_each({"a": 1}, "a|$string", print)
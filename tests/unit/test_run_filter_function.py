@@ -1,11 +1,19 @@
+import hashlib
 import math
+import re
+import zlib
+from collections import OrderedDict
 from datetime import datetime, timezone
 from decimal import Decimal
 
 import pytest
 
-from dictwalk import dictwalk
-from dictwalk.errors import DictWalkParseError
+from dictwalk import dictwalk, get_options, set_options
+from dictwalk.errors import (
+    DictWalkLimitError,
+    DictWalkParseError,
+    DictWalkResolutionError,
+)
 
 
 def test_run_filter_function__builtin_inc():
@@ -36,14 +44,108 @@ def test_run_filter_function__builtin_float():
     assert dictwalk.run_filter_function("$float", "9.5") == 9.5
 
 
+def test_run_filter_function__builtin_parse_number():
+    assert dictwalk.run_filter_function("$parse_number", "$1,234.56") == 1234.56
+    assert dictwalk.run_filter_function("$parse_number", "1 234,56") == 1234.56
+    assert dictwalk.run_filter_function("$parse_number", "1.234.567,8 EUR") == 1234567.8
+    assert dictwalk.run_filter_function("$parse_number", "1,234") == 1234.0
+    assert dictwalk.run_filter_function("$parse_number", "-0,5") == -0.5
+    assert dictwalk.run_filter_function("$parse_number", "(12.5)") == -12.5
+    assert dictwalk.run_filter_function("$parse_number", 7) == 7.0
+
+
+def test_run_filter_function__builtin_parse_number_reads_one_numeric_run():
+    assert dictwalk.run_filter_function("$parse_number", "1e3") == 1000.0
+    assert dictwalk.run_filter_function("$parse_number", "-2.5E-2 kg") == -0.025
+    assert dictwalk.run_filter_function("$parse_number", "-$1,000") == -1000.0
+    assert dictwalk.run_filter_function("$parse_number", "approx. .5") == 0.5
+    assert dictwalk.run_filter_function("$parse_number", "5 each") == 5.0
+
+
+def test_run_filter_function__builtin_parse_number_returns_none_when_unparseable():
+    assert dictwalk.run_filter_function("$parse_number", "n/a") is None
+    assert dictwalk.run_filter_function("$parse_number", "1-2") is None
+    assert dictwalk.run_filter_function("$parse_number", "3 of 10") is None
+    assert dictwalk.run_filter_function("$parse_number", "v1.2 build 7") is None
+    assert dictwalk.run_filter_function("$parse_number", None) is None
+
+
 def test_run_filter_function__builtin_decimal():
     assert dictwalk.run_filter_function("$decimal", "9.5") == Decimal("9.5")
 
 
+def test_run_filter_function__builtin_coerce():
+    assert dictwalk.run_filter_function("$coerce('int')", "42") == 42
+    assert dictwalk.run_filter_function("$coerce('int')", "4.2") is None
+    assert dictwalk.run_filter_function("$coerce('decimal')", "x") is None
+    assert dictwalk.run_filter_function("$coerce('bool')", "yes") is True
+    assert dictwalk.run_filter_function("$coerce('str')", None) is None
+
+
+def test_run_filter_function__builtin_coerce_unknown_type_raises():
+    with pytest.raises(ValueError):
+        dictwalk.run_filter_function("$coerce('complex')", "1")
+
+
+def test_run_filter_function__builtin_coerce_list_of():
+    value = ["1", 2, "three", 4.0, None]
+
+    assert dictwalk.run_filter_function("$coerce_list_of('int')", value) == [
+        1,
+        2,
+        None,
+        4,
+        None,
+    ]
+    assert dictwalk.run_filter_function("$coerce_list_of('float', True)", value) == [
+        1.0,
+        2.0,
+        4.0,
+    ]
+    assert dictwalk.run_filter_function("$coerce_list_of('int')", "12") is None
+
+
+def test_run_filter_function__builtin_coerce_keys():
+    assert dictwalk.run_filter_function(
+        "$coerce_keys('int')", {"1": "a", "2": "b", "x": "c"}
+    ) == {1: "a", 2: "b", "x": "c"}
+    assert dictwalk.run_filter_function("$coerce_keys('str')", {1: "a", None: "b"}) == {
+        "1": "a",
+        None: "b",
+    }
+    collided = dictwalk.run_filter_function(
+        "$coerce_keys('int')", {"1": "first", "2": "b", "01": "last"}
+    )
+    assert list(collided.items()) == [(1, "last"), (2, "b")]
+
+
+def test_run_filter_function__builtin_coerce_keys_passthrough_and_unknown_type():
+    assert dictwalk.run_filter_function("$coerce_keys('int')", ["1"]) == ["1"]
+    with pytest.raises(ValueError):
+        dictwalk.run_filter_function("$coerce_keys('complex')", {})
+
+
 def test_run_filter_function__builtin_round():
     assert dictwalk.run_filter_function("$round", 2.6) == 3.0
 
 
+def test_run_filter_function__builtin_round_sig():
+    assert dictwalk.run_filter_function("$round_sig(2)", 1234.5) == 1200.0
+    assert dictwalk.run_filter_function("$round_sig(3)", 0.0012345) == 0.00123
+    assert dictwalk.run_filter_function("$round_sig(1)", -876) == -900
+    assert dictwalk.run_filter_function("$round_sig(2)", Decimal("0.04567")) == Decimal(
+        "0.046"
+    )
+    assert dictwalk.run_filter_function("$round_sig(2)", 0) == 0
+    assert dictwalk.run_filter_function("$round_sig(2)", "12") is None
+    assert dictwalk.run_filter_function("$round_sig(2)", True) is None
+
+
+def test_run_filter_function__builtin_round_sig_rejects_non_positive_figures():
+    with pytest.raises(ValueError, match="significant figures"):
+        dictwalk.run_filter_function("$round_sig(0)", 12.5)
+
+
 def test_run_filter_function__builtin_floor():
     assert dictwalk.run_filter_function("$floor", 2.9) == 2
 
@@ -68,6 +170,21 @@ def test_run_filter_function__builtin_odd():
     assert dictwalk.run_filter_function("$odd", 5) is True
 
 
+def test_run_filter_function__builtin_sign_predicates():
+    assert dictwalk.run_filter_function("$nonzero", -2) is True
+    assert dictwalk.run_filter_function("$nonzero", 0.0) is False
+    assert dictwalk.run_filter_function("$positive", Decimal("0.5")) is True
+    assert dictwalk.run_filter_function("$positive", 0) is False
+    assert dictwalk.run_filter_function("$negative", -0.1) is True
+    assert dictwalk.run_filter_function("$negative", 3) is False
+
+
+def test_run_filter_function__builtin_sign_predicates_non_numeric_is_false():
+    for name in ("$nonzero", "$positive", "$negative"):
+        for value in ("5", "-5", None, True, [1]):
+            assert dictwalk.run_filter_function(name, value) is False
+
+
 def test_run_filter_function__builtin_gt():
     assert dictwalk.run_filter_function("$gt(2)", 3) is True
 
@@ -153,6 +270,45 @@ def test_run_filter_function__builtin_pick():
     }
 
 
+def test_run_filter_function__builtin_to_ordered():
+    value = {"b": 2, "c": 3, "a": 1}
+
+    insertion = dictwalk.run_filter_function("$to_ordered", value)
+    assert isinstance(insertion, OrderedDict)
+    assert list(insertion.items()) == [("b", 2), ("c", 3), ("a", 1)]
+    assert list(dictwalk.run_filter_function("$to_ordered('sorted')", value)) == [
+        "a",
+        "b",
+        "c",
+    ]
+    assert list(
+        dictwalk.run_filter_function("$to_ordered('reverse_sorted')", value)
+    ) == ["c", "b", "a"]
+    assert dictwalk.run_filter_function("$to_ordered('sorted')", [3, 1]) == [3, 1]
+
+
+def test_run_filter_function__builtin_sort_keys_recursive():
+    value = {"b": [{"z": 1, "a": 2}, 3], "a": {"d": None, "c": {"y": 1, "x": 2}}}
+
+    result = dictwalk.run_filter_function("$sort_keys_recursive", value)
+
+    assert result == value
+    assert list(result) == ["a", "b"]
+    assert list(result["a"]) == ["c", "d"]
+    assert list(result["a"]["c"]) == ["x", "y"]
+    assert list(result["b"][0]) == ["a", "z"]
+    assert list(value) == ["b", "a"]
+    assert dictwalk.run_filter_function("$sort_keys_recursive", [3, 1]) == [3, 1]
+    assert dictwalk.run_filter_function("$sort_keys_recursive", "x") == "x"
+
+
+def test_run_filter_function__builtin_to_ordered_rejects_unknown_order():
+    with pytest.raises(ValueError, match="unsupported order 'random'"):
+        dictwalk.run_filter_function("$to_ordered('random')", {"a": 1})
+    with pytest.raises(TypeError):
+        dictwalk.run_filter_function("$to_ordered('sorted')", {"a": 1, 2: "b"})
+
+
 def test_run_filter_function__builtin_unpick():
     assert dictwalk.run_filter_function("$unpick('b')", {"a": 1, "b": 2, "c": 3}) == {
         "a": 1,
@@ -160,10 +316,260 @@ def test_run_filter_function__builtin_unpick():
     }
 
 
+def test_run_filter_function__builtin_flatten_keys():
+    value = {"a": {"b": 1, "c": {"d": [2]}}, "e": 3, "f": {}}
+    assert dictwalk.run_filter_function("$flatten_keys", value) == {
+        "a.b": 1,
+        "a.c.d": [2],
+        "e": 3,
+        "f": {},
+    }
+    assert dictwalk.run_filter_function("$flatten_keys('__')", {"a": {"b": 1}}) == {
+        "a__b": 1
+    }
+
+
+def test_run_filter_function__builtin_nest_keys():
+    value = {"a.b": 1, "a.c.d": [2], "e": 3}
+    assert dictwalk.run_filter_function("$nest_keys", value) == {
+        "a": {"b": 1, "c": {"d": [2]}},
+        "e": 3,
+    }
+    assert dictwalk.run_filter_function("$nest_keys('__')", {"a__b": 1}) == {
+        "a": {"b": 1}
+    }
+
+
+def test_run_filter_function__builtin_flatten_keys_rejects_cyclic_input():
+    cyclic = {"b": 1}
+    cyclic["a"] = cyclic
+
+    with pytest.raises(DictWalkLimitError):
+        dictwalk.run_filter_function("$flatten_keys", cyclic)
+
+
+def test_run_filter_function__builtin_flatten_keys_round_trips_through_nest_keys():
+    value = {"a": {"b": 1, "c": {"d": 2}}}
+    assert dictwalk.run_filter_function("$flatten_keys|$nest_keys", value) == value
+
+
+def test_run_filter_function__builtin_flatten_keys_non_dict_returns_none():
+    assert dictwalk.run_filter_function("$flatten_keys", [1]) is None
+
+
+def test_run_filter_function__builtin_deep_get():
+    value = {"user": {"address": {"city": "Dublin"}, "tags": [{"n": 1}, {"n": 2}]}}
+    assert (
+        dictwalk.run_filter_function("$deep_get('user.address.city')", value)
+        == "Dublin"
+    )
+    assert dictwalk.run_filter_function("$deep_get('user.tags.n[]')", value) == [1, 2]
+
+
+def test_run_filter_function__builtin_deep_get_missing_returns_none():
+    assert dictwalk.run_filter_function("$deep_get('a.b')", {"a": 1}) is None
+
+
+def test_run_filter_function__builtin_json_pointer():
+    value = {"a": [{"b": 1}], "x/y": {"m~n": 2}, "": 3}
+
+    assert dictwalk.run_filter_function("$json_pointer('/a/0/b')", value) == 1
+    assert dictwalk.run_filter_function("$json_pointer('/x~1y/m~0n')", value) == 2
+    assert dictwalk.run_filter_function("$json_pointer('/')", value) == 3
+    assert dictwalk.run_filter_function("$json_pointer('')", value) == value
+
+
+def test_run_filter_function__builtin_json_pointer_miss_returns_none():
+    value = {"a": [{"b": 1}]}
+
+    assert dictwalk.run_filter_function("$json_pointer('/a/1/b')", value) is None
+    assert dictwalk.run_filter_function("$json_pointer('/a/01')", value) is None
+    assert dictwalk.run_filter_function("$json_pointer('/a/-')", value) is None
+    assert dictwalk.run_filter_function("$json_pointer('/a/0/b/c')", value) is None
+
+
+def test_run_filter_function__builtin_json_pointer_invalid_raises():
+    with pytest.raises(ValueError):
+        dictwalk.run_filter_function("$json_pointer('a/b')", {})
+    with pytest.raises(ValueError):
+        dictwalk.run_filter_function("$json_pointer('/a~2')", {})
+
+
+def test_run_filter_function__builtin_with_default_map():
+    value = {"id": 1, "name": None}
+    result = dictwalk.run_filter_function(
+        "$with_default_map({'name': 'x', 'active': True})", value
+    )
+    assert result == {"id": 1, "name": None, "active": True}
+    assert value == {"id": 1, "name": None}
+
+
+def test_run_filter_function__builtin_with_default_map_non_dict_returns_defaults():
+    assert dictwalk.run_filter_function("$with_default_map({'a': 1})", None) == {"a": 1}
+
+
+def test_run_filter_function__builtin_require():
+    value = {"id": 1, "name": "x"}
+    assert dictwalk.run_filter_function("$require('id', 'name')", value) is value
+
+
+def test_run_filter_function__builtin_require_raises_for_missing_keys():
+    with pytest.raises(DictWalkResolutionError, match="'name', 'email'"):
+        dictwalk.run_filter_function(
+            "$require('id', 'name', 'email')", {"id": 1, "name": None}
+        )
+
+
+def test_run_filter_function__builtin_require_raises_for_non_dict():
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.run_filter_function("$require('id')", [1])
+
+
+def test_run_filter_function__builtin_assert_type():
+    value = {"id": 1, "name": "x"}
+
+    assert dictwalk.run_filter_function("$assert_type('dict')", value) is value
+    assert dictwalk.run_filter_function("$assert_type('Int')", 3) == 3
+    assert dictwalk.run_filter_function(
+        "$assert_type('dict')|$pick('id')", value
+    ) == {"id": 1}
+
+
+def test_run_filter_function__builtin_assert_type_raises_on_mismatch():
+    with pytest.raises(DictWalkResolutionError, match="Expected dict, got list"):
+        dictwalk.run_filter_function("$assert_type('dict')", [1])
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.get({"payload": "x"}, "payload|$assert_type('dict')")
+
+
+def test_run_filter_function__builtin_case_keys():
+    value = {"content-type": "json", "X-Id": 1, 2: "two"}
+    assert dictwalk.run_filter_function("$title_keys", value) == {
+        "Content-Type": "json",
+        "X-Id": 1,
+        2: "two",
+    }
+    assert dictwalk.run_filter_function("$lower_keys", value) == {
+        "content-type": "json",
+        "x-id": 1,
+        2: "two",
+    }
+    assert dictwalk.run_filter_function("$upper_keys", value) == {
+        "CONTENT-TYPE": "json",
+        "X-ID": 1,
+        2: "two",
+    }
+
+
+def test_run_filter_function__builtin_case_keys_collision_keeps_last_value():
+    value = {"Name": 1, "name": 2}
+    assert dictwalk.run_filter_function("$lower_keys", value) == {"name": 2}
+
+
+def test_run_filter_function__builtin_case_keys_non_dict_passthrough():
+    assert dictwalk.run_filter_function("$upper_keys", ["a"]) == ["a"]
+    assert dictwalk.run_filter_function("$nest_keys", "a.b") is None
+
+
+def test_run_filter_function__builtin_wrap():
+    assert dictwalk.run_filter_function("$wrap('result')", 5) == {"result": 5}
+
+
+def test_run_filter_function__builtin_unwrap():
+    assert dictwalk.run_filter_function("$unwrap('result')", {"result": 5}) == 5
+    assert dictwalk.run_filter_function("$unwrap('result')", {"other": 5}) is None
+    assert dictwalk.run_filter_function("$unwrap('result')", 5) is None
+
+
+def test_run_filter_function__builtin_wrap_then_unwrap_round_trips():
+    assert dictwalk.run_filter_function("$wrap('k')|$unwrap('k')", [1, 2]) == [1, 2]
+
+
 def test_run_filter_function__builtin_keys():
     assert dictwalk.run_filter_function("$keys", {"a": 1, "b": 2}) == ["a", "b"]
 
 
+def test_run_filter_function__builtin_keys_matching():
+    data = {"metric_a": 1, "name": "x", "metric_b": 2, "sub_metric_c": 3, 7: 0}
+
+    assert dictwalk.run_filter_function("$keys_matching('^metric_')", data) == [
+        "metric_a",
+        "metric_b",
+    ]
+    assert dictwalk.run_filter_function("$keys_matching('metric')", data) == [
+        "metric_a",
+        "metric_b",
+        "sub_metric_c",
+    ]
+    assert dictwalk.run_filter_function("$keys_matching('metric_*', 'glob')", data) == [
+        "metric_a",
+        "metric_b",
+    ]
+    assert dictwalk.run_filter_function("$keys_matching('^7$')", data) == [7]
+
+
+def test_run_filter_function__builtin_select_keys():
+    config = {"db_host": "h", "db_port": 5432, "cache_ttl": 60, "mydb_x": 1}
+
+    assert dictwalk.run_filter_function("$select_keys('db_*', 'glob')", config) == {
+        "db_host": "h",
+        "db_port": 5432,
+    }
+    assert dictwalk.run_filter_function("$select_keys('db_')", config) == {
+        "db_host": "h",
+        "db_port": 5432,
+        "mydb_x": 1,
+    }
+    assert dictwalk.run_filter_function("$select_keys('^nope')", config) == {}
+    assert dictwalk.run_filter_function("$select_keys('db')", "db_host") is None
+
+
+def test_run_filter_function__builtin_keys_matching_errors_and_non_dict():
+    assert dictwalk.run_filter_function("$keys_matching('a')", ["a"]) is None
+    with pytest.raises(re.error):
+        dictwalk.run_filter_function("$keys_matching('(')", {"a": 1})
+    with pytest.raises(ValueError):
+        dictwalk.run_filter_function("$keys_matching('a', 'sql')", {"a": 1})
+
+
+def test_run_filter_function__builtin_select_keys_errors_name_select_keys():
+    with pytest.raises(ValueError, match=r"'\$select_keys' got unsupported syntax"):
+        dictwalk.run_filter_function("$select_keys('x', 'bogus')", {"x": 1})
+    with pytest.raises(TypeError, match=r"'\$select_keys' expects pattern"):
+        dictwalk.run_filter_function("$select_keys(1)", {"x": 1})
+
+
+def test_run_filter_function__builtin_json_path():
+    store = {
+        "book": [
+            {"title": "A", "price": 8},
+            {"title": "B", "price": 12, "tags": ("x", "y")},
+        ],
+        "bike": {"price": 20},
+    }
+
+    assert dictwalk.run_filter_function("$json_path('$.book[*].title')", store) == [
+        "A",
+        "B",
+    ]
+    assert dictwalk.run_filter_function("$json_path('$..price')", store) == [8, 12, 20]
+    assert dictwalk.run_filter_function("$json_path('$.book[-1].tags[0]')", store) == [
+        "x"
+    ]
+    assert dictwalk.run_filter_function(
+        "$json_path(\"$['book'][:1]\")", store
+    ) == [{"title": "A", "price": 8}]
+    assert dictwalk.run_filter_function("$json_path('$.bike.*')", store) == [20]
+    assert dictwalk.run_filter_function("$json_path('$')", store) == [store]
+    assert dictwalk.run_filter_function("$json_path('$.missing[5]')", store) == []
+
+
+def test_run_filter_function__builtin_json_path_rejects_unsupported_syntax():
+    for expression in ["book", "$.book[?(@.price)]", "$.book[0,1]", "$.book[::2]"]:
+        with pytest.raises(ValueError, match="invalid JSONPath"):
+            dictwalk.run_filter_function(f'$json_path("{expression}")', {})
+
+
 def test_run_filter_function__builtin_values():
     assert dictwalk.run_filter_function("$values", {"a": 1, "b": 2}) == [1, 2]
 
@@ -185,6 +591,23 @@ def test_run_filter_function__builtin_clamp():
     assert dictwalk.run_filter_function("$clamp(0, 10)", 99) == 10
 
 
+def test_run_filter_function__builtin_clamp_each():
+    assert dictwalk.run_filter_function("$clamp_each(0, 10)", [-5, 3, 99]) == [
+        0,
+        3,
+        10,
+    ]
+    assert dictwalk.run_filter_function("$clamp_each(0.5, 1)", (0, 2)) == [0.5, 1]
+    assert dictwalk.run_filter_function("$clamp_each(0, 10)", []) == []
+
+
+def test_run_filter_function__builtin_clamp_each_rejects_non_list():
+    with pytest.raises(TypeError):
+        dictwalk.run_filter_function("$clamp_each(0, 10)", 99)
+    with pytest.raises(TypeError):
+        dictwalk.run_filter_function("$clamp_each(0, 10)", None)
+
+
 def test_run_filter_function__builtin_sign():
     assert dictwalk.run_filter_function("$sign", -2) == -1
 
@@ -201,6 +624,32 @@ def test_run_filter_function__builtin_pct():
     assert dictwalk.run_filter_function("$pct(25)", 200) == 50.0
 
 
+def test_run_filter_function__builtin_ratio():
+    assert dictwalk.run_filter_function("$ratio(200)", 50) == 0.25
+    assert dictwalk.run_filter_function("$ratio(4)", Decimal("1")) == Decimal("0.25")
+
+
+def test_run_filter_function__builtin_ratio_zero_total():
+    assert dictwalk.run_filter_function("$ratio(0)", 5) is None
+    assert dictwalk.run_filter_function("$ratio(0)", Decimal("0")) is None
+
+
+def test_run_filter_function__builtin_abs_error():
+    assert dictwalk.run_filter_function("$abs_error(10)", 7) == 3
+    assert dictwalk.run_filter_function("$abs_error(2.5)", 3.0) == 0.5
+    assert dictwalk.run_filter_function("$abs_error(1)", Decimal("1.25")) == Decimal("0.25")
+    assert dictwalk.run_filter_function("$abs_error(1)", "3") is None
+    assert dictwalk.run_filter_function("$abs_error(1)", True) is None
+
+
+def test_run_filter_function__builtin_rel_error():
+    assert dictwalk.run_filter_function("$rel_error(-8)", -10) == 0.25
+    assert dictwalk.run_filter_function("$rel_error(4)", Decimal("5")) == Decimal("0.25")
+    assert dictwalk.run_filter_function("$rel_error(0)", 5) is None
+    assert dictwalk.run_filter_function("$rel_error(0.5)", Decimal("1")) is None
+    assert dictwalk.run_filter_function("$rel_error(1)", None) is None
+
+
 def test_run_filter_function__builtin_pctile():
     assert dictwalk.run_filter_function("$pctile(50)", [1, 2, 3, 4, 5]) == 3.0
 
@@ -229,6 +678,23 @@ def test_run_filter_function__builtin_iqr():
     assert dictwalk.run_filter_function("$iqr", [1, 2, 3, 4]) == 1.5
 
 
+def test_run_filter_function__builtin_quantiles_preserve_decimal():
+    values = [Decimal("0.1"), Decimal("0.2"), 3, Decimal("0.4")]
+    median = dictwalk.run_filter_function("$median", values)
+    assert isinstance(median, Decimal)
+    assert median == Decimal("0.3")
+    assert dictwalk.run_filter_function("$q1", values) == Decimal("0.175")
+    assert dictwalk.run_filter_function("$q3", values) == Decimal("1.05")
+    assert dictwalk.run_filter_function("$iqr", values) == Decimal("0.875")
+    assert dictwalk.run_filter_function("$pctile(100)", values) == Decimal("3")
+
+
+def test_run_filter_function__builtin_quantiles_mixed_float_use_float_path():
+    median = dictwalk.run_filter_function("$median", [Decimal("1"), 2.0])
+    assert isinstance(median, float)
+    assert median == 1.5
+
+
 def test_run_filter_function__builtin_mode():
     assert dictwalk.run_filter_function("$mode", [1, 2, 2, 3]) == 2
 
@@ -251,10 +717,131 @@ def test_run_filter_function__builtin_between():
     assert dictwalk.run_filter_function("$between(1, 10)", 5) is True
 
 
+def test_run_filter_function__builtin_within():
+    assert dictwalk.run_filter_function("$within(0.3, 1e-9)", 0.1 + 0.2) is True
+    assert dictwalk.run_filter_function("$within(10, 2)", 12) is True
+    assert dictwalk.run_filter_function("$within(10, 2)", 7) is False
+    assert dictwalk.run_filter_function("$within(1, 0.5)", Decimal("1.25")) is True
+
+
+def test_run_filter_function__builtin_within_non_numeric_is_false():
+    assert dictwalk.run_filter_function("$within(10, 2)", "10") is False
+    assert dictwalk.run_filter_function("$within(10, 2)", None) is False
+    assert dictwalk.run_filter_function("$within(10, 'x')", 10) is False
+
+
 def test_run_filter_function__builtin_sum():
     assert dictwalk.run_filter_function("$sum", [1, 2, 3]) == 6
 
 
+def test_run_filter_function__builtin_numeric_aggregates_skip_policy():
+    messy = [1, "n/a", None, 2, True, Decimal("0.5")]
+
+    assert dictwalk.run_filter_function("$sum('skip')", messy) == Decimal("3.5")
+    assert dictwalk.run_filter_function("$avg('skip')", [1, "x", 3]) == 2.0
+    assert dictwalk.run_filter_function("$max('skip')", [1, "zz", 7]) == 7
+    assert dictwalk.run_filter_function("$min('skip')", ("a", 4, 2)) == 2
+    assert dictwalk.run_filter_function("$sum('skip')", ["a"]) == 0
+    assert dictwalk.run_filter_function("$avg('skip')", ["a"]) is None
+    assert dictwalk.run_filter_function("$max('skip')", ["a"]) is None
+    assert dictwalk.run_filter_function("$sum('strict')", [1, 2]) == 3
+
+
+def test_run_filter_function__builtin_numeric_aggregates_strict_by_default():
+    with pytest.raises(TypeError):
+        dictwalk.run_filter_function("$sum", [1, "n/a"])
+    with pytest.raises(ValueError, match="unsupported policy 'loose'"):
+        dictwalk.run_filter_function("$avg('loose')", [1])
+
+
+def test_run_filter_function__builtin_running_avg():
+    assert dictwalk.run_filter_function("$running_avg(2)", [1, 2, 3, 4]) == [
+        1.5,
+        2.5,
+        3.5,
+    ]
+    assert dictwalk.run_filter_function("$running_avg(3, True)", (3, 6, 9, 12)) == [
+        None,
+        None,
+        6.0,
+        9.0,
+    ]
+    assert dictwalk.run_filter_function("$running_avg(2)", [Decimal("1"), 2]) == [
+        Decimal("1.5")
+    ]
+
+
+def test_run_filter_function__builtin_running_avg_edge_cases():
+    assert dictwalk.run_filter_function("$running_avg(5)", [1, 2]) == []
+    assert dictwalk.run_filter_function("$running_avg(5, True)", [1, 2]) == [None, None]
+    assert dictwalk.run_filter_function("$running_avg(0)", [1, 2]) is None
+    assert dictwalk.run_filter_function("$running_avg(2)", "12") == "12"
+
+
+def test_run_filter_function__builtin_cumsum():
+    assert dictwalk.run_filter_function("$cumsum", [1, 2, 3]) == [1, 3, 6]
+    assert dictwalk.run_filter_function("$cumsum", (1.5, 0.5)) == [1.5, 2.0]
+
+
+def test_run_filter_function__builtin_cumulative_pct():
+    assert dictwalk.run_filter_function("$cumulative_pct", [50, 30, 20]) == [
+        50.0,
+        80.0,
+        100.0,
+    ]
+
+
+def test_run_filter_function__builtin_cumulative_pct_empty_and_zero_sum():
+    assert dictwalk.run_filter_function("$cumulative_pct", []) == []
+    assert dictwalk.run_filter_function("$cumulative_pct", [0, 0]) == [None, None]
+
+
+def test_run_filter_function__builtin_min_max_scale():
+    assert dictwalk.run_filter_function("$min_max_scale(0, 100)", [0, 5, 10]) == [
+        0.0,
+        50.0,
+        100.0,
+    ]
+    assert dictwalk.run_filter_function("$min_max_scale(-1, 1)", (2, 4, 3)) == [
+        -1.0,
+        1.0,
+        0.0,
+    ]
+
+
+def test_run_filter_function__builtin_min_max_scale_constant_empty_and_passthrough():
+    assert dictwalk.run_filter_function("$min_max_scale(1, 2)", [7, 7]) == [1, 1]
+    assert dictwalk.run_filter_function("$min_max_scale(1, 2)", []) == []
+    assert dictwalk.run_filter_function("$min_max_scale(1, 2)", 5) == 5
+
+
+def test_run_filter_function__builtin_winsorize():
+    values = [-50, 2, 3, 4, 100]
+
+    assert dictwalk.run_filter_function("$winsorize(0.25)", values) == [
+        2.0,
+        2,
+        3,
+        4,
+        4.0,
+    ]
+    assert dictwalk.run_filter_function("$winsorize(0.125)", values) == [
+        -24.0,
+        2,
+        3,
+        4,
+        52.0,
+    ]
+    assert dictwalk.run_filter_function("$winsorize(0)", (3, 1)) == [3, 1]
+    assert dictwalk.run_filter_function("$winsorize(0.5)", [1, 2, 9]) == [2.0, 2, 2.0]
+
+
+def test_run_filter_function__builtin_winsorize_invalid_fraction_and_passthrough():
+    assert dictwalk.run_filter_function("$winsorize(0.6)", [1, 2]) is None
+    assert dictwalk.run_filter_function("$winsorize(0.1)", []) == []
+    assert dictwalk.run_filter_function("$winsorize(0.1)", "abc") == "abc"
+
+
 def test_run_filter_function__builtin_avg():
     assert dictwalk.run_filter_function("$avg", [1, 2, 3]) == 2.0
 
@@ -263,6 +850,113 @@ def test_run_filter_function__builtin_unique():
     assert dictwalk.run_filter_function("$unique", [1, 2, 2, 3, 1]) == [1, 2, 3]
 
 
+def test_run_filter_function__builtin_count_true():
+    assert dictwalk.run_filter_function("$count_true", [True, 0, "x", None, 3]) == 3
+    assert dictwalk.run_filter_function("$count_true", (False, [])) == 0
+    assert dictwalk.run_filter_function("$count_true", []) == 0
+    assert dictwalk.run_filter_function("$count_true", "abc") is None
+
+
+def test_run_filter_function__builtin_proportion_true():
+    assert (
+        dictwalk.run_filter_function("$proportion_true", [True, False, True, True])
+        == 0.75
+    )
+    assert dictwalk.run_filter_function("$proportion_true", (0, None)) == 0.0
+    assert dictwalk.run_filter_function("$proportion_true", []) is None
+    assert dictwalk.run_filter_function("$proportion_true", {"a": True}) is None
+
+
+def test_run_filter_function__builtin_tally():
+    assert dictwalk.run_filter_function("$tally", ["b", "a", "c", "a", "b", "a"]) == [
+        ["a", 3],
+        ["b", 2],
+        ["c", 1],
+    ]
+    assert dictwalk.run_filter_function("$tally", ({"k": 1}, 2, {"k": 1})) == [
+        [{"k": 1}, 2],
+        [2, 1],
+    ]
+    assert dictwalk.run_filter_function("$tally", []) == []
+    assert dictwalk.run_filter_function("$tally", "abc") == "abc"
+
+
+def test_run_filter_function__builtin_dedup_keys():
+    value = [["a", 1], ("b", 2), ["a", 3], [["x"], 4], [["x"], 5]]
+
+    assert dictwalk.run_filter_function("$dedup_keys", value) == [
+        ["a", [1, 3]],
+        ["b", [2]],
+        [["x"], [4, 5]],
+    ]
+    assert dictwalk.run_filter_function("$dedup_keys", []) == []
+    assert dictwalk.run_filter_function("$dedup_keys", {"a": 1}) is None
+
+
+def test_run_filter_function__builtin_dedup_keys_rejects_non_pairs():
+    with pytest.raises(TypeError, match="expects \\[key, value\\] pairs"):
+        dictwalk.run_filter_function("$dedup_keys", [["a", 1], ["b"]])
+
+
+def test_run_filter_function__builtin_sum_by_and_avg_by():
+    orders = [
+        {"region": "eu", "amount": 10},
+        {"region": "us", "amount": 5},
+        {"region": "eu", "amount": 20},
+        {"region": "us"},
+        {"region": "us", "amount": None},
+        {"amount": 100},
+        "not-a-dict",
+    ]
+
+    assert dictwalk.run_filter_function("$sum_by('region', 'amount')", orders) == {
+        "eu": 30,
+        "us": 5,
+    }
+    assert dictwalk.run_filter_function("$avg_by('region', 'amount')", orders) == {
+        "eu": 15.0,
+        "us": 5.0,
+    }
+    assert dictwalk.run_filter_function("$sum_by('region', 'amount')", []) == {}
+    assert dictwalk.run_filter_function("$sum_by('region', 'amount')", 3) == 3
+
+
+def test_run_filter_function__builtin_sum_by_nested_paths():
+    rows = [
+        {"meta": {"team": "a"}, "stats": {"pts": 2}},
+        {"meta": {"team": "a"}, "stats": {"pts": 3}},
+    ]
+
+    assert dictwalk.run_filter_function("$sum_by('meta.team', 'stats.pts')", rows) == {
+        "a": 5
+    }
+
+
+def test_run_filter_function__builtin_pivot():
+    sales = [
+        {"region": "eu", "q": "q1", "amount": 10},
+        {"region": "eu", "q": "q2", "amount": 5},
+        {"region": "us", "q": "q1", "amount": 7},
+        {"region": "eu", "q": "q1", "amount": 3},
+        {"region": "us", "amount": 1},
+        "not-a-dict",
+    ]
+
+    assert dictwalk.run_filter_function("$pivot('region', 'q', 'amount')", sales) == {
+        "eu": {"q1": 3, "q2": 5},
+        "us": {"q1": 7},
+    }
+    assert dictwalk.run_filter_function(
+        "$pivot('region', 'q', 'amount', 'sum')", sales
+    ) == {"eu": {"q1": 13, "q2": 5}, "us": {"q1": 7}}
+    assert dictwalk.run_filter_function("$pivot('region', 'q', 'amount')", "x") == "x"
+
+
+def test_run_filter_function__builtin_pivot_rejects_unknown_policy():
+    with pytest.raises(ValueError, match="unsupported policy 'max'"):
+        dictwalk.run_filter_function("$pivot('r', 'c', 'v', 'max')", [])
+
+
 def test_run_filter_function__builtin_sort_by():
     value = [
         {"user": {"id": 2}, "name": "two"},
@@ -290,6 +984,56 @@ def test_run_filter_function__builtin_sort_by_reverse():
     ]
 
 
+def test_run_filter_function__builtin_to_set():
+    assert dictwalk.run_filter_function("$to_set", [3, 1, 3, 2]) == {1, 2, 3}
+
+
+def test_run_filter_function__builtin_set_operations_return_sorted_lists():
+    value = [3, 1, 2, 2]
+    assert dictwalk.run_filter_function("$union([5, 4, 1])", value) == [1, 2, 3, 4, 5]
+    assert dictwalk.run_filter_function("$intersection([3, 2, 9])", value) == [2, 3]
+    assert dictwalk.run_filter_function("$difference([2])", value) == [1, 3]
+
+
+def test_run_filter_function__builtin_symdiff():
+    assert dictwalk.run_filter_function("$symdiff([4, 3, 3])", [3, 1, 2]) == [1, 2, 4]
+    assert dictwalk.run_filter_function("$xor_lists([4])", [3, 4]) == [3]
+
+
+def test_run_filter_function__builtin_symdiff_non_list_returns_none():
+    assert dictwalk.run_filter_function("$symdiff([1])", "abc") is None
+
+
+def test_run_filter_function__builtin_set_operations_pass_through_non_collections():
+    assert dictwalk.run_filter_function("$union([1])", "abc") == "abc"
+
+
+def test_run_filter_function__builtin_set_operations_raise_for_non_list_argument():
+    with pytest.raises(TypeError):
+        dictwalk.run_filter_function("$union(5)", [1, 2])
+
+
+def test_run_filter_function__builtin_partition():
+    assert dictwalk.run_filter_function("$partition($even)", [1, 2, 3, 4]) == [
+        [2, 4],
+        [1, 3],
+    ]
+
+
+def test_run_filter_function__builtin_partition_with_boolean_predicate():
+    assert dictwalk.run_filter_function(
+        "$partition('$gt(1)&&$lt(4)')", [1, 2, 3, 4]
+    ) == [[2, 3], [1, 4]]
+    assert dictwalk.run_filter_function("$partition($lt(2)||$gt(3))", [1, 2, 3, 4]) == [
+        [1, 4],
+        [2, 3],
+    ]
+
+
+def test_run_filter_function__builtin_partition_non_list_returns_none():
+    assert dictwalk.run_filter_function("$partition($even)", 4) is None
+
+
 def test_run_filter_function__builtin_unique_by():
     value = [
         {"id": 1, "name": "one-a"},
@@ -366,9 +1110,122 @@ def test_run_filter_function__builtin_chunk():
     ]
 
 
-def test_run_filter_function__builtin_chunk_non_positive_size_returns_none():
-    assert dictwalk.run_filter_function("$chunk(0)", [1, 2, 3]) is None
-
+def test_run_filter_function__builtin_split_at():
+    assert dictwalk.run_filter_function("$split_at(2)", [1, 2, 3, 4]) == [
+        [1, 2],
+        [3, 4],
+    ]
+    assert dictwalk.run_filter_function("$split_at(-1)", [1, 2, 3]) == [[1, 2], [3]]
+    assert dictwalk.run_filter_function("$split_at(10)", [1, 2]) == [[1, 2], []]
+    assert dictwalk.run_filter_function("$split_at(-10)", [1, 2]) == [[], [1, 2]]
+    assert dictwalk.run_filter_function("$split_at(3)", "abcdef") == ["abc", "def"]
+    assert dictwalk.run_filter_function("$split_at(1)", (1, 2)) == [(1,), (2,)]
+
+
+def test_run_filter_function__builtin_split_at_non_sequence_returns_none():
+    assert dictwalk.run_filter_function("$split_at(1)", {"a": 1}) is None
+    assert dictwalk.run_filter_function("$split_at(1)", 5) is None
+
+
+def test_run_filter_function__builtin_rotate():
+    assert dictwalk.run_filter_function("$rotate(1)", [1, 2, 3, 4]) == [2, 3, 4, 1]
+    assert dictwalk.run_filter_function("$rotate(-1)", [1, 2, 3, 4]) == [4, 1, 2, 3]
+    assert dictwalk.run_filter_function("$rotate(6)", [1, 2, 3, 4]) == [3, 4, 1, 2]
+    assert dictwalk.run_filter_function("$rotate(2)", "abcde") == "cdeab"
+    assert dictwalk.run_filter_function("$rotate(1)", (1, 2, 3)) == (2, 3, 1)
+    assert dictwalk.run_filter_function("$rotate(3)", []) == []
+
+
+def test_run_filter_function__builtin_rotate_non_sequence_passes_through():
+    assert dictwalk.run_filter_function("$rotate(1)", {"a": 1}) == {"a": 1}
+    assert dictwalk.run_filter_function("$rotate(1)", 5) == 5
+
+
+def test_run_filter_function__builtin_transpose():
+    assert dictwalk.run_filter_function("$transpose", [[1, 2, 3], [4, 5, 6]]) == [
+        [1, 4],
+        [2, 5],
+        [3, 6],
+    ]
+    assert dictwalk.run_filter_function("$transpose", [[1, 2, 3], (4, 5)]) == [
+        [1, 4],
+        [2, 5],
+    ]
+    assert dictwalk.run_filter_function("$transpose", []) == []
+
+
+def test_run_filter_function__builtin_transpose_pads_ragged_rows():
+    assert dictwalk.run_filter_function("$transpose(True)", [[1, 2], [3]]) == [
+        [1, 3],
+        [2, None],
+    ]
+    assert dictwalk.run_filter_function("$transpose(True, 0)", [[1], [2, 3]]) == [
+        [1, 2],
+        [0, 3],
+    ]
+
+
+def test_run_filter_function__builtin_transpose_non_matrix_returns_none():
+    assert dictwalk.run_filter_function("$transpose", [[1, 2], 3]) is None
+    assert dictwalk.run_filter_function("$transpose", {"a": [1]}) is None
+
+
+def test_run_filter_function__builtin_chunk_non_positive_size_returns_none():
+    assert dictwalk.run_filter_function("$chunk(0)", [1, 2, 3]) is None
+
+
+def test_run_filter_function__builtin_zip():
+    assert dictwalk.run_filter_function("$zip([1, 2, 3])", ["a", "b"]) == [
+        ["a", 1],
+        ["b", 2],
+    ]
+    assert dictwalk.run_filter_function("$zip([1])", "ab") is None
+    with pytest.raises(TypeError):
+        dictwalk.run_filter_function("$zip(5)", ["a"])
+
+
+def test_run_filter_function__builtin_interleave():
+    assert dictwalk.run_filter_function("$interleave(['x', 'y'])", [1, 2, 3, 4]) == [
+        1,
+        "x",
+        2,
+        "y",
+        3,
+        4,
+    ]
+    assert dictwalk.run_filter_function("$interleave([7, 8, 9])", [1]) == [1, 7, 8, 9]
+    assert dictwalk.run_filter_function("$interleave([1])", None) is None
+
+
+def test_run_filter_function__builtin_clamp_len():
+    assert dictwalk.run_filter_function("$clamp_len(3)", "abcdef") == "abc"
+    assert dictwalk.run_filter_function("$clamp_len(3)", [1, 2]) == [1, 2]
+    assert dictwalk.run_filter_function("$clamp_len(2)", (1, 2, 3)) == (1, 2)
+    assert dictwalk.run_filter_function("$clamp_len(1)", {"a": 1, "b": 2}) == {"a": 1}
+    assert dictwalk.run_filter_function("$clamp_len(1)", 42) == 42
+
+
+def test_run_filter_function__builtin_repeat():
+    assert dictwalk.run_filter_function("$repeat(3)", "-") == "---"
+    assert dictwalk.run_filter_function("$repeat(2)", [1, 2]) == [1, 2, 1, 2]
+    assert dictwalk.run_filter_function("$repeat(0)", "ab") == ""
+    assert dictwalk.run_filter_function("$repeat(-1)", [1]) == []
+    assert dictwalk.run_filter_function("$repeat(2)", 5) == 5
+
+
+def test_run_filter_function__builtin_pad_list():
+    assert dictwalk.run_filter_function("$pad_list(4)", [1, 2]) == [1, 2, None, None]
+    assert dictwalk.run_filter_function("$pad_list(3, 0)", (1,)) == [1, 0, 0]
+    assert dictwalk.run_filter_function("$pad_list(2, 0)", [1, 2, 3]) == [1, 2, 3]
+
+
+def test_run_filter_function__builtin_pad_list_truncate():
+    assert dictwalk.run_filter_function("$pad_list(2, 0, True)", [1, 2, 3]) == [1, 2]
+
+
+def test_run_filter_function__builtin_pad_list_non_list_returns_none():
+    assert dictwalk.run_filter_function("$pad_list(2)", "ab") is None
+
 
 def test_run_filter_function__builtin_flatten_one_level():
     assert dictwalk.run_filter_function("$flatten", [[1, 2], [3], 4]) == [1, 2, 3, 4]
@@ -402,10 +1259,43 @@ def test_run_filter_function__builtin_flatten_passthrough_non_collection():
     assert dictwalk.run_filter_function("$flatten", value) == value
 
 
+def test_run_filter_function__builtin_rank():
+    assert dictwalk.run_filter_function("$rank", [30, 10, 20, 10]) == [4, 1, 3, 1]
+    assert dictwalk.run_filter_function("$rank('dense')", [30, 10, 20, 10]) == [
+        3,
+        1,
+        2,
+        1,
+    ]
+    assert dictwalk.run_filter_function("$rank", "abc") == "abc"
+
+
+def test_run_filter_function__builtin_rank_unknown_policy_raises():
+    with pytest.raises(ValueError):
+        dictwalk.run_filter_function("$rank('olympic')", [1, 2])
+
+
 def test_run_filter_function__builtin_sorted():
     assert dictwalk.run_filter_function("$sorted", [3, 1, 2]) == [1, 2, 3]
 
 
+def test_run_filter_function__builtin_sorted_by_abs():
+    deltas = [3, -1, -5, 2, 1]
+
+    assert dictwalk.run_filter_function("$sorted_by_abs", deltas) == [-1, 1, 2, 3, -5]
+    assert dictwalk.run_filter_function("$sorted_by_abs(True)", deltas) == [
+        -5,
+        3,
+        2,
+        -1,
+        1,
+    ]
+    assert dictwalk.run_filter_function("$sorted_by_abs", (-0.5, 0.25)) == [0.25, -0.5]
+    assert dictwalk.run_filter_function("$sorted_by_abs", -3) == -3
+    with pytest.raises(TypeError):
+        dictwalk.run_filter_function("$sorted_by_abs", [1, "a"])
+
+
 def test_run_filter_function__builtin_first():
     assert dictwalk.run_filter_function("$first", [9, 8, 7]) == 9
 
@@ -418,6 +1308,24 @@ def test_run_filter_function__builtin_contains():
     assert dictwalk.run_filter_function("$contains(2)", [1, 2, 3]) is True
 
 
+def test_run_filter_function__builtin_index_of():
+    assert dictwalk.run_filter_function("$index_of(3)", [1, 3, 3]) == 1
+    assert dictwalk.run_filter_function("$index_of('lo')", "hello") == 3
+
+
+def test_run_filter_function__builtin_index_of_not_found():
+    assert dictwalk.run_filter_function("$index_of(9)", [1, 2]) == -1
+    assert dictwalk.run_filter_function("$index_of('z')", "hello") == -1
+    assert dictwalk.run_filter_function("$index_of(1)", {"a": 1}) is None
+
+
+def test_run_filter_function__builtin_count_occurrences():
+    assert dictwalk.run_filter_function("$count_occurrences(3)", [3, 1, 3.0]) == 2
+    assert dictwalk.run_filter_function("$count_occurrences('aa')", "aaaa") == 2
+    assert dictwalk.run_filter_function("$count_occurrences('z')", "abc") == 0
+    assert dictwalk.run_filter_function("$count_occurrences(1)", {"a": 1}) is None
+
+
 def test_run_filter_function__builtin_in():
     assert dictwalk.run_filter_function("$in([1, 2, 3])", 2) is True
 
@@ -445,6 +1353,30 @@ def test_run_filter_function__builtin_replace():
     )
 
 
+def test_run_filter_function__builtin_replace_map():
+    assert (
+        dictwalk.run_filter_function(
+            "$replace_map({'&': 'and', '@': 'at'})", "tom & jerry @ home"
+        )
+        == "tom and jerry at home"
+    )
+    assert dictwalk.run_filter_function("$replace_map({'a': 'b', 'b': 'c'})", "ab") == (
+        "bc"
+    )
+    assert (
+        dictwalk.run_filter_function("$replace_map({'a': '1', 'ab': '2'})", "abac")
+        == "21c"
+    )
+    assert dictwalk.run_filter_function("$replace_map({'1': 1.5})", 212) == "21.52"
+
+
+def test_run_filter_function__builtin_replace_map_rejects_invalid_mapping():
+    with pytest.raises(TypeError):
+        dictwalk.run_filter_function("$replace_map(['a'])", "abc")
+    with pytest.raises(TypeError):
+        dictwalk.run_filter_function("$replace_map({1: 'x'})", "abc")
+
+
 def test_run_filter_function__builtin_regex_replace():
     assert (
         dictwalk.run_filter_function("$regex_replace('\\\\d+', '#')", "item 123")
@@ -456,10 +1388,61 @@ def test_run_filter_function__builtin_split():
     assert dictwalk.run_filter_function("$split(',')", "a,b,c") == ["a", "b", "c"]
 
 
+def test_run_filter_function__builtin_split_lines():
+    text = "a\r\nb\nc\r"
+
+    assert dictwalk.run_filter_function("$split_lines", text) == ["a", "b", "c"]
+    assert dictwalk.run_filter_function("$split_lines(True)", text) == [
+        "a\r\n",
+        "b\n",
+        "c\r",
+    ]
+
+
+def test_run_filter_function__builtin_strip_html():
+    html = "<p>Fish &amp; <b>chips</b></p><!-- note --><script>x < 1</script>"
+
+    assert dictwalk.run_filter_function("$strip_html", html) == "Fish &amp; chips"
+    assert dictwalk.run_filter_function("$strip_html(True)", html) == "Fish & chips"
+
+
+def test_run_filter_function__builtin_strip_html_keeps_bare_angle_brackets():
+    assert dictwalk.run_filter_function("$strip_html", "a < b > c") == "a < b > c"
+    assert (
+        dictwalk.run_filter_function("$strip_html", "1 <2 and <b>3</b>") == "1 <2 and 3"
+    )
+
+
+def test_run_filter_function__builtin_escape_and_unescape_html():
+    text = "<a href=\"x\">Tom & 'Jerry'</a>"
+    escaped = "&lt;a href=&quot;x&quot;&gt;Tom &amp; &#x27;Jerry&#x27;&lt;/a&gt;"
+
+    assert dictwalk.run_filter_function("$escape_html", text) == escaped
+    assert dictwalk.run_filter_function("$unescape_html", escaped) == text
+    assert dictwalk.run_filter_function("$escape_html", 3) == "3"
+
+
 def test_run_filter_function__builtin_join():
     assert dictwalk.run_filter_function("$join('-')", ["a", "b", "c"]) == "a-b-c"
 
 
+def test_run_filter_function__builtin_to_csv_row():
+    assert (
+        dictwalk.run_filter_function("$to_csv_row", ["a", 'say "hi"', "x,y", 3])
+        == 'a,"say ""hi""","x,y",3'
+    )
+
+
+def test_run_filter_function__builtin_to_csv_row_with_delimiter():
+    assert dictwalk.run_filter_function("$to_csv_row('\\t')", ["a", "b c", 1]) == (
+        "a\tb c\t1"
+    )
+
+
+def test_run_filter_function__builtin_to_csv_row_non_list_returns_none():
+    assert dictwalk.run_filter_function("$to_csv_row", "a,b") is None
+
+
 def test_run_filter_function__builtin_startswith():
     assert dictwalk.run_filter_function("$startswith('he')", "hello") is True
 
@@ -479,6 +1462,18 @@ def test_run_filter_function__builtin_default():
     assert dictwalk.run_filter_function("$default(9)", None) == 9
 
 
+def test_run_filter_function__builtin_coalesce_empty_to():
+    path_filter = "$coalesce_empty_to('n/a')"
+
+    assert dictwalk.run_filter_function(path_filter, None) == "n/a"
+    assert dictwalk.run_filter_function(path_filter, "") == "n/a"
+    assert dictwalk.run_filter_function(path_filter, []) == "n/a"
+    assert dictwalk.run_filter_function(path_filter, {}) == "n/a"
+    assert dictwalk.run_filter_function(path_filter, "x") == "x"
+    assert dictwalk.run_filter_function(path_filter, [0]) == [0]
+    assert dictwalk.run_filter_function(path_filter, 0) == 0
+
+
 def test_run_filter_function__builtin_const():
     assert (
         dictwalk.run_filter_function("$const('literal value')", {"a": "b"})
@@ -486,14 +1481,193 @@ def test_run_filter_function__builtin_const():
     )
 
 
+def test_run_filter_function__builtin_apply_if():
+    assert dictwalk.run_filter_function("$apply_if($lt(0), $neg)", -3) == 3
+    assert dictwalk.run_filter_function("$apply_if($lt(0), $neg)", 4) == 4
+    assert (
+        dictwalk.run_filter_function("$apply_if($gt(1)&&$lt(5), $double|$inc)", 2)
+        == 5
+    )
+
+
+def test_run_filter_function__builtin_apply_if_map_suffix():
+    assert dictwalk.run_filter_function("$apply_if($even, $neg)[]", [1, 2, 3, 4]) == [
+        1,
+        -2,
+        3,
+        -4,
+    ]
+
+
+def test_run_filter_function__builtin_map_recursive():
+    doc = {"name": " Ada ", "tags": [" x", ["y "]], "meta": {"note": " hi "}}
+
+    assert dictwalk.run_filter_function("$map_recursive($strip)", doc) == {
+        "name": "Ada",
+        "tags": ["x", ["y"]],
+        "meta": {"note": "hi"},
+    }
+    assert dictwalk.run_filter_function(
+        "$map_recursive($apply_if($gt(1), $double))", [1, {"a": 3}, [2]]
+    ) == [1, {"a": 6}, [4]]
+    assert dictwalk.run_filter_function("$map_recursive($inc)", 4) == 5
+    assert dictwalk.run_filter_function("$map_recursive($inc)", {}) == {}
+
+
+def test_get__map_recursive_leaves_source_untouched():
+    data = {"doc": {"a": [" x "]}}
+
+    assert dictwalk.get(data, "doc|$map_recursive($strip)") == {"a": ["x"]}
+    assert data == {"doc": {"a": [" x "]}}
+
+
+def test_run_filter_function__builtin_switch():
+    path_filter = "$switch('A', 'Active', 'I', 'Inactive', 'Unknown')"
+    assert dictwalk.run_filter_function(path_filter, "A") == "Active"
+    assert dictwalk.run_filter_function(path_filter, "I") == "Inactive"
+    assert dictwalk.run_filter_function(path_filter, "X") == "Unknown"
+
+
+def test_run_filter_function__builtin_switch_without_default_returns_none():
+    assert dictwalk.run_filter_function("$switch(1, 'one', 2, 'two')", 2) == "two"
+    assert dictwalk.run_filter_function("$switch(1, 'one', 2, 'two')", 3) is None
+
+
+def test_run_filter_function__builtin_lookup():
+    path_filter = "$lookup({'A': 'Active', 'I': 'Inactive'})"
+    assert dictwalk.run_filter_function(path_filter, "A") == "Active"
+    assert dictwalk.run_filter_function(path_filter, "X") is None
+
+
+def test_run_filter_function__builtin_lookup_with_default():
+    path_filter = "$lookup({1: 'one'}, 'other')"
+    assert dictwalk.run_filter_function(path_filter, 1) == "one"
+    assert dictwalk.run_filter_function(path_filter, 2) == "other"
+
+
+def test_run_filter_function__builtin_lookup_non_dict_mapping_raises():
+    with pytest.raises(TypeError):
+        dictwalk.run_filter_function("$lookup([1, 2])", 1)
+
+
+def test_run_filter_function__builtin_nan_to():
+    assert dictwalk.run_filter_function("$nan_to(0)", math.nan) == 0
+    assert dictwalk.run_filter_function("$nan_to(0)", 1.5) == 1.5
+    assert dictwalk.run_filter_function("$nan_to(None)", [1.0, math.nan, "x"]) == [
+        1.0,
+        None,
+        "x",
+    ]
+    assert dictwalk.run_filter_function("$nan_to(0)", math.inf) == math.inf
+
+
+def test_run_filter_function__builtin_nan_to_include_inf():
+    values = [math.inf, -math.inf, math.nan, 2]
+    assert dictwalk.run_filter_function("$nan_to(0, True)", values) == [0, 0, 0, 2]
+
+
+def test_run_filter_function__builtin_typed_default():
+    assert dictwalk.run_filter_function("$typed_default(0)", 12) == 12
+    assert dictwalk.run_filter_function("$typed_default(0)", "12") == 0
+    assert dictwalk.run_filter_function("$typed_default(0)", None) == 0
+    assert dictwalk.run_filter_function("$typed_default('')", "x") == "x"
+
+
+def test_run_filter_function__builtin_typed_default_bool_is_not_int():
+    assert dictwalk.run_filter_function("$typed_default(0)", True) == 0
+    assert dictwalk.run_filter_function("$typed_default(False)", 1) is False
+
+
+def test_run_filter_function__builtin_hash():
+    expected = hashlib.sha512(b"abc").hexdigest()
+    assert dictwalk.run_filter_function("$hash('sha512')", "abc") == expected
+    assert dictwalk.run_filter_function("$hash('sha512')", b"abc") == expected
+
+
+def test_run_filter_function__builtin_hash_shorthands():
+    assert dictwalk.run_filter_function("$md5", "abc") == hashlib.md5(b"abc").hexdigest()
+    assert dictwalk.run_filter_function("$sha1", 12) == hashlib.sha1(b"12").hexdigest()
+    assert (
+        dictwalk.run_filter_function("$sha256", "é")
+        == hashlib.sha256("é".encode()).hexdigest()
+    )
+
+
+def test_run_filter_function__builtin_checksums():
+    assert dictwalk.run_filter_function("$crc32", "abc") == zlib.crc32(b"abc")
+    assert dictwalk.run_filter_function("$adler32", 7) == zlib.adler32(b"7")
+
+
+def test_run_filter_function__builtin_checksum_modulo():
+    assert (
+        dictwalk.run_filter_function("$crc32(100)", "abc") == zlib.crc32(b"abc") % 100
+    )
+    assert dictwalk.run_filter_function("$adler32(8)", "abc") == zlib.adler32(b"abc") % 8
+
+
+def test_run_filter_function__builtin_checksum_rejects_non_positive_buckets():
+    with pytest.raises(ValueError, match="positive bucket count"):
+        dictwalk.run_filter_function("$crc32(0)", "abc")
+    with pytest.raises(ValueError, match="positive bucket count"):
+        dictwalk.run_filter_function("$adler32(-4)", "abc")
+
+
+def test_run_filter_function__builtin_hash_unknown_algorithm_raises():
+    with pytest.raises(ValueError):
+        dictwalk.run_filter_function("$hash('nope')", "abc")
+
+
 def test_run_filter_function__builtin_coalesce():
     assert dictwalk.run_filter_function("$coalesce(None, 7, 8)", None) == 7
 
 
+def test_run_filter_function__builtin_coalesce_flat():
+    value = [None, [None, [], (None, 3)], 4]
+    assert dictwalk.run_filter_function("$coalesce_flat", value) == 3
+    assert dictwalk.run_filter_function("$coalesce_flat", [None, [None]]) is None
+    assert dictwalk.run_filter_function("$coalesce_flat", 5) == 5
+
+
+def test_run_filter_function__builtin_coalesce_flat_rejects_cyclic_input():
+    cyclic = [None]
+    cyclic.append(cyclic)
+
+    with pytest.raises(DictWalkLimitError):
+        dictwalk.run_filter_function("$coalesce_flat", cyclic)
+    with pytest.raises(DictWalkLimitError):
+        dictwalk.run_filter_function("$flatten_deep", cyclic)
+
+
 def test_run_filter_function__builtin_bool():
     assert dictwalk.run_filter_function("$bool", "YES") is True
 
 
+def test_run_filter_function__builtin_bool_lenient_treats_unknown_strings_as_false():
+    assert dictwalk.run_filter_function("$bool", "off") is False
+    assert dictwalk.run_filter_function("$bool", "maybe") is False
+
+
+def test_run_filter_function__builtin_bool_strict():
+    assert dictwalk.run_filter_function("$bool(True)", "On") is True
+    assert dictwalk.run_filter_function("$bool(True)", " no ") is False
+    assert dictwalk.run_filter_function("$bool(True)", "0") is False
+    assert dictwalk.run_filter_function("$bool(True)", "maybe") is None
+    assert dictwalk.run_filter_function("$bool(True)", 0) is False
+
+
+def test_run_filter_function__builtin_to_bool_strict():
+    assert dictwalk.run_filter_function("$to_bool_strict", " Yes ") is True
+    assert dictwalk.run_filter_function("$to_bool_strict", "off") is False
+    assert dictwalk.run_filter_function("$to_bool_strict", 1) is True
+    assert dictwalk.run_filter_function("$to_bool_strict", False) is False
+
+
+def test_run_filter_function__builtin_to_bool_strict_rejects_unknown_values():
+    for value in ["maybe", 2, 1.0, None]:
+        with pytest.raises(DictWalkResolutionError):
+            dictwalk.run_filter_function("$to_bool_strict", value)
+
+
 def test_run_filter_function__builtin_type_is():
     assert dictwalk.run_filter_function("$type_is('int')", 5) is True
 
@@ -517,11 +1691,41 @@ def test_run_filter_function__builtin_compact():
     ]
 
 
+def test_run_filter_function__builtin_prune_nulls():
+    doc = {"a": None, "b": {"c": None, "d": 1}, "e": [None, 2, {"f": None}], "g": ""}
+
+    assert dictwalk.run_filter_function("$prune_nulls", doc) == {
+        "b": {"d": 1},
+        "e": [2, {}],
+        "g": "",
+    }
+    assert dictwalk.run_filter_function("$prune_nulls(True)", doc) == {
+        "b": {"d": 1},
+        "e": [2],
+    }
+    assert dictwalk.run_filter_function("$prune_nulls(True)", {"a": {"b": []}}) == {}
+    assert dictwalk.run_filter_function("$prune_nulls", "x") == "x"
+    assert dictwalk.run_filter_function("$prune_nulls", None) is None
+
+
 def test_run_filter_function__builtin_compact_passthrough_non_collection():
     value = "hello"
     assert dictwalk.run_filter_function("$compact", value) == value
 
 
+def test_run_filter_function__builtin_fill_forward():
+    value = [None, 1, None, 0, None]
+    assert dictwalk.run_filter_function("$fill_forward", value) == [None, 1, 1, 0, 0]
+    assert value == [None, 1, None, 0, None]
+    assert dictwalk.run_filter_function("$fill_forward", "abc") == "abc"
+
+
+def test_run_filter_function__builtin_fill_backward():
+    value = (None, 1, None, 0, None)
+    assert dictwalk.run_filter_function("$fill_backward", value) == [1, 1, 0, 0, None]
+    assert dictwalk.run_filter_function("$fill_backward", []) == []
+
+
 def test_run_filter_function__builtin_from_json():
     assert dictwalk.run_filter_function("$from_json", '{"a": 1, "b": [2, 3]}') == {
         "a": 1,
@@ -548,6 +1752,23 @@ def test_run_filter_function__builtin_to_json_raises_for_unserializable_value():
         dictwalk.run_filter_function("$to_json", object())
 
 
+def test_run_filter_function__builtin_canonical_json():
+    value = {"b": [2, {"y": 1.5, "x": None}], "a": "é"}
+
+    assert (
+        dictwalk.run_filter_function("$canonical_json", value)
+        == '{"a":"\\u00e9","b":[2,{"x":null,"y":1.5}]}'
+    )
+    assert dictwalk.run_filter_function(
+        "$canonical_json|$sha256", {"a": 1, "b": 2}
+    ) == dictwalk.run_filter_function("$canonical_json|$sha256", {"b": 2, "a": 1})
+
+
+def test_run_filter_function__builtin_canonical_json_rejects_nan():
+    with pytest.raises(ValueError):
+        dictwalk.run_filter_function("$canonical_json", {"a": float("nan")})
+
+
 def test_run_filter_function__builtin_to_datetime():
     assert dictwalk.run_filter_function(
         "$to_datetime", "2024-01-02T03:04:05Z"
@@ -571,12 +1792,218 @@ def test_run_filter_function__builtin_timestamp():
     ) == pytest.approx(expected)
 
 
+def test_run_filter_function__builtin_timestamp_ms():
+    assert (
+        dictwalk.run_filter_function("$timestamp_ms", "2024-01-02T03:04:05.123999Z")
+        == 1704164645123
+    )
+    assert (
+        dictwalk.run_filter_function(
+            "$timestamp_ms", datetime(1970, 1, 1, 0, 0, 1, tzinfo=timezone.utc)
+        )
+        == 1000
+    )
+    assert dictwalk.run_filter_function("$timestamp_ms", 1.5) == 1500
+
+
+def test_run_filter_function__builtin_timestamp_ms_uncoercible_returns_none():
+    assert dictwalk.run_filter_function("$timestamp_ms", "not a date") is None
+    assert dictwalk.run_filter_function("$timestamp_ms", [1]) is None
+
+
+def test_run_filter_function__builtin_from_timestamp_ms():
+    assert dictwalk.run_filter_function(
+        "$from_timestamp_ms", 1704164645123
+    ) == datetime(2024, 1, 2, 3, 4, 5, 123000, tzinfo=timezone.utc)
+    assert dictwalk.run_filter_function("$from_timestamp_ms", 1.5) == datetime(
+        1970, 1, 1, 0, 0, 0, 1500, tzinfo=timezone.utc
+    )
+    assert dictwalk.run_filter_function(
+        "$from_timestamp_ms", Decimal("-1000")
+    ) == datetime(1969, 12, 31, 23, 59, 59, tzinfo=timezone.utc)
+
+
+def test_run_filter_function__builtin_from_timestamp_ms_non_numeric_returns_none():
+    assert dictwalk.run_filter_function("$from_timestamp_ms", "1000") is None
+    assert dictwalk.run_filter_function("$from_timestamp_ms", True) is None
+    assert dictwalk.run_filter_function("$from_timestamp_ms", None) is None
+
+
+def test_run_filter_function__builtin_from_timestamp_ms_out_of_range_returns_none():
+    assert dictwalk.run_filter_function("$from_timestamp_ms", 1e20) is None
+    assert dictwalk.run_filter_function("$from_timestamp_ms", -(10**20)) is None
+    assert dictwalk.run_filter_function("$from_timestamp_ms", float("inf")) is None
+    assert dictwalk.run_filter_function("$from_timestamp_ms", float("nan")) is None
+
+
+def test_run_filter_function__builtin_timestamp_ms_round_trip():
+    assert (
+        dictwalk.run_filter_function(
+            "$from_timestamp_ms|$timestamp_ms", 1704164645123
+        )
+        == 1704164645123
+    )
+
+
 def test_run_filter_function__builtin_age_seconds():
     result = dictwalk.run_filter_function("$age_seconds", "1970-01-01T00:00:00+00:00")
     assert isinstance(result, float)
     assert result > 1_000_000
 
 
+def test_run_filter_function__builtin_age_days():
+    result = dictwalk.run_filter_function("$age_days", "1970-01-01T00:00:00+00:00")
+    assert isinstance(result, float)
+    assert result > 19_000
+    assert dictwalk.run_filter_function("$age_days", "not a date") is None
+
+
+def test_run_filter_function__builtin_duration():
+    assert dictwalk.run_filter_function(
+        "$duration('2024-01-01T01:00:30Z')", "2024-01-01T00:00:00Z"
+    ) == pytest.approx(3630.0)
+
+
+def test_run_filter_function__builtin_duration_can_be_negative():
+    assert dictwalk.run_filter_function(
+        "$duration('2024-01-01T00:00:00Z')", "2024-01-02T00:00:00Z"
+    ) == pytest.approx(-86400.0)
+
+
+def test_run_filter_function__builtin_duration_reads_naive_values_as_utc():
+    assert dictwalk.run_filter_function(
+        "$duration('2024-01-01T01:00:00Z')", datetime(2024, 1, 1)
+    ) == pytest.approx(3600.0)
+    assert dictwalk.run_filter_function(
+        "$duration('2024-01-01T01:00:00')",
+        datetime(2024, 1, 1, tzinfo=timezone.utc),
+    ) == pytest.approx(3600.0)
+
+
+def test_run_filter_function__builtin_duration_invalid_value_returns_none():
+    assert (
+        dictwalk.run_filter_function("$duration('not-a-date')", "2024-01-01T00:00:00Z")
+        is None
+    )
+    assert (
+        dictwalk.run_filter_function("$duration('2024-01-01T00:00:00Z')", None) is None
+    )
+
+
+def test_run_filter_function__builtin_business_days_between():
+    bdays = "$business_days_between('{}')"
+
+    assert dictwalk.run_filter_function(bdays.format("2024-01-09"), "2024-01-05") == 2
+    assert dictwalk.run_filter_function(bdays.format("2024-01-15"), "2024-01-01") == 10
+    assert dictwalk.run_filter_function(bdays.format("2024-01-01"), "2024-01-15") == -10
+    assert dictwalk.run_filter_function(bdays.format("2024-01-06"), "2024-01-06") == 0
+    assert (
+        dictwalk.run_filter_function(
+            bdays.format("2024-01-08T09:00:00Z"), datetime(2024, 1, 5, 23, 0)
+        )
+        == 1
+    )
+
+
+def test_run_filter_function__builtin_business_days_between_subtracts_holidays():
+    holidays = "['2024-01-01', '2024-01-06', '2024-01-01', '2024-02-01']"
+
+    assert (
+        dictwalk.run_filter_function(
+            f"$business_days_between('2024-01-15', {holidays})", "2024-01-01"
+        )
+        == 9
+    )
+    assert (
+        dictwalk.run_filter_function(
+            f"$business_days_between('2024-01-01', {holidays})", "2024-01-15"
+        )
+        == -9
+    )
+
+
+def test_run_filter_function__builtin_business_days_between_invalid_returns_none():
+    assert (
+        dictwalk.run_filter_function("$business_days_between('x')", "2024-01-01")
+        is None
+    )
+    assert (
+        dictwalk.run_filter_function(
+            "$business_days_between('2024-01-15', ['nope'])", "2024-01-01"
+        )
+        is None
+    )
+    assert (
+        dictwalk.run_filter_function("$business_days_between('2024-01-15')", [1])
+        is None
+    )
+
+
+def test_run_filter_function__builtin_humanize_duration():
+    assert dictwalk.run_filter_function("$humanize_duration", 7503) == "2h 5m 3s"
+    assert dictwalk.run_filter_function("$humanize_duration", 90061.4) == "1d 1h 1m 1s"
+    assert dictwalk.run_filter_function("$humanize_duration", 0) == "0s"
+    assert dictwalk.run_filter_function("$humanize_duration", -65) == "-1m 5s"
+
+
+def test_run_filter_function__builtin_humanize_duration_largest_unit_only():
+    assert dictwalk.run_filter_function("$humanize_duration(True)", 7503) == "2 hours"
+    assert dictwalk.run_filter_function("$humanize_duration(True)", 60) == "1 minute"
+
+
+def test_run_filter_function__builtin_humanize_duration_non_numeric_returns_none():
+    assert dictwalk.run_filter_function("$humanize_duration", "7503") is None
+
+
+def test_run_filter_function__builtin_date_trunc_day():
+    assert dictwalk.run_filter_function(
+        "$date_trunc('day')", "2024-03-15T13:45:30.123456Z"
+    ) == datetime(2024, 3, 15, tzinfo=timezone.utc)
+
+
+def test_run_filter_function__builtin_date_trunc_month_and_year():
+    value = "2024-03-15T13:45:30Z"
+    assert dictwalk.run_filter_function("$date_trunc('month')", value) == datetime(
+        2024, 3, 1, tzinfo=timezone.utc
+    )
+    assert dictwalk.run_filter_function("$date_trunc('year')", value) == datetime(
+        2024, 1, 1, tzinfo=timezone.utc
+    )
+
+
+def test_run_filter_function__builtin_date_trunc_invalid_value_returns_none():
+    assert dictwalk.run_filter_function("$date_trunc('hour')", "not-a-date") is None
+
+
+def test_run_filter_function__builtin_date_trunc_raises_for_unknown_unit():
+    with pytest.raises(ValueError):
+        dictwalk.run_filter_function("$date_trunc('fortnight')", "2024-03-15T13:45:30Z")
+
+
+def test_run_filter_function__builtin_iso_week_and_iso_year():
+    # 2021-01-01 falls in ISO week 53 of 2020.
+    assert dictwalk.run_filter_function("$iso_week", "2021-01-01T00:00:00Z") == 53
+    assert dictwalk.run_filter_function("$iso_year", "2021-01-01T00:00:00Z") == 2020
+
+
+def test_run_filter_function__builtin_iso_week_invalid_value_returns_none():
+    assert dictwalk.run_filter_function("$iso_week", "not-a-date") is None
+    assert dictwalk.run_filter_function("$iso_year", ["2021-01-01"]) is None
+
+
+def test_run_filter_function__builtin_is_weekend_and_is_weekday():
+    assert dictwalk.run_filter_function("$is_weekend", "2024-01-06") is True
+    assert dictwalk.run_filter_function("$is_weekend", "2024-01-08T10:00:00Z") is False
+    assert dictwalk.run_filter_function("$is_weekday", datetime(2024, 1, 5)) is True
+    assert dictwalk.run_filter_function("$is_weekday", "2024-01-07") is False
+
+
+def test_run_filter_function__builtin_is_weekend_invalid_value_returns_false():
+    assert dictwalk.run_filter_function("$is_weekend", "not-a-date") is False
+    assert dictwalk.run_filter_function("$is_weekday", "not-a-date") is False
+    assert dictwalk.run_filter_function("$is_weekday", None) is False
+
+
 def test_run_filter_function__builtin_before():
     assert (
         dictwalk.run_filter_function(
@@ -595,6 +2022,27 @@ def test_run_filter_function__builtin_after():
     )
 
 
+def test_run_filter_function__builtin_between_dates():
+    path_filter = "$between_dates('2024-01-01T00:00:00Z', '2024-12-31T00:00:00Z')"
+    assert dictwalk.run_filter_function(path_filter, "2024-06-01T00:00:00Z") is True
+    assert dictwalk.run_filter_function(path_filter, "2024-01-01T00:00:00Z") is True
+    assert dictwalk.run_filter_function(path_filter, "2025-01-01T00:00:00Z") is False
+
+
+def test_run_filter_function__builtin_between_dates_treats_naive_as_utc():
+    path_filter = "$between_dates('2024-01-01T00:00:00', '2024-01-02T00:00:00Z')"
+    naive = datetime(2024, 1, 1, 12, 0)
+    assert dictwalk.run_filter_function(path_filter, naive) is True
+    aware = datetime(2024, 1, 2, 0, 0, 1, tzinfo=timezone.utc)
+    assert dictwalk.run_filter_function(path_filter, aware) is False
+
+
+def test_run_filter_function__builtin_between_dates_coercion_failure_is_false():
+    path_filter = "$between_dates('2024-01-01', '2024-12-31')"
+    assert dictwalk.run_filter_function(path_filter, "not a date") is False
+    assert dictwalk.run_filter_function(path_filter, None) is False
+
+
 def test_run_filter_function__supports_round_with_args():
     assert dictwalk.run_filter_function("$round(2)", 2.349) == 2.35
 
@@ -615,3 +2063,72 @@ def test_run_filter_function__rejects_path_filter_instance():
 def test_run_filter_function__raises_parse_error_for_non_filter_string():
     with pytest.raises(DictWalkParseError):
         dictwalk.run_filter_function("add(2)", 3)
+
+
+def test_run_filter_function__builtin_similarity():
+    assert dictwalk.run_filter_function("$similarity('kitten')", "sitting") == (
+        pytest.approx(1 - 3 / 7)
+    )
+
+
+def test_run_filter_function__builtin_similarity_identical_and_empty():
+    assert dictwalk.run_filter_function("$similarity('abc')", "abc") == 1.0
+    assert dictwalk.run_filter_function("$similarity('')", "") == 1.0
+
+
+def test_run_filter_function__builtin_similarity_stringifies_non_string_input():
+    assert dictwalk.run_filter_function("$similarity('123')", 124) == pytest.approx(
+        2 / 3
+    )
+
+
+def test_run_filter_function__builtin_indent():
+    assert dictwalk.run_filter_function("$indent(2)", "a\n\n b") == "  a\n\n   b"
+    assert dictwalk.run_filter_function("$indent(0)", "a\nb") == "a\nb"
+    assert dictwalk.run_filter_function("$indent(1)", 42) == " 42"
+
+
+def test_run_filter_function__builtin_dedent():
+    assert dictwalk.run_filter_function("$dedent", "    a\n      b\n") == "a\n  b\n"
+
+
+def test_run_filter_function__builtin_wrap_text():
+    text = "the quick brown fox jumps"
+
+    assert dictwalk.run_filter_function("$wrap_text(10)", text) == (
+        "the quick\nbrown fox\njumps"
+    )
+    assert dictwalk.run_filter_function("$wrap_text(12, 2)", text) == (
+        "  the quick\n  brown fox\n  jumps"
+    )
+
+
+@pytest.fixture
+def restore_options():
+    original = get_options()
+    yield
+    set_options(**original)
+
+
+def test_run_filter_function__string_builtins_stringify_none_by_default():
+    assert get_options() == {"none_safe_strings": False}
+    assert dictwalk.run_filter_function("$upper", None) == "NONE"
+
+
+def test_run_filter_function__none_safe_strings_option_returns_none(restore_options):
+    set_options(none_safe_strings=True)
+
+    for path_filter in (
+        "$upper",
+        "$lower",
+        "$title",
+        "$strip",
+        "$replace('a', 'b')",
+        "$split(',')",
+        "$startswith('N')",
+        "$matches('None')",
+    ):
+        assert dictwalk.run_filter_function(path_filter, None) is None
+    assert dictwalk.run_filter_function("$upper", "abc") == "ABC"
+    assert dictwalk.run_filter_function("$string", None) == "None"
+
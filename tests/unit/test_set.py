@@ -1,4 +1,7 @@
+import pytest
+
 from dictwalk import dictwalk
+from dictwalk.errors import DictWalkParseError, DictWalkResolutionError
 
 
 def test_set__creates_nested_dicts():
@@ -73,6 +76,32 @@ def test_set__sets_terminal_map_values():
     assert data == expected
 
 
+def test_set__updates_only_chained_filter_matches():
+    data = {"a": {"b": [{"id": 1, "k": "x"}, {"id": 2, "k": "x"}, {"id": 2, "k": "y"}]}}
+    path = "a.b[?.id==2 && .k==x].c"
+    value = 99
+    expected = {
+        "a": {
+            "b": [
+                {"id": 1, "k": "x"},
+                {"id": 2, "k": "x", "c": 99},
+                {"id": 2, "k": "y"},
+            ]
+        }
+    }
+
+    assert dictwalk.set(data, path, value) == expected
+
+
+def test_set__does_not_create_item_for_chained_filter_without_matches():
+    data = {"a": {"b": [{"id": 1, "k": "x"}]}}
+    path = "a.b[?.id==2 && .k==y].c"
+    value = 99
+    expected = {"a": {"b": [{"id": 1, "k": "x"}]}}
+
+    assert dictwalk.set(data, path, value) == expected
+
+
 def test_set__transforms_lists_when_mapped_filter_function_is_passed_in_value():
     data = {"a": {"b": [1, 2, 3]}}
     path = "a.b"
@@ -229,6 +258,28 @@ def test_set__sets_values_using_root_reference_write_expression_with_transform()
     assert data == expected
 
 
+def test_set__sets_values_using_coalesce_paths_write_expression():
+    data = {"a": {"b": [{"c": 0}, {"c": 0}]}, "d": None, "e": 7}
+    path = "a.b[].c"
+    value = "$coalesce_paths($$root.missing, $$root.d, $$root.e)"
+    expected = {"a": {"b": [{"c": 7}, {"c": 7}]}, "d": None, "e": 7}
+
+    result = dictwalk.set(data, path, value)
+
+    assert result is data
+    assert data == expected
+
+
+def test_set__filter_quoted_value_may_contain_brackets():
+    data = {"items": [{"note": "a[b", "v": 0}, {"note": "a]b", "v": 0}]}
+    expected = {"items": [{"note": "a[b", "v": 1}, {"note": "a]b", "v": 0}]}
+
+    result = dictwalk.set(data, "items[?.note=='a[b'].v", 1)
+
+    assert result is data
+    assert data == expected
+
+
 def test_set__updates_only_items_matching_operator_filter():
     data = {
         "a": {
@@ -416,3 +467,217 @@ def test_set__does_not_overwrite_incompatible_when_disabled():
     data = {"a": 1}
     dictwalk.set(data, "a.b", 2, overwrite_incompatible=False)
     assert data == {"a": 1}
+
+
+def test_set__filter_predicate_with_root_reference():
+    data = {"min": 2, "items": [{"s": 1}, {"s": 3}]}
+    dictwalk.set(data, "items[?.s==$gt($$root.min)].ok", True)
+    assert data == {"min": 2, "items": [{"s": 1}, {"s": 3, "ok": True}]}
+
+
+def test_set__overwrite_container_disabled_still_replaces_scalars():
+    data = {"a": 1, "b": {"x": 1}, "c": [1]}
+    dictwalk.set(data, "a.k", 2, overwrite_container=False)
+    dictwalk.set(data, "b[0]", 2, overwrite_container=False)
+    dictwalk.set(data, "c.k", 2, overwrite_container=False)
+    assert data == {"a": {"k": 2}, "b": {"x": 1}, "c": [1]}
+
+
+def test_set__overwrite_scalar_disabled_still_replaces_containers():
+    data = {"a": 1, "b": {"x": 1}}
+    dictwalk.set(data, "a.k", 2, overwrite_scalar=False)
+    dictwalk.set(data, "b[0]", 2, overwrite_scalar=False)
+    assert data == {"a": 1, "b": [2]}
+
+
+def test_set__overwrite_flags_override_overwrite_incompatible():
+    data = {"a": 1}
+    dictwalk.set(data, "a.k", 2, overwrite_incompatible=False, overwrite_scalar=True)
+    assert data == {"a": {"k": 2}}
+
+
+def test_set_all__accepts_overwrite_flags():
+    data = {"a": 1, "b": {"x": 1}}
+    dictwalk.set_all(data, ["a[0]", "b[0]"], 2, overwrite_container=False)
+    assert data == {"a": [2], "b": {"x": 1}}
+
+
+def test_set__index_from_root_reference():
+    data = {"target_index": 1, "items": [{"v": 0}, {"v": 0}]}
+    path = "items[$$root.target_index].v"
+    value = 5
+    expected = {"target_index": 1, "items": [{"v": 0}, {"v": 5}]}
+
+    result = dictwalk.set(data, path, value)
+
+    assert result is data
+    assert data == expected
+
+
+def test_set__index_from_root_reference_raises_for_non_int():
+    data = {"target_index": "1", "items": [{"v": 0}, {"v": 0}]}
+
+    with pytest.raises(TypeError):
+        dictwalk.set(data, "items[$$root.target_index].v", 5)
+
+
+def test_set_all__applies_value_to_each_path():
+    data = {"a": {}, "b": {"items": [{"v": 0}, {"v": 0}]}}
+    expected = {"a": {"v": 1}, "b": {"items": [{"v": 1}, {"v": 1}]}, "c": {"v": 1}}
+
+    result = dictwalk.set_all(data, ["a.v", "b.items[].v", "c.v"], 1)
+
+    assert result is data
+    assert data == expected
+
+
+def test_set_all__strict_validates_all_paths_before_writing():
+    data = {"a": {}}
+
+    with pytest.raises(DictWalkResolutionError):
+        dictwalk.set_all(data, ["a.v", "missing.v"], 1, strict=True)
+
+    assert data == {"a": {}}
+
+
+def test_set_all__invalid_path_writes_nothing():
+    data = {"a": {}}
+
+    with pytest.raises(DictWalkParseError):
+        dictwalk.set_all(data, ["a.v", "$$root.v"], 1)
+
+    assert data == {"a": {}}
+
+
+
+def test_set__report_lists_modified_leaves():
+    data = {"users": [{"n": "a", "on": True}, {"n": "b"}, {"n": "c", "on": True}]}
+
+    result, modified = dictwalk.set(data, "users[?.on==True].flag", 1, report=True)
+
+    assert result is data
+    assert modified == ["users[0].flag", "users[2].flag"]
+    assert dictwalk.set(data, "users[].n", "$upper", report=True)[1] == [
+        "users[0].n",
+        "users[1].n",
+        "users[2].n",
+    ]
+
+
+def test_set__report_covers_wildcards_and_created_paths():
+    data = {"cfg": {"x": {"v": 1}, "y": {"v": 2}}}
+    nested = {"cfg": {"x": {"v": 1}, "y": {"v": 2}, "z": [{"v": 3}]}}
+
+    assert dictwalk.set(data, "cfg.*.v", 0, report=True)[1] == ["cfg.x.v", "cfg.y.v"]
+    assert dictwalk.set(nested, "**.v", 9, report=True)[1] == [
+        "cfg.x.v",
+        "cfg.y.v",
+        "cfg.z[0].v",
+    ]
+    assert dictwalk.set({}, "a.b", 1, report=True) == ({"a": {"b": 1}}, ["a.b"])
+    assert dictwalk.set([1, 2], ".[1]", 5, report=True) == ([1, 5], [".[1]"])
+    assert dictwalk.set({"a": 1}, "a.b", 2, overwrite_scalar=False, report=True) == (
+        {"a": 1},
+        [],
+    )
+
+
+def test_set__report_paths_round_trip_for_unsafe_and_non_string_keys():
+    data = {"a": {"x.y": {"z": 1}, 1: {"z": 2}, "ok": {"z": 3}}}
+
+    _, modified = dictwalk.set(data, "a.*.z", 0, report=True)
+
+    assert modified == ["/a/x.y/z", None, "a.ok.z"]
+    dictwalk.set(data, modified[0], 7, dialect="jsonpointer")
+    assert data["a"]["x.y"] == {"z": 7}
+
+
+def test_set__skip_unchanged_leaves_equal_values_alone():
+    original = {"id": 1}
+    data = {"a": original, "users": [{"n": "a"}, {"n": "B"}, {"n": "c"}]}
+
+    assert dictwalk.set(data, "a", {"id": 1}, skip_unchanged=True) is data
+    assert data["a"] is original
+    assert dictwalk.set(
+        data, "users[].n", "$upper", report=True, skip_unchanged=True
+    ) == (data, ["users[0].n", "users[2].n"])
+    _, modified = dictwalk.set(data, "users.*.n", "B", report=True, skip_unchanged=True)
+    assert modified == ["users[0].n", "users[2].n"]
+    _, modified = dictwalk.set(data, "a.new", None, report=True, skip_unchanged=True)
+    assert modified == ["a.new"]
+
+
+def test_set__skip_unchanged_defaults_to_writing_every_leaf():
+    original = {"id": 1}
+    data = {"a": original}
+
+    assert dictwalk.set(data, "a", {"id": 1}, report=True) == (data, ["a"])
+    assert data["a"] is not original
+
+
+def test_set__path_params():
+    data = {"users": [{"id": "o'brien"}, {"id": "x"}]}
+
+    dictwalk.set(data, "users[?.id=='{id}'].active", True, params={"id": "o'brien"})
+    dictwalk.set(data, "meta.{key}", 1, params={"key": "version"})
+
+    assert data == {
+        "users": [{"id": "o'brien", "active": True}, {"id": "x"}],
+        "meta": {"version": 1},
+    }
+
+
+def test_set__jsonpointer_dialect():
+    data = {"a": [{"b": 1}], "dotted": {}}
+
+    dictwalk.set(data, "/a/0/b", 5, dialect="jsonpointer")
+    dictwalk.set(data, "/dotted/k.v", True, dialect="jsonpointer")
+    dictwalk.set(data, "/new/key", "x", dialect="jsonpointer")
+
+    assert data == {
+        "a": [{"b": 5}],
+        "dotted": {"k.v": True},
+        "new": {"key": "x"},
+    }
+
+
+def test_set__jsonpointer_dialect_numeric_segments_name_mapping_members():
+    data = {"a": {}, "b": [0]}
+
+    dictwalk.set(data, "/a/0", 1, dialect="jsonpointer")
+    dictwalk.set(data, "/b/0", 5, dialect="jsonpointer")
+    dictwalk.set(data, "/c/0", "x", dialect="jsonpointer")
+    assert data == {"a": {"0": 1}, "b": [5], "c": ["x"]}
+
+    dictwalk.unset(data, "/a/0", dialect="jsonpointer")
+    assert data["a"] == {}
+
+
+def test_set__jsonpointer_dialect_rejects_whole_document_pointer():
+    with pytest.raises(DictWalkParseError):
+        dictwalk.set({}, "", 1, dialect="jsonpointer")
+
+
+def test_set_if__writes_only_leaves_whose_current_value_matches():
+    data = {"jobs": [{"status": "running", "age": 40}, {"status": "done", "age": 50}]}
+
+    assert dictwalk.set_if(
+        data, "jobs.*.status", "expired", "$in(['running'])", report=True
+    ) == (data, ["jobs[0].status"])
+    assert data["jobs"][1]["status"] == "done"
+
+    dictwalk.set_if(data, "jobs[].age", "$add(1)", "$gt(45) && $lt(60)")
+    assert [job["age"] for job in data["jobs"]] == [40, 51]
+
+
+def test_set_if__tests_missing_leaf_as_none():
+    data = {"config": {"retries": 5}}
+
+    dictwalk.set_if(data, "config.retries", 3, "$is_empty")
+    dictwalk.set_if(data, "config.timeout", 30, "$is_empty")
+    assert data == {"config": {"retries": 5, "timeout": 30}}
+
+
+def test_set_if__rejects_non_predicate_condition():
+    with pytest.raises(DictWalkParseError):
+        dictwalk.set_if({"a": 1}, "a", 2, "running")
@@ -1,4 +1,7 @@
+import pytest
+
 from dictwalk import dictwalk
+from dictwalk.errors import DictWalkParseError
 
 
 def test_unset__unsets_nested_key():
@@ -177,3 +180,27 @@ def test_unset__unsets_root_list_filter_selectors():
 
     dictwalk.unset(data, "$$root[?.id==3]")
     assert data == [{"id": 1, "v": 10}, {"id": 2}]
+
+
+def test_unset__rejects_index_from_root_reference():
+    data = {"target_index": 0, "items": [1, 2]}
+
+    with pytest.raises(DictWalkParseError):
+        dictwalk.unset(data, "items[$$root.target_index]")
+
+
+def test_unset__path_params():
+    data = {"a": {"b": 1, "c": 2}}
+
+    dictwalk.unset(data, "a.{key}", params={"key": "b"})
+
+    assert data == {"a": {"c": 2}}
+
+
+def test_unset__jsonpointer_dialect():
+    data = {"a": [{"b": 1, "c": 2}], "k/v": 3}
+
+    dictwalk.unset(data, "/a/0/b", dialect="jsonpointer")
+    dictwalk.unset(data, "/k~1v", dialect="jsonpointer")
+
+    assert data == {"a": [{"c": 2}]}
@@ -0,0 +1,59 @@
+import pytest
+from dictwalk import Validator, dictwalk
+from dictwalk.errors import DictWalkParseError
+
+
+def test_validator__check_reports_each_path():
+    validator = Validator(["a.b", "a.c", "items[0].id", "items[0].name"])
+    data = {"a": {"b": None}, "items": [{"id": 1}]}
+
+    assert validator.check(data) == {
+        "a.b": True,
+        "a.c": False,
+        "items[0].id": True,
+        "items[0].name": False,
+    }
+
+
+def test_validator__matches_exists():
+    paths = ["a.*.id", "a.x[?.id==2]", "a.x.id[]", "$$root.a.y", "a.**.id"]
+    validator = Validator(paths)
+    data = {"a": {"x": [{"id": 1}], "y": {"id": 2}}}
+
+    expected = {path: dictwalk.exists(data, path) for path in paths}
+    assert validator.check(data) == expected
+
+
+def test_validator__missing_lists_unresolved_paths_in_order():
+    validator = Validator(["user.id", "user.email", "user.name"])
+
+    assert validator.missing({"user": {"id": 1, "name": "x"}}) == ["user.email"]
+    assert validator.missing({"user": {"id": 1, "email": "e", "name": "x"}}) == []
+    assert validator.missing({}) == ["user.id", "user.email", "user.name"]
+
+
+def test_validator__is_reusable_across_documents():
+    validator = Validator(["a.b"])
+
+    assert [validator.check(doc)["a.b"] for doc in ({"a": {"b": 1}}, {"a": 1}, {})] == [
+        True,
+        False,
+        False,
+    ]
+    assert validator.paths == ["a.b"]
+
+
+def test_validator__raises_parse_error_at_construction():
+    with pytest.raises(DictWalkParseError):
+        Validator(["a.b", "a.$$root.c"])
+
+
+def test_validator__nonempty_wildcards_controls_empty_projection_results():
+    data = {"users": [{"active": False}]}
+
+    assert Validator(["users[?.active==True]"]).check(data) == {
+        "users[?.active==True]": False
+    }
+    assert Validator(["users[?.active==True]"], nonempty_wildcards=False).check(
+        data
+    ) == {"users[?.active==True]": True}